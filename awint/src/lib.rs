@@ -8,11 +8,15 @@
 
 #[doc(hidden)]
 pub use awint_core::awint_internals;
-pub use awint_core::{bw, Bits, InlAwi, SerdeError};
+pub use awint_core::{bits_for_str_len, bw, max_str_len, Bits, InlAwi, Runs, SerdeError};
 #[cfg(feature = "dag")]
 pub use awint_dag;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use awint_ext::ArcBits;
+#[cfg(feature = "rkyv_support")]
+pub use awint_ext::{ArchivedExtAwi, ArchivedExtAwiError, ExtAwiResolver};
 #[cfg(feature = "alloc")]
-pub use awint_ext::{Awi, ExtAwi, FPType, OrdBits, FP};
+pub use awint_ext::{Awi, ExtAwi, FPType, FpRounding, OrdBits, FP};
 #[doc(hidden)]
 #[cfg(feature = "std")]
 pub use awint_macro_internals;
@@ -55,3 +59,27 @@ pub mod prelude {
 pub mod fp {
     pub use awint_ext::fp::*;
 }
+
+/// Bit permutation related items
+#[cfg(feature = "alloc")]
+pub mod bit_permutation {
+    pub use awint_ext::bit_permutation::*;
+}
+
+/// Cyclic redundancy check related items
+#[cfg(feature = "alloc")]
+pub mod crc {
+    pub use awint_ext::crc::*;
+}
+
+/// Chinese remainder theorem related items
+#[cfg(feature = "alloc")]
+pub mod crt {
+    pub use awint_ext::crt::*;
+}
+
+/// GF(2) linear algebra related items
+#[cfg(feature = "alloc")]
+pub mod gf2 {
+    pub use awint_ext::gf2::*;
+}
@@ -1,9 +1,13 @@
 mod bits;
+#[cfg(feature = "borsh_support")]
+mod borsh;
 mod inlawi;
 #[cfg(feature = "serde_support")]
 mod serde;
 
 pub use bits::Bits;
+#[cfg(feature = "borsh_support")]
+pub use borsh::{borsh_deserialize_bits, borsh_deserialize_bw};
 pub use inlawi::InlAwi;
 
 #[cfg(feature = "const_support")]
@@ -64,6 +64,25 @@ use const_fn::const_fn;
 /// return `None` if the input bitwidths are not equal to each other. The `Bits`
 /// have been left unchanged if `None` is returned.
 ///
+/// # `const` support
+///
+/// When the "const_support" feature is enabled, most functions on `Bits` and
+/// every constructor on `InlAwi` are `const`. This includes all of the common
+/// arithmetic like [Bits::add_], [Bits::sub_], [Bits::not_], [Bits::shl_],
+/// [Bits::mul_add_], and comparisons like [Bits::const_eq], so lookup tables
+/// can be computed at compile time from `InlAwi` arithmetic. Without the
+/// feature, the same functions are available but only at runtime.
+///
+/// The following are never `const`:
+/// - the functions taking closures or iterators: [Bits::fill_from_digit_iter_],
+///   [Bits::fold_digits], [Bits::find_monotone_boundary], [Bits::lanes_map_],
+///   and [Bits::runs]
+/// - the panicking width checks [Bits::expect_width] and
+///   [Bits::expect_eq_width]
+/// - [Bits::jacobi] and [Bits::mod_pow_windowed_]
+/// - `rand_` and `is_probably_prime` from "rand_support"
+/// - the formatting trait impls
+///
 /// # Portability
 ///
 /// This crate strives to maintain deterministic outputs across architectures
@@ -237,6 +256,41 @@ impl<'a> Bits {
         self.nzbw().get()
     }
 
+    /// Returns `self`, asserting that `self.bw() == w`. This is useful for
+    /// finding the origin of mismatched bitwidths that would otherwise result
+    /// in a `None` from a fallible function further along.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the location of the caller if `self.bw() != w`
+    #[track_caller]
+    pub fn expect_width(&self, w: usize) -> &Self {
+        if self.bw() != w {
+            panic!(
+                "expected a bitwidth of {w}, found a bitwidth of {}",
+                self.bw()
+            );
+        }
+        self
+    }
+
+    /// Returns `self`, asserting that `self.bw() == rhs.bw()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the location of the caller if the bitwidths are not equal
+    #[track_caller]
+    pub fn expect_eq_width(&self, rhs: &Self) -> &Self {
+        if self.bw() != rhs.bw() {
+            panic!(
+                "mismatched bitwidths: `self.bw()` is {}, `rhs.bw()` is {}",
+                self.bw(),
+                rhs.bw()
+            );
+        }
+        self
+    }
+
     /// # Safety
     ///
     /// `i < self.total_digits()` should hold true
@@ -336,8 +390,8 @@ impl<'a> Bits {
     }
 
     /// Clears the unused bits. This is only needed if you are using certain
-    /// hidden functions to write to the digits directly.
-    #[doc(hidden)]
+    /// hidden functions to write to the digits directly, after which this
+    /// restores the invariant checked by [Bits::is_canonical].
     #[inline]
     #[const_fn(cfg(feature = "const_support"))]
     pub const fn clear_unused_bits(&mut self) {
@@ -347,6 +401,29 @@ impl<'a> Bits {
         *self.last_mut() &= MAX >> (BITS - self.extra());
     }
 
+    /// Returns if the unused bits of `self` (the bits in the last digit beyond
+    /// the bitwidth) are all clear, which is an invariant that all functions
+    /// of `Bits` uphold. This can only be broken by writing to the digits
+    /// directly through certain hidden functions, in which case
+    /// [Bits::clear_unused_bits] can restore it.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u5);
+    /// assert!(x.is_canonical());
+    /// x.as_mut_slice()[0] = 1 << 5;
+    /// assert!(!x.is_canonical());
+    /// x.clear_unused_bits();
+    /// assert!(x.is_canonical());
+    /// ```
+    #[inline]
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn is_canonical(&self) -> bool {
+        (self.extra() == 0) || ((self.last() >> self.extra()) == 0)
+    }
+
     /// Some functions cannot handle set unused bits, so this acts as a quick
     /// way to check if unused bits are indeed clear.
     ///
@@ -357,8 +434,7 @@ impl<'a> Bits {
     #[track_caller]
     #[const_fn(cfg(feature = "const_support"))]
     pub const fn assert_cleared_unused_bits(&self) {
-        let one: Digit = 1;
-        if (self.extra() != 0) && (self.last() >= one.wrapping_shl(self.extra() as u32)) {
+        if !self.is_canonical() {
             panic!(
                 "unused bits are set in a `Bits` struct, they have been set with one of the \
                  hidden functions and not properly unset with `Bits::clear_unused_bits`"
@@ -571,6 +647,97 @@ impl<'a> Bits {
         }
     }
 
+    /// Assigns the digits yielded by `iter` to `self`, least significant digit
+    /// first. If `iter` runs out of digits, the rest of `self` is zeroed.
+    /// Returns `true` if `iter` yielded more than `self.total_digits()`
+    /// digits or if the last digit had bits set beyond `self.bw()`, in which
+    /// case the excess is ignored. At most one digit beyond
+    /// `self.total_digits()` is taken from `iter`. This is not portable
+    /// across `Digit` sizes, use [Bits::u8_slice_] for that.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u200);
+    /// assert!(!x.fill_from_digit_iter_([1, 2].iter().copied()));
+    /// assert_eq!(x.first(), 1);
+    /// assert!(x.fill_from_digit_iter_(core::iter::repeat(1)));
+    /// ```
+    pub fn fill_from_digit_iter_<I: IntoIterator<Item = Digit>>(&mut self, iter: I) -> bool {
+        let mut iter = iter.into_iter().fuse();
+        for x in self.as_mut_slice() {
+            *x = iter.next().unwrap_or(0);
+        }
+        let excess = !self.is_canonical();
+        self.clear_unused_bits();
+        excess || iter.next().is_some()
+    }
+
+    /// Writes the digits of `self` to `out` in reverse order, so that the most
+    /// significant digit comes first. This is for interop with big integer
+    /// libraries that store their limbs in big endian order. Note that this
+    /// reorders whole digits, the bits within each digit are unchanged.
+    /// Returns `None` if `out.len() != self.total_digits()`. This is not
+    /// portable across `Digit` sizes.
+    ///
+    /// ```
+    /// use awint::{awi::*, awint_internals::BITS};
+    ///
+    /// let mut x = ExtAwi::zero(bw(BITS * 2 + 1));
+    /// x.digit_(3);
+    /// x.set(BITS * 2, true).unwrap();
+    /// let mut out = [0; 3];
+    /// x.to_reversed_digits(&mut out).unwrap();
+    /// assert_eq!(out, [1, 0, 3]);
+    /// assert!(x.to_reversed_digits(&mut [0; 2]).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn to_reversed_digits(&self, out: &mut [Digit]) -> Option<()> {
+        let len = self.total_digits();
+        if out.len() != len {
+            return None
+        }
+        const_for!(i in {0..len} {
+            // Safety: `i < self.total_digits()`
+            out[len - 1 - i] = unsafe { self.get_unchecked(i) };
+        });
+        Some(())
+    }
+
+    /// Folds `f` over the digits of `self`, starting with the accumulator
+    /// `init` and visiting all `self.total_digits()` digits in order from
+    /// least significant to most significant. The unused bits of the last
+    /// digit are always zero. This is useful for computing checksums or
+    /// hashes over the raw digits without allocation or `unsafe`.
+    ///
+    /// Note that the number of digits and the bits in each digit depend on
+    /// the size of `Digit`, which changes with the `*_digits` feature flags
+    /// and target architecture, so results are not portable in general. Use
+    /// [Bits::to_u8_slice] for a portable representation.
+    ///
+    /// ```
+    /// use awint::{
+    ///     awi::*,
+    ///     awint_internals::{Digit, BITS},
+    /// };
+    ///
+    /// let mut x = ExtAwi::zero(bw(BITS * 2 + 1));
+    /// x.digit_(3);
+    /// x.set(BITS * 2, true).unwrap();
+    /// // wrapping sum of the digits
+    /// assert_eq!(
+    ///     x.fold_digits(0, |acc: Digit, digit| acc.wrapping_add(digit)),
+    ///     4
+    /// );
+    /// // digits are visited least significant first
+    /// assert_eq!(x.fold_digits(0, |acc: Digit, digit| acc * 4 + digit), 49);
+    /// ```
+    #[must_use]
+    pub fn fold_digits<T, F: FnMut(T, Digit) -> T>(&self, init: T, f: F) -> T {
+        self.as_slice().iter().copied().fold(init, f)
+    }
+
     /// # Safety
     ///
     /// `range` must satisfy `range.start <= range.end` and `range.end <=
@@ -602,6 +769,93 @@ impl<'a> Bits {
         );
     }
 
+    /// Sets the whole `Digit`s in the digit index range `range` to all ones if
+    /// `value` is true, or all zeros otherwise, and then clears unused bits.
+    /// This is faster than [Bits::range_or_] and similar functions for
+    /// digit-aligned ranges. An empty range does nothing to `self`. `None` is
+    /// returned if `range.start > range.end` or `range.end >
+    /// self.total_digits()`.
+    ///
+    /// # Portability
+    ///
+    /// The indexes are in units of `Digit`s, which vary in size between
+    /// platforms. Use [Bits::total_digits] and `awint::awint_internals::BITS`
+    /// to calculate ranges portably.
+    ///
+    /// ```
+    /// use awint::{awint_internals::BITS, inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u300);
+    /// let len = x.total_digits();
+    /// x.fill_digits(true, 1..len).unwrap();
+    /// assert_eq!(x.tz(), BITS);
+    /// assert_eq!(x.count_ones(), 300 - BITS);
+    /// x.fill_digits(false, 0..len).unwrap();
+    /// assert!(x.is_zero());
+    /// assert!(x.fill_digits(true, 0..(len + 1)).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn fill_digits(&mut self, value: bool, range: Range<usize>) -> Option<()> {
+        if (range.start > range.end) || (range.end > self.total_digits()) {
+            return None
+        }
+        // Safety: we checked the requirements above
+        unsafe { self.digit_set(value, range, true) };
+        Some(())
+    }
+
+    /// Returns the whole `Digit` at the digit index `inx`, or `None` if `inx >=
+    /// self.total_digits()`. Unused bits of the last digit are always zero.
+    /// Note that this is different from the hidden bit-indexed
+    /// `Bits::get_digit`.
+    ///
+    /// # Portability
+    ///
+    /// The indexes are in units of `Digit`s, which vary in size between
+    /// platforms. Use [Bits::total_digits] and `awint::awint_internals::BITS`
+    /// to calculate indexes portably.
+    ///
+    /// ```
+    /// use awint::{awint_internals::BITS, inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u300);
+    /// let last = x.total_digits() - 1;
+    /// x.set_digit_(0, 42).unwrap();
+    /// x.set_digit_(last, !0).unwrap();
+    /// assert_eq!(x.digit_at(0), Some(42));
+    /// // the unused bits are cleared
+    /// assert_eq!(x.digit_at(last), Some(!0 >> (BITS - (300 - last * BITS))));
+    /// assert_eq!(x.digit_at(last + 1), None);
+    /// assert!(x.set_digit_(last + 1, 0).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn digit_at(&self, inx: usize) -> Option<Digit> {
+        if inx >= self.total_digits() {
+            return None
+        }
+        // Safety: we checked that `inx < self.total_digits()`
+        Some(unsafe { self.get_unchecked(inx) })
+    }
+
+    /// Sets the whole `Digit` at the digit index `inx` to `digit`, clearing
+    /// unused bits if it is the last digit. `None` is returned if `inx >=
+    /// self.total_digits()`. See [Bits::digit_at] for portability concerns.
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn set_digit_(&mut self, inx: usize, digit: Digit) -> Option<()> {
+        if inx >= self.total_digits() {
+            return None
+        }
+        // Safety: we checked that `inx < self.total_digits()`
+        unsafe {
+            *self.get_unchecked_mut(inx) = digit;
+        }
+        self.clear_unused_bits();
+        Some(())
+    }
+
     /// Gets one `Digit` from `self` starting at the bit index `start`.
     /// Bits that extend beyond `self.bw()` are zeroed.
     #[doc(hidden)]
@@ -657,10 +911,11 @@ impl<'a> Bits {
 }
 
 impl fmt::Debug for Bits {
-    /// Forwards to the `LowerHex` impl. We cannot use decimal because it would
-    /// require allocation.
+    /// Uses the default `LowerHex` formatting regardless of formatter flags,
+    /// so that `{:#?}` pretty printing of containing structs is unaffected.
+    /// We cannot use decimal because it would require allocation.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::LowerHex::fmt(self, f)
+        self.debug_format_pow2(f, 4, "0x", false, 8, true)
     }
 }
 
@@ -672,22 +927,29 @@ impl fmt::Display for Bits {
 }
 
 impl fmt::LowerHex for Bits {
-    /// Lowercase hexadecimal formatting.
+    /// Lowercase hexadecimal formatting. By default, underscores are inserted
+    /// every 8 digits and a bitwidth suffix is included. The `#` alternate
+    /// flag removes the underscores and suffix, and the precision sets the
+    /// number of digits between underscores (`.0` removes them). This applies
+    /// to the other power-of-two radix formatting impls as well.
     ///
     /// ```
     /// use awint::{inlawi, Bits, InlAwi};
-    /// assert_eq!(
-    ///     format!("{:x}", inlawi!(0xfedcba9876543210u100)),
-    ///     "0xfedcba98_76543210_u100"
-    /// );
+    /// let x = inlawi!(0xfedcba9876543210u100);
+    /// assert_eq!(format!("{:x}", x), "0xfedcba98_76543210_u100");
+    /// assert_eq!(format!("{:#x}", x), "0xfedcba9876543210");
+    /// assert_eq!(format!("{:.4x}", x), "0xfedc_ba98_7654_3210_u100");
+    /// assert_eq!(format!("{:#.4x}", x), "0xfedc_ba98_7654_3210");
+    /// assert_eq!(format!("{:.0x}", x), "0xfedcba9876543210_u100");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.debug_format_hexadecimal(f, false)
+        self.debug_format_flags(f, 4, "0x", false)
     }
 }
 
 impl fmt::UpperHex for Bits {
-    /// Uppercase hexadecimal formatting.
+    /// Uppercase hexadecimal formatting. See the `LowerHex` impl for the
+    /// supported formatter flags.
     ///
     /// ```
     /// use awint::{inlawi, Bits, InlAwi};
@@ -697,7 +959,7 @@ impl fmt::UpperHex for Bits {
     /// );
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.debug_format_hexadecimal(f, true)
+        self.debug_format_flags(f, 4, "0x", true)
     }
 }
 
@@ -712,7 +974,7 @@ impl fmt::Octal for Bits {
     /// );
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.debug_format_octal(f)
+        self.debug_format_flags(f, 3, "0o", false)
     }
 }
 
@@ -724,7 +986,7 @@ impl fmt::Binary for Bits {
     /// assert_eq!(format!("{:b}", inlawi!(11000101)), "0b11000101_u8");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.debug_format_binary(f)
+        self.debug_format_flags(f, 1, "0b", false)
     }
 }
 
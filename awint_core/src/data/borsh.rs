@@ -0,0 +1,100 @@
+use core::num::NonZeroUsize;
+
+use awint_internals::*;
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::{Bits, InlAwi};
+
+/// The number of bytes needed to hold `w` bits
+const fn bytes_len(w: usize) -> usize {
+    (w >> 3) + ((w & 0b111) != 0) as usize
+}
+
+/// A `borsh_support` impl
+impl BorshSerialize for Bits {
+    /// Serializes `self` in a deterministic and platform independent way. The
+    /// bitwidth is written as a little-endian `u64`, followed by the
+    /// `ceil(bw / 8)` bytes of the value in little-endian order.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0x234u12);
+    /// assert_eq!(borsh::to_vec(x.as_ref()).unwrap(), vec![
+    ///     12, 0, 0, 0, 0, 0, 0, 0, 0x34, 0x2
+    /// ]);
+    /// ```
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.bw() as u64).serialize(writer)?;
+        let mut len = bytes_len(self.bw());
+        for digit in self.as_slice() {
+            let n = if len < DIGIT_BYTES { len } else { DIGIT_BYTES };
+            writer.write_all(&digit.to_le_bytes()[..n])?;
+            len -= n;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the `u64` bitwidth prefix of the `borsh_support` format
+#[doc(hidden)]
+pub fn borsh_deserialize_bw<R: Read>(reader: &mut R) -> Result<NonZeroUsize> {
+    let w = u64::deserialize_reader(reader)?;
+    match usize::try_from(w).ok().and_then(NonZeroUsize::new) {
+        Some(w) => Ok(w),
+        None => Err(Error::new(
+            ErrorKind::InvalidData,
+            "bitwidth is zero or does not fit in a `usize`",
+        )),
+    }
+}
+
+/// Reads the `ceil(bw / 8)` bytes of the `borsh_support` format into `bits`
+#[doc(hidden)]
+pub fn borsh_deserialize_bits<R: Read>(bits: &mut Bits, reader: &mut R) -> Result<()> {
+    let mut len = bytes_len(bits.bw());
+    for digit in bits.as_mut_slice() {
+        let n = if len < DIGIT_BYTES { len } else { DIGIT_BYTES };
+        let mut buf = [0u8; DIGIT_BYTES];
+        reader.read_exact(&mut buf[..n])?;
+        *digit = Digit::from_le_bytes(buf);
+        len -= n;
+    }
+    if (bits.extra() != 0) && ((bits.last() >> bits.extra()) != 0) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "bits beyond the bitwidth are set",
+        ))
+    }
+    Ok(())
+}
+
+/// A `borsh_support` impl
+impl<const BW: usize, const LEN: usize> BorshSerialize for InlAwi<BW, LEN> {
+    /// Forwards to the corresponding impl for `Bits`
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_ref().serialize(writer)
+    }
+}
+
+/// A `borsh_support` impl
+impl<const BW: usize, const LEN: usize> BorshDeserialize for InlAwi<BW, LEN> {
+    /// Deserializes the format of the `BorshSerialize` impl for `Bits`,
+    /// returning an error if the bitwidth is not `BW` or if the bits beyond the
+    /// bitwidth in the last byte are not zero
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let w = borsh_deserialize_bw(reader)?;
+        if w.get() != BW {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "bitwidth does not match the `InlAwi` type",
+            ))
+        }
+        let mut res = Self::zero();
+        borsh_deserialize_bits(&mut res, reader)?;
+        Ok(res)
+    }
+}
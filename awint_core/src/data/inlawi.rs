@@ -230,6 +230,28 @@ impl<'a, const BW: usize, const LEN: usize> InlAwi<BW, LEN> {
         val
     }
 
+    /// Creates an `InlAwi` directly from its raw `Digit`s in little endian
+    /// order. Returns `None` if any of the unused bits in the last digit are
+    /// set.
+    ///
+    /// # Portability
+    ///
+    /// This depends on the size of `Digit`, use `TryFrom<&[u8]>` for a
+    /// portable alternative.
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn from_digits(digits: [Digit; LEN]) -> Option<Self> {
+        RawStackBits::<BW, LEN>::_assert_invariants();
+        let val = Self {
+            _raw_stack_bits: RawStackBits { _digits: digits },
+        };
+        let extra = extra_u(BW);
+        if (extra != 0) && ((digits[LEN - 1] >> extra) != 0) {
+            return None
+        }
+        Some(val)
+    }
+
     /// Zero-value construction with bitwidth `BW`
     #[const_fn(cfg(feature = "const_support"))]
     pub const fn zero() -> Self {
@@ -293,6 +315,27 @@ impl<const BW: usize, const LEN: usize> PartialEq for InlAwi<BW, LEN> {
 /// If `self` and `other` have unmatching bit widths, `false` will be returned.
 impl<const BW: usize, const LEN: usize> Eq for InlAwi<BW, LEN> {}
 
+impl<const BW: usize, const LEN: usize> TryFrom<&[u8]> for InlAwi<BW, LEN> {
+    type Error = SerdeError;
+
+    /// Creates an `InlAwi` from little endian bytes. Returns
+    /// `SerdeError::NonEqualWidths` if `buf.len()` is not the minimum number of
+    /// bytes needed to store `BW` bits, and `SerdeError::Overflow` if any bits
+    /// beyond `BW` are set in the last byte. This is portable across target
+    /// architecture pointer sizes and endianness.
+    fn try_from(buf: &[u8]) -> Result<Self, Self::Error> {
+        let bw = Self::const_bw();
+        let extra = bw % 8;
+        if buf.len() != ((bw / 8) + ((extra != 0) as usize)) {
+            return Err(SerdeError::NonEqualWidths)
+        }
+        if (extra != 0) && ((buf[buf.len() - 1] >> extra) != 0) {
+            return Err(SerdeError::Overflow)
+        }
+        Ok(Self::unstable_from_u8_slice(buf))
+    }
+}
+
 #[cfg(feature = "zeroize_support")]
 impl<const BW: usize, const LEN: usize> zeroize::Zeroize for InlAwi<BW, LEN> {
     fn zeroize(&mut self) {
@@ -400,14 +400,17 @@ formatting the rest of this document
 
 #[doc(hidden)]
 pub use awint_internals;
-pub use awint_internals::{bw, SerdeError};
+pub use awint_internals::{bits_for_str_len, bw, max_str_len, SerdeError};
 
 pub(crate) mod data;
 pub use data::{Bits, InlAwi};
+#[cfg(feature = "borsh_support")]
+#[doc(hidden)]
+pub use data::{borsh_deserialize_bits, borsh_deserialize_bw};
 
 mod logic;
 
-pub use logic::OrdBits;
+pub use logic::{OrdBits, Runs};
 
 /// Subset of `awint::awi`
 pub mod awi {
@@ -10,5 +10,7 @@ mod permute;
 mod primitives;
 #[cfg(feature = "rand_support")]
 mod rand;
+mod runs;
 mod sum;
 pub use cmp::OrdBits;
+pub use runs::Runs;
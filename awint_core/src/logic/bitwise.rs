@@ -179,6 +179,73 @@ impl Bits {
         Some(())
     }
 
+    /// Mask-assigns a range of ones to `self`, setting the bits in `range` and
+    /// clearing all other bits. An empty or reversed range zeroes `self`.
+    /// `None` is returned if `range.start > self.bw()` or `range.end >
+    /// self.bw()`.
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn range_mask_(&mut self, range: Range<usize>) -> Option<()> {
+        if range.start > self.bw() || range.end > self.bw() {
+            return None
+        }
+        // see `range_and_` for why we chose this
+        if range.start >= range.end {
+            self.zero_();
+            return Some(())
+        }
+        let start = digits_u(range.start);
+        let end = digits_u(range.end);
+        let start_bits = extra_u(range.start);
+        let end_bits = extra_u(range.end);
+        // Safety: the early `None` return above prevents any out of bounds indexing.
+        unsafe {
+            self.digit_set(false, 0..start, false);
+            if start == end {
+                // The range is entirely contained in one digit, and `end_bits` cannot be
+                // zero because `range.start < range.end`
+                *self.get_unchecked_mut(start) = (MAX << start_bits) & (MAX >> (BITS - end_bits));
+            } else {
+                *self.get_unchecked_mut(start) = MAX << start_bits;
+                self.digit_set(true, (start + 1)..end, false);
+                if end_bits != 0 {
+                    *self.get_unchecked_mut(end) = MAX >> (BITS - end_bits);
+                } else if end < self.total_digits() {
+                    *self.get_unchecked_mut(end) = 0;
+                }
+            }
+            if (end + 1) < self.total_digits() {
+                self.digit_set(false, (end + 1)..self.total_digits(), false);
+            }
+        }
+        Some(())
+    }
+
+    /// Unsigned-mask-assigns, setting the least significant `n` bits and
+    /// clearing all other bits. This is equivalent to
+    /// `self.range_mask_(0..n)`, and unlike shifting a `umax` value, works
+    /// when `n == self.bw()`. `None` is returned if `n > self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u100);
+    /// x.umask_(70).unwrap();
+    /// assert_eq!(x, inlawi!(0x3fffffffffffffffffu100));
+    /// x.umask_(100).unwrap();
+    /// assert!(x.is_umax());
+    /// x.umask_(0).unwrap();
+    /// assert!(x.is_zero());
+    /// assert!(x.umask_(101).is_none());
+    /// x.range_mask_(4..8).unwrap();
+    /// assert_eq!(x, inlawi!(0xf0u100));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn umask_(&mut self, n: usize) -> Option<()> {
+        self.range_mask_(0..n)
+    }
+
     /// Xor-assigns a range of ones to `self`. An empty or reversed range does
     /// nothing to `self`. `None` is returned if `range.start > self.bw()`
     /// or `range.end > self.bw()`.
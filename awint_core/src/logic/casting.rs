@@ -139,4 +139,38 @@ impl Bits {
         }
         false
     }
+
+    /// Clamping-resize-copy-assigns `rhs` to `self`. This is a saturating
+    /// version of `zero_resize_` (if `signed` is `false`) or `sign_resize_` (if
+    /// `signed` is `true`). If the value of `rhs` can be represented in
+    /// `self.bw()` bits, it is copied exactly. Otherwise, unsigned values
+    /// clamp to `self.umax_()`, and signed values clamp to `self.imax_()` if
+    /// `rhs` is positive or `self.imin_()` if `rhs` is negative. Clamping can
+    /// only occur when `self.bw() < rhs.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut y = inlawi!(0u8);
+    /// y.resize_clamped_(&inlawi!(300u16), false);
+    /// assert_eq!(y, inlawi!(255u8));
+    /// y.resize_clamped_(&inlawi!(-300i16), true);
+    /// assert_eq!(y, inlawi!(-128i8));
+    /// y.resize_clamped_(&inlawi!(-100i16), true);
+    /// assert_eq!(y, inlawi!(-100i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn resize_clamped_(&mut self, rhs: &Self, signed: bool) {
+        if signed {
+            if self.sign_resize_(rhs) {
+                if rhs.msb() {
+                    self.imin_()
+                } else {
+                    self.imax_()
+                }
+            }
+        } else if self.zero_resize_(rhs) {
+            self.umax_()
+        }
+    }
 }
@@ -8,11 +8,22 @@ use crate::Bits;
 #[doc(hidden)]
 pub struct CCResult<T> {
     run_fielding: bool,
-    success: bool,
+    /// `None` if all the checks succeeded, otherwise the error corresponding to
+    /// the first failed check
+    error: Option<SerdeError>,
     _phantom_data: PhantomData<fn() -> T>,
 }
 
 impl<T> CCResult<T> {
+    #[inline]
+    const fn failure(error: SerdeError) -> Self {
+        CCResult {
+            run_fielding: false,
+            error: Some(error),
+            _phantom_data: PhantomData,
+        }
+    }
+
     #[inline]
     pub const fn run_fielding(&self) -> bool {
         self.run_fielding
@@ -27,12 +38,29 @@ impl<T> CCResult<T> {
     pub const fn wrap_none(self) -> Option<T> {
         None
     }
+
+    /// Used by the `try_` construction macros
+    #[inline]
+    pub const fn wrap_ok(self, t: T) -> Result<T, SerdeError> {
+        Ok(t)
+    }
+
+    /// Used by the `try_` construction macros
+    #[inline]
+    pub const fn wrap_err(self) -> Result<T, SerdeError> {
+        match self.error {
+            Some(e) => Err(e),
+            // the only successful case that does not run fielding is a zero width
+            // concatenation, which construction macros cannot return
+            None => Err(SerdeError::ZeroBitwidth),
+        }
+    }
 }
 
 impl CCResult<()> {
     #[inline]
     pub const fn wrap_if_success(self) -> Option<()> {
-        if self.success {
+        if self.error.is_none() {
             Some(())
         } else {
             None
@@ -103,40 +131,37 @@ impl Bits {
         check_nonzero_cw: bool,
         ok_on_zero: bool,
     ) -> CCResult<T> {
-        let none = CCResult {
-            run_fielding: false,
-            success: false,
-            _phantom_data: PhantomData,
-        };
+        // reversed ranges or ranges extending beyond the bitwidth of a component
         const_for!(i in {0..LE} {
             if le0[i] > le1[i] {
-                return none
+                return CCResult::failure(SerdeError::Overflow)
             }
         });
+        // concatenation widths that are not equal to the common width
         const_for!(i in {0..GE} {
             if cw < ge[i] {
-                return none
+                return CCResult::failure(SerdeError::NonEqualWidths)
             }
         });
         const_for!(i in {0..EQ} {
             if cw != eq[i] {
-                return none
+                return CCResult::failure(SerdeError::NonEqualWidths)
             }
         });
         if check_nonzero_cw && (cw == 0) {
             if ok_on_zero {
                 return CCResult {
                     run_fielding: false,
-                    success: true,
+                    error: None,
                     _phantom_data: PhantomData,
                 }
             } else {
-                return none
+                return CCResult::failure(SerdeError::ZeroBitwidth)
             }
         }
         CCResult {
             run_fielding: true,
-            success: true,
+            error: None,
             _phantom_data: PhantomData,
         }
     }
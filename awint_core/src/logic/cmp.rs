@@ -3,7 +3,7 @@ use core::{
     cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
-    ops::{Deref, DerefMut},
+    ops::{Deref, DerefMut, Range},
 };
 
 use awint_internals::*;
@@ -146,6 +146,15 @@ impl Bits {
         }
     }
 
+    /// If `self` is negative when interpreted as signed. This is the same as
+    /// [Bits::msb].
+    #[inline]
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.msb()
+    }
+
     /// If `self` is unsigned-one
     #[const_fn(cfg(feature = "const_support"))]
     #[must_use]
@@ -318,6 +327,151 @@ impl Bits {
         Some(true)
     }
 
+    /// Range-equality comparison, `self[range] == rhs`. The bits of `self` in
+    /// `range` are compared against all of the bits in `rhs`. `None` is
+    /// returned if `range.start > range.end`, `range.end > self.bw()`, or
+    /// `(range.end - range.start) != rhs.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    /// let x = inlawi!(0xfd_42_ba9876543210u100);
+    /// assert!(x.range_eq(48..56, &inlawi!(0x42u8)).unwrap());
+    /// assert!(!x.range_eq(44..52, &inlawi!(0x42u8)).unwrap());
+    /// assert!(x.range_eq(44..56, &inlawi!(0x42u8)).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn range_eq(&self, range: Range<usize>, rhs: &Self) -> Option<bool> {
+        if (range.start > range.end)
+            || (range.end > self.bw())
+            || ((range.end - range.start) != rhs.bw())
+        {
+            return None
+        }
+        // the bits of `self` beyond `range.end` can only appear in the last digit
+        let mask = if rhs.extra() == 0 {
+            MAX
+        } else {
+            MAX >> rhs.unused()
+        };
+        let last = rhs.total_digits() - 1;
+        if (self.get_digit(range.start + (last * BITS)) & mask) != rhs.last() {
+            return Some(false)
+        }
+        // Safety: `i < rhs.total_digits()`
+        unsafe {
+            const_for!(i in {0..last}.rev() {
+                if self.get_digit(range.start + (i * BITS)) != rhs.get_unchecked(i) {
+                    return Some(false)
+                }
+            });
+        }
+        Some(true)
+    }
+
+    /// Range-unsigned-less-than comparison, `self[range] < rhs`. The bits of
+    /// `self` in `range` are interpreted as an unsigned integer with a bitwidth
+    /// of `rhs.bw()`. `None` is returned under the same conditions as
+    /// [Bits::range_eq].
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    /// let x = inlawi!(0xfd_42_ba9876543210u100);
+    /// assert!(x.range_ult(48..56, &inlawi!(0x43u8)).unwrap());
+    /// assert!(!x.range_ult(48..56, &inlawi!(0x42u8)).unwrap());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn range_ult(&self, range: Range<usize>, rhs: &Self) -> Option<bool> {
+        if (range.start > range.end)
+            || (range.end > self.bw())
+            || ((range.end - range.start) != rhs.bw())
+        {
+            return None
+        }
+        let mask = if rhs.extra() == 0 {
+            MAX
+        } else {
+            MAX >> rhs.unused()
+        };
+        let last = rhs.total_digits() - 1;
+        let x = self.get_digit(range.start + (last * BITS)) & mask;
+        let y = rhs.last();
+        if x < y {
+            return Some(true)
+        } else if x != y {
+            return Some(false)
+        }
+        // Safety: `i < rhs.total_digits()`
+        unsafe {
+            const_for!(i in {0..last}.rev() {
+                let x = self.get_digit(range.start + (i * BITS));
+                let y = rhs.get_unchecked(i);
+                if x < y {
+                    return Some(true)
+                } else if x != y {
+                    return Some(false)
+                }
+            });
+        }
+        Some(false)
+    }
+
+    /// Unsigned-less-than comparison against a left shifted `rhs`, `self <
+    /// (rhs << shl)`. The shift is the same as [Bits::shl_], meaning that bits
+    /// shifted beyond the bitwidth are truncated, but the shifted value is
+    /// computed digit by digit during the comparison rather than being
+    /// materialized in a temporary. `None` is returned if `self.bw() !=
+    /// rhs.bw()` or `shl >= self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    /// let x = inlawi!(0x1234u100);
+    /// assert!(x.ult_shl(&inlawi!(0x124u100), 4).unwrap());
+    /// assert!(!x.ult_shl(&inlawi!(0x123u100), 4).unwrap());
+    /// // the shifted value is truncated like `shl_`
+    /// assert!(x.ult_shl(&inlawi!(1u100), 99).unwrap());
+    /// assert!(!x.ult_shl(&inlawi!(2u100), 99).unwrap());
+    /// assert!(x.ult_shl(&x, 100).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn ult_shl(&self, rhs: &Self, shl: usize) -> Option<bool> {
+        if (self.bw() != rhs.bw()) || (shl >= self.bw()) {
+            return None
+        }
+        let mask = if self.extra() == 0 {
+            MAX
+        } else {
+            MAX >> self.unused()
+        };
+        let last = self.total_digits() - 1;
+        // Safety: `i < self.total_digits()`
+        unsafe {
+            const_for!(i in {0..self.total_digits()}.rev() {
+                let start = i * BITS;
+                // the digit of `rhs << shl` starting at bit `start`
+                let mut y = if start >= shl {
+                    rhs.get_digit(start - shl)
+                } else if (shl - start) < BITS {
+                    rhs.first() << (shl - start)
+                } else {
+                    0
+                };
+                if i == last {
+                    y &= mask;
+                }
+                let x = self.get_unchecked(i);
+                if x < y {
+                    return Some(true)
+                } else if x != y {
+                    return Some(false)
+                }
+            });
+        }
+        Some(false)
+    }
+
     /// Total ordering over bitstrings, including differentiation between
     /// differing bitwidths of `self` and `rhs`. This orders first on bitwidth
     /// and then on unsigned value.
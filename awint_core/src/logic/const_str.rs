@@ -203,6 +203,55 @@ impl Bits {
         Ok(())
     }
 
+    /// Assigns to `self` the unsigned integer value represented by `src` in the
+    /// given `radix`. This is like [Bits::bytes_radix_] but does not need
+    /// scratchpads, because the value is accumulated directly in `self` with
+    /// Horner's method. This makes parsing possible in `no_std` and no-`alloc`
+    /// contexts where the bitwidth is fixed by `self`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u16);
+    /// x.from_bytes_radix_(b"12_345", 10).unwrap();
+    /// assert_eq!(x, inlawi!(12345u16));
+    /// x.from_bytes_radix_(b"fFfF", 16).unwrap();
+    /// assert_eq!(x, inlawi!(0xffffu16));
+    /// assert!(x.from_bytes_radix_(b"65536", 10).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The chars of `src` are verified in the same way as
+    /// [Bits::bytes_radix_], and `self` is not mutated if there are
+    /// `InvalidRadix` or `InvalidChar` errors. If the value of `src` cannot be
+    /// represented in `self.bw()` bits, `Overflow` is returned and `self` is
+    /// set to an unspecified value.
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn from_bytes_radix_(&mut self, src: &[u8], radix: u8) -> Result<(), SerdeError> {
+        if let Err(e) = verify_for_bytes_(src, radix) {
+            return Err(e)
+        }
+        self.zero_();
+        const_for!(i in {0..src.len()} {
+            let b = src[i];
+            if b == b'_' {
+                continue;
+            }
+            let char_digit = if radix <= 10 || b <= b'9' {
+                b.wrapping_sub(b'0')
+            } else if b <= b'Z' {
+                b.wrapping_sub(b'A').wrapping_add(10)
+            } else {
+                b.wrapping_sub(b'a').wrapping_add(10)
+            } as Digit;
+            if self.digit_cin_mul_(char_digit, radix as Digit) != 0 {
+                return Err(Overflow)
+            }
+        });
+        Ok(())
+    }
+
     /// Assigns the `[u8]` representation of `self` to `dst` (sign indicators,
     /// prefixes, and postfixes not included). `signed` specifies if `self`
     /// should be interpreted as signed. `radix` specifies the radix, and
@@ -257,22 +306,103 @@ impl Bits {
         }
     }
 
-    /// Writes the bits content as hexadecimal to `f`, with underscores every 8
-    /// digits. I have decided on including the "0x" prefix and bitwidth suffix
-    /// always, because it is confusing in `assert_` debugging otherwise.
+    /// Writes the unsigned value of `self` in the given `radix` to the start of
+    /// `buf` as lowercase ASCII digits, most significant digit first and
+    /// without leading zeros, and returns the number of bytes written. Unlike
+    /// [Bits::to_bytes_radix], no scratchpad is needed, because the digits
+    /// are accumulated directly in `buf` by doubling and adding the bits of
+    /// `self` from most to least significant. This makes formatting possible
+    /// in `no_std` and no-`alloc` contexts. A zero value is written as a
+    /// single `b'0'`. The bytes of `buf` after the returned length are set to
+    /// unspecified values. The exact maximum length needed for `buf` is
+    /// returned by [crate::max_str_len].
+    ///
+    /// Returns `None` if `radix` is not in `2..=36` or if `buf` is too small.
+    ///
+    /// ```
+    /// use awint::{inlawi, max_str_len, Bits, InlAwi};
+    ///
+    /// let mut buf = [0u8; max_str_len(16, 10)];
+    /// let len = inlawi!(12345u16)
+    ///     .to_bytes_radix_no_pad(10, &mut buf)
+    ///     .unwrap();
+    /// assert_eq!(&buf[..len], b"12345");
+    /// let len = inlawi!(0xbeefu16)
+    ///     .to_bytes_radix_no_pad(16, &mut buf)
+    ///     .unwrap();
+    /// assert_eq!(&buf[..len], b"beef");
+    /// assert!(inlawi!(12345u16)
+    ///     .to_bytes_radix_no_pad(2, &mut buf)
+    ///     .is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn to_bytes_radix_no_pad(&self, radix: u8, buf: &mut [u8]) -> Option<usize> {
+        if radix < 2 || radix > 36 || buf.is_empty() {
+            return None
+        }
+        // the digit values are accumulated at the end of `buf`, least significant
+        // digit last
+        let end = buf.len();
+        let mut len = 1;
+        buf[end - 1] = 0;
+        const_for!(i in {0..self.sig()}.rev() {
+            let mut carry = self.get(i).unwrap() as u8;
+            const_for!(j in {(end - len)..end}.rev() {
+                // digits are less than the radix, so this can only exceed it once
+                let tmp = (buf[j] << 1) | carry;
+                if tmp >= radix {
+                    buf[j] = tmp - radix;
+                    carry = 1;
+                } else {
+                    buf[j] = tmp;
+                    carry = 0;
+                }
+            });
+            if carry != 0 {
+                if len == end {
+                    return None
+                }
+                len += 1;
+                buf[end - len] = 1;
+            }
+        });
+        // move to the start and convert to chars
+        const_for!(j in {0..len} {
+            let digit = buf[end - len + j];
+            buf[j] = if digit < 10 {
+                b'0'.wrapping_add(digit)
+            } else {
+                b'a'.wrapping_add(digit).wrapping_sub(10)
+            };
+        });
+        Some(len)
+    }
+
+    /// Writes the bits content in the power-of-two radix `1 << log2` to `f`,
+    /// with the given `prefix`. `group` is the number of digits between
+    /// underscores, with no underscores being inserted if it is zero. If
+    /// `suffix` is true, a bitwidth suffix like "_u100" is appended. I have
+    /// decided on including the prefix always, because it is confusing in
+    /// `assert_` debugging otherwise.
     #[inline]
-    pub(crate) fn debug_format_hexadecimal(
+    pub(crate) fn debug_format_pow2(
         &self,
         f: &mut fmt::Formatter,
+        log2: usize,
+        prefix: &str,
         upper: bool,
+        group: usize,
+        suffix: bool,
     ) -> fmt::Result {
         self.assert_cleared_unused_bits();
-        f.write_fmt(format_args!("0x"))?;
-        const_for!(j0 in {0..(self.bw() >> 2).wrapping_add(1)}.rev() {
-            if (self.get_digit(j0 << 2) & 0b1111) != 0 {
+        let mask = (1 << log2) - 1;
+        f.write_str(prefix)?;
+        const_for!(j0 in {0..(self.bw() / log2).wrapping_add(1)}.rev() {
+            if (self.get_digit(j0.wrapping_mul(log2)) & mask) != 0 {
                 // we have reached the first nonzero character
                 const_for!(j1 in {0..j0.wrapping_add(1)}.rev() {
-                    let mut char_digit = (self.get_digit(j1 << 2) & 0b1111) as u8;
+                    let mut char_digit = (self.get_digit(j1.wrapping_mul(log2)) & mask) as u8;
                     if char_digit < 10 {
                         char_digit += b'0';
                     } else if upper {
@@ -280,39 +410,11 @@ impl Bits {
                     } else {
                         char_digit += b'a'.wrapping_sub(10);
                     }
-                    // Safety: we strictly capped the range of possible values above with `& 0b1111`
+                    // Safety: we strictly capped the range of possible values above with
+                    // `& mask`, and `log2` is at most 4
                     let c = unsafe { char::from_u32_unchecked(char_digit as u32) };
                     f.write_fmt(format_args!("{c}"))?;
-                    if ((j1 % 8) == 0) && (j1 != 0) {
-                        f.write_fmt(format_args!("_"))?;
-                    }
-                });
-                break
-            }
-            if j0 == 0 {
-                // we have reached the end without printing anything, print at least one '0'
-                f.write_fmt(format_args!("{}", '0'))?;
-            }
-        });
-        f.write_fmt(format_args!("_u{}", self.bw()))
-    }
-
-    #[inline]
-    pub(crate) fn debug_format_octal(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.assert_cleared_unused_bits();
-        f.write_fmt(format_args!("0o"))?;
-        const_for!(j0 in {0..(self.bw() / 3).wrapping_add(1)}.rev() {
-            if (self.get_digit(j0.wrapping_mul(3)) & 0b111) != 0 {
-                // we have reached the first nonzero character
-                const_for!(j1 in {0..j0.wrapping_add(1)}.rev() {
-                    let mut char_digit = (self.get_digit(j1.wrapping_mul(3)) & 0b111) as u8;
-                    char_digit += b'0';
-                    // Safety: we strictly capped the range of possible values above with `& 0b111`
-                    let c = unsafe { char::from_u32_unchecked(char_digit as u32) };
-                    if let Err(e) = f.write_fmt(format_args!("{c}")) {
-                        return Err(e)
-                    }
-                    if ((j1 % 8) == 0) && (j1 != 0) {
+                    if (j1 != 0) && matches!(j1.checked_rem(group), Some(0)) {
                         f.write_fmt(format_args!("_"))?;
                     }
                 });
@@ -323,36 +425,27 @@ impl Bits {
                 f.write_fmt(format_args!("{}", '0'))?;
             }
         });
-        f.write_fmt(format_args!("_u{}", self.bw()))
+        if suffix {
+            f.write_fmt(format_args!("_u{}", self.bw()))?;
+        }
+        Ok(())
     }
 
-    // TODO this could be optimized
+    /// Writes the bits content in the power-of-two radix `1 << log2` to `f`,
+    /// respecting the formatter flags. By default there are underscores every
+    /// 8 digits and a bitwidth suffix. The `#` alternate flag removes both the
+    /// underscores and the suffix, and the precision sets the number of digits
+    /// between underscores (with `.0` removing them).
     #[inline]
-    pub(crate) fn debug_format_binary(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.assert_cleared_unused_bits();
-        f.write_fmt(format_args!("0b"))?;
-        const_for!(j0 in {0..self.bw()}.rev() {
-            if (self.get_digit(j0) & 0b1) != 0 {
-                // we have reached the first nonzero character
-                const_for!(j1 in {0..(j0 + 1)}.rev() {
-                    let mut char_digit = (self.get_digit(j1) & 0b1) as u8;
-                    char_digit += b'0';
-                    // Safety: we strictly capped the range of possible values above with `& 0b1`
-                    let c = unsafe { char::from_u32_unchecked(char_digit as u32) };
-                    if let Err(e) = f.write_fmt(format_args!("{c}")) {
-                        return Err(e)
-                    }
-                    if ((j1 % 8) == 0) && (j1 != 0) {
-                        f.write_fmt(format_args!("_"))?;
-                    }
-                });
-                break
-            }
-            if j0 == 0 {
-                // we have reached the end without printing anything, print at least one '0'
-                f.write_fmt(format_args!("{}", '0'))?;
-            }
-        });
-        f.write_fmt(format_args!("_u{}", self.bw()))
+    pub(crate) fn debug_format_flags(
+        &self,
+        f: &mut fmt::Formatter,
+        log2: usize,
+        prefix: &str,
+        upper: bool,
+    ) -> fmt::Result {
+        let alternate = f.alternate();
+        let group = f.precision().unwrap_or(if alternate { 0 } else { 8 });
+        self.debug_format_pow2(f, log2, prefix, upper, group, !alternate)
     }
 }
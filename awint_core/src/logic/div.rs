@@ -1,3 +1,5 @@
+use core::mem;
+
 use awint_internals::*;
 use const_fn::const_fn;
 
@@ -393,4 +395,311 @@ impl Bits {
         quo.neg_(duo_msb != div_msb);
         Some(())
     }
+
+    /// Carryless-divides `self` by `div`, treating both as polynomials over
+    /// GF(2) where bit `i` is the coefficient of `x^i`. The quotient is
+    /// assigned to `self` and the remainder to `rem`, such that the degree of
+    /// `rem` is less than the degree of `div`. `pad` is used as a temporary.
+    /// Returns `None` if any bitwidths are not equal or `div.is_zero()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// // (x^4 + x + 1) / (x^2 + 1) = (x^2 + 1) rem x
+    /// let mut quo = inlawi!(0b10011_u8);
+    /// let mut rem = inlawi!(0u8);
+    /// let mut pad = inlawi!(0u8);
+    /// let div = inlawi!(0b101_u8);
+    /// quo.clmul_div_rem_(&div, &mut rem, &mut pad).unwrap();
+    /// assert_eq!(quo, inlawi!(0b101_u8));
+    /// assert_eq!(rem, inlawi!(0b10_u8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn clmul_div_rem_(
+        &mut self,
+        div: &Self,
+        rem: &mut Self,
+        pad: &mut Self,
+    ) -> Option<()> {
+        let w = self.bw();
+        if div.is_zero() || w != div.bw() || w != rem.bw() || w != pad.bw() {
+            return None
+        }
+        rem.copy_(self).unwrap();
+        self.zero_();
+        let div_sig = div.sig();
+        loop {
+            let rem_sig = rem.sig();
+            if rem_sig < div_sig {
+                break
+            }
+            // cancel the leading term of `rem`
+            let s = rem_sig - div_sig;
+            pad.copy_(div).unwrap();
+            pad.shl_(s).unwrap();
+            rem.xor_(pad).unwrap();
+            self.set(s, true).unwrap();
+        }
+        Some(())
+    }
+
+    /// Computes the Jacobi symbol `(self / n)` of the unsigned values of
+    /// `self` and `n`, returning 1, -1, or 0. The Jacobi symbol is only
+    /// defined for odd positive `n`, so `None` is returned if `n` is even
+    /// (including zero). When `n` is an odd prime, this is the Legendre
+    /// symbol, which is 1 if `self` is a nonzero quadratic residue modulo `n`,
+    /// -1 if it is a nonresidue, and 0 if `n` divides `self`.
+    ///
+    /// `pads` is a set of scratchpads that will be mutated arbitrarily. It
+    /// must have at least 4 elements, and the bitwidths of all the scratchpads
+    /// and of `n` must equal `self.bw()`, otherwise `None` is returned.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut pads = [inlawi!(0u16); 4];
+    /// let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.const_as_mut()).collect();
+    /// // 2 is a square modulo 7 because 3 * 3 = 9 = 2 (mod 7)
+    /// assert_eq!(inlawi!(2u16).jacobi(&inlawi!(7u16), &mut pads), Some(1));
+    /// assert_eq!(inlawi!(3u16).jacobi(&inlawi!(7u16), &mut pads), Some(-1));
+    /// assert_eq!(inlawi!(14u16).jacobi(&inlawi!(7u16), &mut pads), Some(0));
+    /// // 1001 = 7 * 11 * 13
+    /// assert_eq!(
+    ///     inlawi!(19u16).jacobi(&inlawi!(1001u16), &mut pads),
+    ///     Some(-1)
+    /// );
+    /// // for composite `n` a result of 1 does not imply a square, 2 is not a
+    /// // square modulo 15
+    /// assert_eq!(inlawi!(2u16).jacobi(&inlawi!(15u16), &mut pads), Some(1));
+    /// assert_eq!(inlawi!(19u16).jacobi(&inlawi!(1000u16), &mut pads), None);
+    /// ```
+    #[must_use]
+    pub fn jacobi(&self, n: &Self, pads: &mut [&mut Bits]) -> Option<i8> {
+        let [a, m, quo, rem, ..] = pads else {
+            return None
+        };
+        let w = self.bw();
+        if (w != n.bw())
+            || (w != a.bw())
+            || (w != m.bw())
+            || (w != quo.bw())
+            || (w != rem.bw())
+            || !n.lsb()
+        {
+            return None
+        }
+        // we swap references instead of copying
+        let mut a: &mut Bits = a;
+        let mut m: &mut Bits = m;
+        let mut rem: &mut Bits = rem;
+        m.copy_(n).unwrap();
+        Bits::udivide(quo, rem, self, m).unwrap();
+        mem::swap(&mut a, &mut rem);
+        let mut res = 1;
+        while !a.is_zero() {
+            // (2 / m) is -1 iff `m` is 3 or 5 mod 8
+            let tz = a.tz();
+            a.lshr_(tz).unwrap();
+            let m_mod8 = m.to_u8() & 0b111;
+            if ((tz & 1) != 0) && ((m_mod8 == 3) || (m_mod8 == 5)) {
+                res = -res;
+            }
+            // quadratic reciprocity, `a` and `m` are both odd here
+            mem::swap(&mut a, &mut m);
+            if ((a.to_u8() & 0b11) == 3) && ((m.to_u8() & 0b11) == 3) {
+                res = -res;
+            }
+            Bits::udivide(quo, rem, a, m).unwrap();
+            mem::swap(&mut a, &mut rem);
+        }
+        if m.is_uone() {
+            Some(res)
+        } else {
+            Some(0)
+        }
+    }
+
+    /// Computes the greatest common divisor of the unsigned values of `a` and
+    /// `b` using the extended Euclidean algorithm. The gcd is assigned to
+    /// `a`, and Bézout coefficients satisfying `a_orig * x + b_orig * y ==
+    /// gcd` are assigned to `x` and `y` as signed values. `b` is zeroed.
+    ///
+    /// The coefficients are the minimal ones, with `|x| <= b_orig / (2 * gcd)`
+    /// and `|y| <= a_orig / (2 * gcd)` unless one input is a multiple of the
+    /// other, in which case they are 0 or 1. This means that the coefficients
+    /// always fit in the signed range of the shared bitwidth if it is at least
+    /// 2, without needing any extra bits beyond those needed for the unsigned
+    /// inputs. With a bitwidth of 1 the equation still holds modulo 2.
+    ///
+    /// `pads` is a set of scratchpads that will be mutated arbitrarily. It
+    /// must have at least 5 elements, and `None` is returned if it does not or
+    /// if the bitwidths of any of the arguments or scratchpads are not all
+    /// equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut pads = [inlawi!(0i16); 5];
+    /// let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.const_as_mut()).collect();
+    /// let mut a = inlawi!(240i16);
+    /// let mut b = inlawi!(46i16);
+    /// let mut x = inlawi!(0i16);
+    /// let mut y = inlawi!(0i16);
+    /// Bits::extended_gcd_(&mut a, &mut b, &mut x, &mut y, &mut pads).unwrap();
+    /// assert_eq!(a, inlawi!(2i16));
+    /// // 240 * -9 + 46 * 47 == 2
+    /// assert_eq!(x, inlawi!(-9i16));
+    /// assert_eq!(y, inlawi!(47i16));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn extended_gcd_(
+        a: &mut Self,
+        b: &mut Self,
+        x: &mut Self,
+        y: &mut Self,
+        pads: &mut [&mut Bits],
+    ) -> Option<()> {
+        let [s, t, quo, rem, prod, ..] = pads else {
+            return None
+        };
+        let w = a.bw();
+        if (w != b.bw())
+            || (w != x.bw())
+            || (w != y.bw())
+            || (w != s.bw())
+            || (w != t.bw())
+            || (w != quo.bw())
+            || (w != rem.bw())
+            || (w != prod.bw())
+        {
+            return None
+        }
+        // invariants: `a_orig * x + b_orig * y == a` and `a_orig * s + b_orig * t
+        // == b`. Wrapping arithmetic is exact modulo `2^w`, and the final
+        // coefficients are small enough to be correct as signed values.
+        x.uone_();
+        y.zero_();
+        s.zero_();
+        t.uone_();
+        while !b.is_zero() {
+            Bits::udivide(quo, rem, a, b).unwrap();
+            a.copy_(b).unwrap();
+            b.copy_(rem).unwrap();
+            // `(x, s) = (s, x - quo * s)`
+            prod.zero_();
+            prod.mul_add_(quo, s).unwrap();
+            rem.copy_(x).unwrap();
+            rem.sub_(prod).unwrap();
+            x.copy_(s).unwrap();
+            s.copy_(rem).unwrap();
+            // `(y, t) = (t, y - quo * t)`
+            prod.zero_();
+            prod.mul_add_(quo, t).unwrap();
+            rem.copy_(y).unwrap();
+            rem.sub_(prod).unwrap();
+            y.copy_(t).unwrap();
+            t.copy_(rem).unwrap();
+        }
+        Some(())
+    }
+
+    /// Modular-exponentiation-assigns `self`, setting it to `self^exp mod
+    /// modulus` where all values are unsigned. The exponent is processed from
+    /// the most significant end `window` bits at a time, using a precomputed
+    /// table of the powers `self^0` through `self^(2^window - 1)`. The same
+    /// sequence of squarings and multiplications is performed for any `exp`
+    /// value of the same bitwidth, and table entries are selected by
+    /// [Bits::conditional_assign_] on every entry so that the memory access
+    /// pattern does not depend on `exp`. Note that this does not make the
+    /// whole function constant-time, because the division used for modular
+    /// reduction is not constant-time.
+    ///
+    /// `pads` is a set of scratchpads that will be mutated arbitrarily. It
+    /// must have at least `5 + 2^window` elements, each with a bitwidth of
+    /// twice `self.bw()`. `None` is returned if it does not, if `window` is
+    /// zero or larger than the bitwidth of a `Digit`, if `modulus.bw() !=
+    /// self.bw()`, or if `modulus` is zero. `exp` can have any bitwidth.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut pads = [inlawi!(0u128); 9];
+    /// let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.const_as_mut()).collect();
+    /// let mut x = inlawi!(4u64);
+    /// let m = inlawi!(497u64);
+    /// x.mod_pow_windowed_(&inlawi!(13u8), &m, 2, &mut pads)
+    ///     .unwrap();
+    /// assert_eq!(x, inlawi!(445u64));
+    /// // Fermat's little theorem with the prime `2^61 - 1`
+    /// let p = inlawi!(0x1fff_ffff_ffff_ffff_u64);
+    /// let mut exp = p;
+    /// exp.dec_(false);
+    /// let mut x = inlawi!(12345u64);
+    /// x.mod_pow_windowed_(&exp, &p, 2, &mut pads).unwrap();
+    /// assert_eq!(x, inlawi!(1u64));
+    /// ```
+    // `Digit` is `usize` on some platforms
+    #[allow(clippy::unnecessary_cast)]
+    #[must_use]
+    pub fn mod_pow_windowed_(
+        &mut self,
+        exp: &Self,
+        modulus: &Self,
+        window: usize,
+        pads: &mut [&mut Bits],
+    ) -> Option<()> {
+        if (window == 0) || (window > BITS) || (window >= USIZE_BITS) {
+            return None
+        }
+        let table_len = 1usize << window;
+        if (self.bw() != modulus.bw()) || modulus.is_zero() || (pads.len() < (5 + table_len)) {
+            return None
+        }
+        let w = self.bw() * 2;
+        if pads[..(5 + table_len)].iter().any(|pad| pad.bw() != w) {
+            return None
+        }
+        let (fixed, table) = pads.split_at_mut(5);
+        let table = &mut table[..table_len];
+        let [m, acc, prod, quo, sel] = fixed else {
+            return None
+        };
+        m.zero_resize_(modulus);
+        // `table[i] = self^i mod m`
+        acc.uone_();
+        Bits::udivide(quo, table[0], acc, m).unwrap();
+        acc.zero_resize_(self);
+        Bits::udivide(quo, table[1], acc, m).unwrap();
+        for i in 2..table_len {
+            let (lo, hi) = table.split_at_mut(i);
+            prod.zero_();
+            prod.mul_add_(lo[i - 1], lo[1]).unwrap();
+            Bits::udivide(quo, hi[0], prod, m).unwrap();
+        }
+        acc.copy_(table[0]).unwrap();
+        let mask = MAX >> (BITS - window);
+        // `exp.bw() != 0`, so this is `ceil(exp.bw() / window)`
+        let windows = ((exp.bw() - 1) / window) + 1;
+        for j in (0..windows).rev() {
+            for _ in 0..window {
+                prod.zero_();
+                prod.mul_add_(acc, acc).unwrap();
+                Bits::udivide(quo, acc, prod, m).unwrap();
+            }
+            // `get_digit` zeroes bits beyond `exp.bw()`
+            let digit = exp.get_digit(j * window) & mask;
+            sel.zero_();
+            for (i, entry) in table.iter().enumerate() {
+                sel.conditional_assign_(entry, i == (digit as usize))
+                    .unwrap();
+            }
+            prod.zero_();
+            prod.mul_add_(acc, sel).unwrap();
+            Bits::udivide(quo, acc, prod, m).unwrap();
+        }
+        self.zero_resize_(acc);
+        Some(())
+    }
 }
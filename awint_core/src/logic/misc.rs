@@ -1,4 +1,8 @@
-use core::ptr;
+use core::{
+    num::NonZeroUsize,
+    ops::Range,
+    ptr::{self, NonNull},
+};
 
 use awint_internals::*;
 use const_fn::const_fn;
@@ -94,6 +98,61 @@ impl Bits {
         (self.total_digits() * BITS) - self.unused()
     }
 
+    /// Returns the number of leading bits equal to `value`, starting from the
+    /// most significant bit. This is [Bits::lz] if `value` is `false`, and
+    /// the number of leading ones if `value` is `true`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    /// let x = inlawi!(0b1110_0100u8);
+    /// assert_eq!(x.leading_run(true), 3);
+    /// assert_eq!(x.leading_run(false), 0);
+    /// assert_eq!(x.trailing_run(false), 2);
+    /// assert_eq!(x.trailing_run(true), 0);
+    /// assert_eq!(inlawi!(umax: ..100).leading_run(true), 100);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn leading_run(&self, value: bool) -> usize {
+        self.assert_cleared_unused_bits();
+        // inverting for runs of ones, the unused bits are masked back to zero
+        // which makes the calculation the same as `lz`
+        let flip = if value { MAX } else { 0 };
+        let last = self.total_digits() - 1;
+        const_for!(i in {0..self.total_digits()}.rev() {
+            let mut x = unsafe{self.get_unchecked(i)} ^ flip;
+            if (i == last) && (self.extra() != 0) {
+                x &= MAX >> self.unused();
+            }
+            if x != 0 {
+                return ((self.total_digits() - 1 - i) * BITS)
+                    + (x.leading_zeros() as usize) - self.unused();
+            }
+        });
+        self.bw()
+    }
+
+    /// Returns the number of trailing bits equal to `value`, starting from the
+    /// least significant bit. This is [Bits::tz] if `value` is `false`, and
+    /// the number of trailing ones if `value` is `true`.
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn trailing_run(&self, value: bool) -> usize {
+        self.assert_cleared_unused_bits();
+        let flip = if value { MAX } else { 0 };
+        let last = self.total_digits() - 1;
+        const_for!(i in {0..self.total_digits()} {
+            let mut x = unsafe{self.get_unchecked(i)} ^ flip;
+            if (i == last) && (self.extra() != 0) {
+                x &= MAX >> self.unused();
+            }
+            if x != 0 {
+                return (i * BITS) + (x.trailing_zeros() as usize);
+            }
+        });
+        self.bw()
+    }
+
     /// Returns the number of significant bits, `self.bw() - self.lz()`
     #[const_fn(cfg(feature = "const_support"))]
     #[must_use]
@@ -116,6 +175,273 @@ impl Bits {
         ones
     }
 
+    /// Returns the number of set ones with bit indexes in `range`. An empty or
+    /// reversed range returns `Some(0)`. `None` is returned if `range.start >
+    /// self.bw()` or `range.end > self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0b1011_0110_1001_u12);
+    /// assert_eq!(x.count_ones_in_range(0..12), Some(x.count_ones()));
+    /// assert_eq!(x.count_ones_in_range(4..8), Some(2));
+    /// assert_eq!(x.count_ones_in_range(7..7), Some(0));
+    /// assert_eq!(x.count_ones_in_range(4..13), None);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn count_ones_in_range(&self, range: Range<usize>) -> Option<usize> {
+        if range.start > self.bw() || range.end > self.bw() {
+            return None
+        }
+        if range.start >= range.end {
+            return Some(0)
+        }
+        let start = digits_u(range.start);
+        let end = digits_u(range.end);
+        let start_bits = extra_u(range.start);
+        let end_bits = extra_u(range.end);
+        // Safety: the early `None` return above prevents any out of bounds indexing.
+        unsafe {
+            if start == end {
+                // The range is entirely contained in one digit, `end_bits` is nonzero
+                let x =
+                    self.get_unchecked(start) & (MAX << start_bits) & (MAX >> (BITS - end_bits));
+                return Some(x.count_ones() as usize)
+            }
+            let mut ones = (self.get_unchecked(start) & (MAX << start_bits)).count_ones() as usize;
+            const_for!(i in {(start + 1)..end} {
+                ones += self.get_unchecked(i).count_ones() as usize;
+            });
+            if end_bits != 0 {
+                // Avoid overshift from `(BITS - end_bits)`
+                ones +=
+                    (self.get_unchecked(end) & (MAX >> (BITS - end_bits))).count_ones() as usize;
+            }
+            Some(ones)
+        }
+    }
+
+    /// Returns the index of the least significant set bit at or above `start`,
+    /// or `None` if there is no such bit or `start >= self.bw()`. Together
+    /// with [Bits::prev_set_bit_from], this can be used to efficiently iterate
+    /// over set bits.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0b1000_0010_0100_u12);
+    /// assert_eq!(x.next_set_bit_from(0), Some(2));
+    /// assert_eq!(x.next_set_bit_from(3), Some(5));
+    /// assert_eq!(x.next_set_bit_from(5), Some(5));
+    /// assert_eq!(x.next_set_bit_from(12), None);
+    /// let mut v = vec![];
+    /// let mut i = 0;
+    /// while let Some(j) = x.next_set_bit_from(i) {
+    ///     v.push(j);
+    ///     i = j + 1;
+    /// }
+    /// assert_eq!(v, [2, 5, 11]);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn next_set_bit_from(&self, start: usize) -> Option<usize> {
+        if start >= self.bw() {
+            return None
+        }
+        self.assert_cleared_unused_bits();
+        let mut i = digits_u(start);
+        // mask off the bits below `start` in the starting partial digit
+        let mut x = unsafe { self.get_unchecked(i) } & (MAX << extra_u(start));
+        loop {
+            if x != 0 {
+                return Some((i * BITS) + (x.trailing_zeros() as usize))
+            }
+            i += 1;
+            if i >= self.total_digits() {
+                return None
+            }
+            x = unsafe { self.get_unchecked(i) };
+        }
+    }
+
+    /// Returns the index of the most significant set bit at or below `start`,
+    /// or `None` if there is no such bit or `start >= self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0b1000_0010_0100_u12);
+    /// assert_eq!(x.prev_set_bit_from(11), Some(11));
+    /// assert_eq!(x.prev_set_bit_from(10), Some(5));
+    /// assert_eq!(x.prev_set_bit_from(1), None);
+    /// assert_eq!(x.prev_set_bit_from(12), None);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn prev_set_bit_from(&self, start: usize) -> Option<usize> {
+        if start >= self.bw() {
+            return None
+        }
+        let mut i = digits_u(start);
+        // mask off the bits above `start` in the starting partial digit
+        let mut x = unsafe { self.get_unchecked(i) } & (MAX >> (BITS - 1 - extra_u(start)));
+        loop {
+            if x != 0 {
+                return Some((i * BITS) + (BITS - 1 - (x.leading_zeros() as usize)))
+            }
+            if i == 0 {
+                return None
+            }
+            i -= 1;
+            x = unsafe { self.get_unchecked(i) };
+        }
+    }
+
+    /// Priority encoder, returning the index of the most significant set bit
+    /// of `self`, or `None` if `self.is_zero()`. This is the same as
+    /// `self.bw() - 1 - self.lz()` for nonzero values.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// assert_eq!(inlawi!(0b0010_0100_u8).priority_encode(), Some(5));
+    /// assert_eq!(inlawi!(0u8).priority_encode(), None);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn priority_encode(&self) -> Option<usize> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.bw() - 1 - self.lz())
+        }
+    }
+
+    /// One-hot decoder, returning the index of the set bit if exactly one bit
+    /// of `self` is set, otherwise returning `None`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// assert_eq!(inlawi!(0b0010_0000_u8).one_hot_decode(), Some(5));
+    /// assert_eq!(inlawi!(0b0010_0100_u8).one_hot_decode(), None);
+    /// assert_eq!(inlawi!(0u8).one_hot_decode(), None);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn one_hot_decode(&self) -> Option<usize> {
+        if self.count_ones() == 1 {
+            Some(self.tz())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the parity of `self`, which is the XOR of all the bits. This
+    /// is equivalent to `(self.count_ones() & 1) == 1`.
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn parity(&self) -> bool {
+        self.assert_cleared_unused_bits();
+        let mut x: Digit = 0;
+        const_for!(i in {0..self.total_digits()} {
+            x ^= unsafe{self.get_unchecked(i)};
+        });
+        (x.count_ones() & 1) == 1
+    }
+
+    /// OR reduction, returning if any bit of `self` is set. This is the same
+    /// as `!self.is_zero()`, under the name used in hardware description
+    /// languages.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0b0110u4);
+    /// assert!(x.or_reduce());
+    /// assert!(!x.and_reduce());
+    /// assert!(!x.xor_reduce());
+    /// ```
+    #[inline]
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn or_reduce(&self) -> bool {
+        !self.is_zero()
+    }
+
+    /// AND reduction, returning if all bits of `self` are set. This is the
+    /// same as [Bits::is_umax].
+    #[inline]
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn and_reduce(&self) -> bool {
+        self.is_umax()
+    }
+
+    /// XOR reduction, returning if an odd number of bits of `self` are set.
+    /// This is the same as [Bits::parity].
+    #[inline]
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn xor_reduce(&self) -> bool {
+        self.parity()
+    }
+
+    /// Sets the bit at `inx` so that the parity of `self` becomes even if
+    /// `odd` is false, or odd if `odd` is true. The previous value of the bit
+    /// at `inx` is ignored. Returns `None` if `inx >= self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b1011_0110_0u9);
+    /// x.set_parity_bit_(0, false).unwrap();
+    /// assert_eq!(x, inlawi!(0b1011_0110_1u9));
+    /// assert!(!x.parity());
+    /// x.set_parity_bit_(0, true).unwrap();
+    /// assert_eq!(x, inlawi!(0b1011_0110_0u9));
+    /// assert!(x.parity());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn set_parity_bit_(&mut self, inx: usize, odd: bool) -> Option<()> {
+        if inx >= self.bw() {
+            return None
+        }
+        self.set(inx, false).unwrap();
+        let p = self.parity() != odd;
+        self.set(inx, p)
+    }
+
+    /// Returns the inner product of `self` and `rhs` as vectors over GF(2),
+    /// which is the parity of `self & rhs`. This is computed digit by digit
+    /// without a temporary. Returns `None` if `self.bw() != rhs.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0b1011_0110u8);
+    /// // two common set bits
+    /// assert!(!x.gf2_dot(&inlawi!(0b0000_0110u8)).unwrap());
+    /// // three common set bits
+    /// assert!(x.gf2_dot(&inlawi!(0b1000_0110u8)).unwrap());
+    /// assert!(x.gf2_dot(&inlawi!(0u9)).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn gf2_dot(&self, rhs: &Self) -> Option<bool> {
+        if self.bw() != rhs.bw() {
+            return None
+        }
+        let mut x: Digit = 0;
+        const_for!(i in {0..self.total_digits()} {
+            // Safety: the bitwidths are equal, so `i` is within both
+            x ^= unsafe { self.get_unchecked(i) & rhs.get_unchecked(i) };
+        });
+        Some((x.count_ones() & 1) == 1)
+    }
+
     /// "Fielding" bitfields with targeted copy assigns. The bitwidths of `self`
     /// and `rhs` do not have to be equal, but the inputs must collectively obey
     /// `width <= self.bw() && width <= rhs.bw() && to <= (self.bw() - width)
@@ -169,10 +495,12 @@ impl Bits {
         // `misc.rs`, and `macro_fuzzing.rs`. There are debug asserts for out of bounds.
         unsafe {
             if (bw_digits != 0) && (from_bits == 0) && (to_bits == 0) {
-                const_for!(i in {0..bw_digits} {
-                    *self.get_unchecked_mut(i + to_digits) =
-                        rhs.get_unchecked(i + from_digits);
-                });
+                // `self` and `rhs` cannot alias because of the `&mut`
+                ptr::copy_nonoverlapping(
+                    rhs.as_ptr().add(from_digits),
+                    self.as_mut_ptr().add(to_digits),
+                    bw_digits,
+                );
                 // handle last digit
                 if bw_bits != 0 {
                     let to_mask = MAX << bw_bits;
@@ -252,6 +580,32 @@ impl Bits {
         Some(())
     }
 
+    /// The same as [Bits::field], except that `to` and `from` must be
+    /// multiples of `BITS` or else `None` is returned. This guarantees that
+    /// the bulk of the field is copied with a single `memcpy` of whole digits,
+    /// with only a partial last digit needing masking.
+    ///
+    /// # Portability
+    ///
+    /// Whether `to` and `from` are aligned depends on the size of `Digit`,
+    /// which varies between architectures and features. [Bits::field] always
+    /// uses the same fast path internally when it can, so this should only be
+    /// used when the alignment is known to hold for all targets.
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn copy_digit_aligned_(
+        &mut self,
+        to: usize,
+        rhs: &Self,
+        from: usize,
+        width: usize,
+    ) -> Option<()> {
+        if (extra_u(to) != 0) || (extra_u(from) != 0) {
+            return None
+        }
+        self.field(to, rhs, from, width)
+    }
+
     /// A specialization of [Bits::field] with `from` set to 0.
     #[const_fn(cfg(feature = "const_support"))]
     #[must_use]
@@ -620,9 +974,87 @@ impl Bits {
         }
     }
 
+    /// Conditionally swaps the bits of `a` and `b` if `swap` is true. This
+    /// uses a masked XOR for every digit regardless of `swap`, so there are no
+    /// data dependent branches, which is useful for constant-time selection
+    /// networks. `None` is returned if the bitwidths of `a` and `b` are not
+    /// equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut a = inlawi!(0x123u100);
+    /// let mut b = inlawi!(0x456u100);
+    /// Bits::cswap(&mut a, &mut b, false).unwrap();
+    /// assert_eq!((a, b), (inlawi!(0x123u100), inlawi!(0x456u100)));
+    /// Bits::cswap(&mut a, &mut b, true).unwrap();
+    /// assert_eq!((a, b), (inlawi!(0x456u100), inlawi!(0x123u100)));
+    /// assert!(Bits::cswap(&mut a, &mut inlawi!(0u99), true).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn cswap(a: &mut Bits, b: &mut Bits, swap: bool) -> Option<()> {
+        if a.bw() != b.bw() {
+            return None
+        }
+        let mask = (0 as Digit).wrapping_sub(swap as Digit);
+        // Safety: `a` and `b` have the same number of digits
+        unsafe {
+            const_for!(i in {0..a.total_digits()} {
+                let t = (a.get_unchecked(i) ^ b.get_unchecked(i)) & mask;
+                *a.get_unchecked_mut(i) ^= t;
+                *b.get_unchecked_mut(i) ^= t;
+            });
+        }
+        Some(())
+    }
+
+    /// Conditionally copy-assigns `src` to `self` if `cond` is true. This is
+    /// the same as [Bits::mux_] except that it uses a masked XOR for every
+    /// digit regardless of `cond`, so there are no data dependent branches.
+    /// `None` is returned if the bitwidths of `self` and `src` are not equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x123u100);
+    /// x.conditional_assign_(&inlawi!(0x456u100), false).unwrap();
+    /// assert_eq!(x, inlawi!(0x123u100));
+    /// x.conditional_assign_(&inlawi!(0x456u100), true).unwrap();
+    /// assert_eq!(x, inlawi!(0x456u100));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn conditional_assign_(&mut self, src: &Bits, cond: bool) -> Option<()> {
+        if self.bw() != src.bw() {
+            return None
+        }
+        let mask = (0 as Digit).wrapping_sub(cond as Digit);
+        // Safety: `self` and `src` have the same number of digits
+        unsafe {
+            const_for!(i in {0..self.total_digits()} {
+                *self.get_unchecked_mut(i) ^= (self.get_unchecked(i) ^ src.get_unchecked(i)) & mask;
+            });
+        }
+        Some(())
+    }
+
     /// Repeat-assigns `self` by `rhs`. This is logically equivalent to
     /// concatenating an infinite number of `rhs` bit strings together, then
     /// resize-assigning to `self`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u10);
+    /// x.repeat_(&inlawi!(0b011u3));
+    /// assert_eq!(x, inlawi!(0b1_011_011_011u10));
+    /// // a single bit `rhs` fills all of `self`
+    /// x.repeat_(&inlawi!(1u1));
+    /// assert!(x.is_umax());
+    /// x.repeat_(&inlawi!(0u1));
+    /// assert!(x.is_zero());
+    /// ```
     #[const_fn(cfg(feature = "const_support"))]
     pub const fn repeat_(&mut self, rhs: &Bits) {
         let w = rhs.bw();
@@ -686,4 +1118,239 @@ impl Bits {
             self.clear_unused_bits();
         }
     }
+
+    /// Replicate-assigns `self` by tiling `pattern` across it as many times as
+    /// fit, starting from the least significant bit, with the final partial
+    /// copy truncated. This has the same semantics as [Bits::repeat_], but
+    /// returns an `Option` for consistency with the other fallible fielding
+    /// functions. Because `Bits` cannot have a zero bitwidth, this always
+    /// returns `Some`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u14);
+    /// x.replicate_(&inlawi!(0b101u3)).unwrap();
+    /// assert_eq!(x, inlawi!(0b01_101_101_101_101u14));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn replicate_(&mut self, pattern: &Bits) -> Option<()> {
+        self.repeat_(pattern);
+        Some(())
+    }
+
+    /// Binary searches the unsigned range `[0, 2^self.bw())` for the largest
+    /// value for which `pred` returns `true`, and assigns it to `self`. `pred`
+    /// is assumed to be monotone, returning `true` for all values up to some
+    /// boundary and `false` for all values after it. Returns `None` and leaves
+    /// `self` zeroed if `pred` is `false` for zero.
+    ///
+    /// The search determines the bits of the result from most to least
+    /// significant, so that each candidate is the midpoint `lo + 2^i` of the
+    /// remaining range `[lo, lo + 2^(i + 1))`. This never overflows, unlike
+    /// `(lo + hi) / 2` would for `hi = 2^self.bw()`. `pred` is called
+    /// `self.bw()` times, plus once more if the result is zero.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// // integer square root of 1000
+    /// let mut x = inlawi!(0u16);
+    /// x.find_monotone_boundary(|x| {
+    ///     let x = x.to_u32();
+    ///     x * x <= 1000
+    /// })
+    /// .unwrap();
+    /// assert_eq!(x.to_u16(), 31);
+    /// assert!(x.find_monotone_boundary(|_| false).is_none());
+    /// ```
+    #[must_use]
+    pub fn find_monotone_boundary(&mut self, mut pred: impl FnMut(&Bits) -> bool) -> Option<()> {
+        self.zero_();
+        for i in (0..self.bw()).rev() {
+            self.set(i, true).unwrap();
+            if !pred(self) {
+                self.set(i, false).unwrap();
+            }
+        }
+        if self.is_zero() && !pred(self) {
+            return None
+        }
+        Some(())
+    }
+
+    /// Returns if `self` is a multiple of `align`, which must be a power of
+    /// two. Returns `None` if `align` is not a power of two or if the
+    /// bitwidths are not equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// assert_eq!(
+    ///     inlawi!(0x1240u16).is_aligned_to(&inlawi!(0x40u16)),
+    ///     Some(true)
+    /// );
+    /// assert_eq!(
+    ///     inlawi!(0x1240u16).is_aligned_to(&inlawi!(0x80u16)),
+    ///     Some(false)
+    /// );
+    /// assert_eq!(inlawi!(0x1240u16).is_aligned_to(&inlawi!(0x30u16)), None);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn is_aligned_to(&self, align: &Self) -> Option<bool> {
+        if (self.bw() != align.bw()) || (align.count_ones() != 1) {
+            return None
+        }
+        Some(self.tz() >= align.tz())
+    }
+
+    /// Rounds `self` down to the nearest multiple of `align`, which must be a
+    /// power of two. Returns `None` if `align` is not a power of two or if the
+    /// bitwidths are not equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x1234u16);
+    /// x.align_down_(&inlawi!(0x100u16)).unwrap();
+    /// assert_eq!(x, inlawi!(0x1200u16));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn align_down_(&mut self, align: &Self) -> Option<()> {
+        if (self.bw() != align.bw()) || (align.count_ones() != 1) {
+            return None
+        }
+        self.range_and_(align.tz()..self.bw())
+    }
+
+    /// Rounds `self` up to the nearest multiple of `align`, which must be a
+    /// power of two. Returns `Some(true)` if the result overflowed and wrapped
+    /// around to zero. `pad` is a scratchpad that will be mutated
+    /// arbitrarily. Returns `None` if `align` is not a power of two or if the
+    /// bitwidths are not all equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut pad = inlawi!(0u16);
+    /// let mut x = inlawi!(0x1234u16);
+    /// assert_eq!(x.align_up_(&inlawi!(0x100u16), &mut pad), Some(false));
+    /// assert_eq!(x, inlawi!(0x1300u16));
+    /// // already aligned values are unchanged
+    /// assert_eq!(x.align_up_(&inlawi!(0x100u16), &mut pad), Some(false));
+    /// assert_eq!(x, inlawi!(0x1300u16));
+    /// let mut y = inlawi!(0xff01u16);
+    /// assert_eq!(y.align_up_(&inlawi!(0x100u16), &mut pad), Some(true));
+    /// assert_eq!(y, inlawi!(0u16));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn align_up_(&mut self, align: &Self, pad: &mut Self) -> Option<bool> {
+        if (self.bw() != align.bw()) || (self.bw() != pad.bw()) || (align.count_ones() != 1) {
+            return None
+        }
+        let k = align.tz();
+        if self.tz() >= k {
+            return Some(false)
+        }
+        self.range_and_(k..self.bw()).unwrap();
+        pad.copy_(self).unwrap();
+        let (oflow, _) = self.cin_sum_(false, pad, align).unwrap();
+        Some(oflow)
+    }
+
+    /// Splits `self` into `self.bw() / lane_bw` lanes of bitwidth `lane_bw`,
+    /// starting from the least significant bit, and calls `f` on a temporary
+    /// view of each lane in order. Any changes `f` makes to a lane are written
+    /// back to `self`. This allows SWAR style algorithms that operate on
+    /// small integers packed into a single `Bits`. Returns `None` if `lane_bw`
+    /// does not evenly divide `self.bw()`.
+    ///
+    /// No allocation is needed, the lane views are formed in place by
+    /// temporarily rotating the digits containing each lane. If `f` panics,
+    /// the digits are still rotated back, so `self` keeps the lanes that were
+    /// already mapped along with whatever `f` wrote to the panicking lane.
+    ///
+    /// ```
+    /// use awint::{bw, inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x7f_04_ff_00_u32);
+    /// x.lanes_map_(bw(8), |lane| {
+    ///     lane.inc_(true);
+    /// })
+    /// .unwrap();
+    /// assert_eq!(x, inlawi!(0x80_05_00_01_u32));
+    /// assert!(x.lanes_map_(bw(5), |_| ()).is_none());
+    /// ```
+    #[must_use]
+    pub fn lanes_map_(
+        &mut self,
+        lane_bw: NonZeroUsize,
+        mut f: impl FnMut(&mut Bits),
+    ) -> Option<()> {
+        let lane_bw = lane_bw.get();
+        if self.bw().wrapping_rem(lane_bw) != 0 {
+            return None
+        }
+        /// Undoes the temporary rotation of a lane when dropped, so that `self`
+        /// is not corrupted if `f` panics
+        struct LaneGuard {
+            ptr: NonNull<Digit>,
+            bw: NonZeroUsize,
+            s0: usize,
+            saved: Option<(usize, Digit)>,
+        }
+
+        impl Drop for LaneGuard {
+            fn drop(&mut self) {
+                // Safety: this is the same region that `subdigits_mut!` gave us, and
+                // the lane view is no longer in use
+                let sub =
+                    unsafe { Bits::from_raw_parts_mut(RawBits::from_raw_parts(self.ptr, self.bw)) };
+                if let Some((i, saved)) = self.saved {
+                    sub.as_mut_slice()[i] |= saved;
+                }
+                sub.rotl_(self.s0).unwrap();
+            }
+        }
+
+        let lane_digits = lane_bw / BITS;
+        let lane_extra = lane_bw % BITS;
+        for lane in 0..(self.bw() / lane_bw) {
+            let start = lane * lane_bw;
+            let range = (start / BITS)..(((start + lane_bw - 1) / BITS) + 1);
+            let s0 = start % BITS;
+            // Safety: `range` is a nonempty range within the digits of `self`. The
+            // subdigits have enough bits after rotating the lane to the start, and
+            // the lane view upholds the invariants of `Bits` because we clear the bits
+            // in its last digit beyond `lane_bw` while it exists. Both the lane view
+            // and the guard are derived from the same raw pointer, and `sub` is not
+            // used again after it is taken.
+            unsafe {
+                subdigits_mut!(self, range, sub, {
+                    sub.rotr_(s0).unwrap();
+                    let mut saved = None;
+                    if lane_extra != 0 {
+                        let last = sub.get_unchecked_mut(lane_digits);
+                        let tmp = *last & (MAX << lane_extra);
+                        *last &= !tmp;
+                        saved = Some((lane_digits, tmp));
+                    }
+                    let bw = NonZeroUsize::new_unchecked(sub.bw());
+                    let ptr = NonNull::new_unchecked(sub.as_mut_ptr());
+                    let guard = LaneGuard { ptr, bw, s0, saved };
+                    let view = Bits::from_raw_parts_mut(RawBits::from_raw_parts(
+                        ptr,
+                        NonZeroUsize::new_unchecked(lane_bw),
+                    ));
+                    f(view);
+                    drop(guard);
+                });
+            }
+        }
+        Some(())
+    }
 }
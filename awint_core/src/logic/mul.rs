@@ -7,7 +7,18 @@ use crate::Bits;
 
 /// # Multiplication
 impl Bits {
-    /// Assigns `cin + (self * rhs)` to `self` and returns the overflow
+    /// Assigns `cin + (self * rhs)` to `self` and returns the overflow. The
+    /// overflow is the `Digit` that would have been the next most significant
+    /// bits of the result, so this can be chained across multiple `Bits` for
+    /// scaling by small constants without a full width multiplier.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x1234u16);
+    /// assert_eq!(x.digit_cin_mul_(5, 0x10), 0x1);
+    /// assert_eq!(x, inlawi!(0x2345u16));
+    /// ```
     #[const_fn(cfg(feature = "const_support"))]
     pub const fn digit_cin_mul_(&mut self, cin: Digit, rhs: Digit) -> Digit {
         let mut carry = cin;
@@ -53,8 +64,21 @@ impl Bits {
         Some((mul_carry != 0) || (add_carry != 0))
     }
 
-    /// Multiplies `lhs` by `rhs` and add-assigns the product to `self`. Three
-    /// operands eliminates the need for an allocating temporary.
+    /// Multiplies `lhs` by `rhs` and add-assigns the product to `self`, or in
+    /// other words sets `self` to `self + (lhs * rhs)` with wrapping to
+    /// `self.bw()`. Three operands eliminates the need for an allocating
+    /// temporary. `None` is returned if any bitwidths do not match.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut acc = inlawi!(10u8);
+    /// acc.mul_add_(&inlawi!(3u8), &inlawi!(4u8)).unwrap();
+    /// assert_eq!(acc, inlawi!(22u8));
+    /// // wrapping
+    /// acc.mul_add_(&inlawi!(16u8), &inlawi!(16u8)).unwrap();
+    /// assert_eq!(acc, inlawi!(22u8));
+    /// ```
     #[const_fn(cfg(feature = "const_support"))]
     #[must_use]
     pub const fn mul_add_(&mut self, lhs: &Self, rhs: &Self) -> Option<()> {
@@ -109,6 +133,93 @@ impl Bits {
         Some(())
     }
 
+    /// Signed-multiply-assigns `self` by `rhs`, and returns if the signed
+    /// product was truncated. The resulting bits of `self` are the same as
+    /// with [Bits::mul_] (which does not distinguish signedness for wrapping
+    /// multiplication), but this additionally returns `Some(true)` if the true
+    /// signed product could not fit in a signed integer of `self.bw()` bits and
+    /// wrapped around, and `Some(false)` if the product is exact. `pad` is a
+    /// scratchpad that will be mutated arbitrarily. Returns `None` if the
+    /// bitwidths are not all equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut pad = inlawi!(0i8);
+    /// let mut x = inlawi!(-50i8);
+    /// assert_eq!(x.checked_signed_mul_(&inlawi!(2i8), &mut pad), Some(false));
+    /// assert_eq!(x, inlawi!(-100i8));
+    /// // the true product of 200 does not fit in an `i8`, and wraps to -56
+    /// let mut x = inlawi!(100i8);
+    /// assert_eq!(x.checked_signed_mul_(&inlawi!(2i8), &mut pad), Some(true));
+    /// assert_eq!(x, inlawi!(-56i8));
+    /// // -128 fits
+    /// let mut x = inlawi!(-64i8);
+    /// assert_eq!(x.checked_signed_mul_(&inlawi!(2i8), &mut pad), Some(false));
+    /// assert_eq!(x, inlawi!(-128i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn checked_signed_mul_(&mut self, rhs: &Self, pad: &mut Self) -> Option<bool> {
+        if self.bw() != rhs.bw() || self.bw() != pad.bw() {
+            return None
+        }
+        let neg = self.msb() != rhs.msb();
+        // multiply the unsigned magnitudes
+        self.abs_();
+        pad.copy_(rhs).unwrap();
+        pad.abs_();
+        let len = self.total_digits();
+        let mut overflow = false;
+        // Safety: the indexes stay within `len`. This is an inplace multiplication that
+        // goes from the most significant digit of `self` down, so that the partial
+        // products only get added to digits of `self` that have already been consumed.
+        unsafe {
+            let mut self_i = len;
+            loop {
+                if self_i == 0 {
+                    break
+                }
+                self_i -= 1;
+                let x = self.get_unchecked(self_i);
+                *self.get_unchecked_mut(self_i) = 0;
+                if x != 0 {
+                    let mut carry0 = 0;
+                    let mut carry1 = 0;
+                    const_for!(pad_i in {0..(len - self_i)} {
+                        let tmp0 = widen_mul_add(x, pad.get_unchecked(pad_i), carry0);
+                        carry0 = tmp0.1;
+                        let tmp1 = widen_add(self.get_unchecked(self_i + pad_i), tmp0.0, carry1);
+                        carry1 = tmp1.1;
+                        *self.get_unchecked_mut(self_i + pad_i) = tmp1.0;
+                    });
+                    // carries beyond the last digit
+                    if (carry0 != 0) || (carry1 != 0) {
+                        overflow = true;
+                    }
+                    // partial products that would be entirely beyond the last digit
+                    const_for!(pad_i in {(len - self_i)..len} {
+                        if pad.get_unchecked(pad_i) != 0 {
+                            overflow = true;
+                        }
+                    });
+                }
+            }
+        }
+        // magnitude bits beyond the bitwidth
+        if (self.extra() != 0) && ((self.last() >> self.extra()) != 0) {
+            overflow = true;
+        }
+        self.clear_unused_bits();
+        // the magnitude must fit in `self.bw() - 1` bits, except for the most negative
+        // value
+        if self.msb() && !(neg && self.is_imin()) {
+            overflow = true;
+        }
+        self.neg_(neg);
+        Some(overflow)
+    }
+
     /// Arbitrarily-unsigned-multiplies `lhs` by `rhs` and add-assigns the
     /// product to `self`. This function is equivalent to:
     /// ```
@@ -1,4 +1,4 @@
-use core::{mem::MaybeUninit, num::NonZeroUsize, ptr};
+use core::{mem::MaybeUninit, num::NonZeroUsize, ops::Range, ptr};
 
 use awint_internals::*;
 use const_fn::const_fn;
@@ -115,6 +115,23 @@ fn digit_rotate_test() {
     }
 }
 
+/// Xor-assigns `rhs` to `x` at a position `shl`.
+///
+/// # Safety
+///
+/// The set bits of `rhs`, shifted by `shl`, must be within `x.bw()`
+#[const_fn(cfg(feature = "const_support"))]
+const unsafe fn digit_xor(x: &mut Bits, rhs: Digit, shl: usize) {
+    let bits = extra_u(shl);
+    let digits = digits_u(shl);
+    unsafe {
+        *x.get_unchecked_mut(digits) ^= rhs << bits;
+        if (bits != 0) && ((rhs >> (BITS - bits)) != 0) {
+            *x.get_unchecked_mut(digits + 1) ^= rhs >> (BITS - bits);
+        }
+    }
+}
+
 /// # Bit permutation
 impl Bits {
     /// Shift-left-assigns at the digit level
@@ -256,6 +273,25 @@ impl Bits {
         }
     }
 
+    /// Reverses the order of the digits in `start..end`
+    ///
+    /// # Safety
+    ///
+    /// `start <= end <= self.total_digits()` must hold
+    #[inline]
+    #[const_fn(cfg(feature = "const_support"))]
+    pub(crate) const unsafe fn digit_reverse_range_(&mut self, start: usize, end: usize) {
+        let n = end - start;
+        const_for!(i in {0..(n / 2)} {
+            // Safety: `start <= start + i < end - 1 - i < end <= self.total_digits()`
+            unsafe {
+                let lo = self.get_unchecked(start + i);
+                *self.get_unchecked_mut(start + i) = self.get_unchecked(end - 1 - i);
+                *self.get_unchecked_mut(end - 1 - i) = lo;
+            }
+        });
+    }
+
     /// Left-shifts-assigns by `s` bits. If `s >= self.bw()`, then
     /// `None` is returned and the `Bits` are left unchanged.
     ///
@@ -316,6 +352,192 @@ impl Bits {
         }
     }
 
+    /// Left-shifts `self` by `s` bits and assigns the result to `out`, leaving
+    /// `self` unchanged. If `out.bw() != self.bw()` or `s >= self.bw()`, then
+    /// `None` is returned and `out` is left unchanged. See [Bits::shl_].
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn shl_to(&self, s: usize, out: &mut Bits) -> Option<()> {
+        if (out.bw() != self.bw()) || (s >= self.bw()) {
+            return None
+        }
+        out.copy_(self).unwrap();
+        out.shl_(s)
+    }
+
+    /// Logically-right-shifts `self` by `s` bits and assigns the result to
+    /// `out`, leaving `self` unchanged. If `out.bw() != self.bw()` or `s >=
+    /// self.bw()`, then `None` is returned and `out` is left unchanged. See
+    /// [Bits::lshr_].
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn lshr_to(&self, s: usize, out: &mut Bits) -> Option<()> {
+        if (out.bw() != self.bw()) || (s >= self.bw()) {
+            return None
+        }
+        out.copy_(self).unwrap();
+        out.lshr_(s)
+    }
+
+    /// Arithmetically-right-shifts `self` by `s` bits and assigns the result to
+    /// `out`, leaving `self` unchanged. If `out.bw() != self.bw()` or `s >=
+    /// self.bw()`, then `None` is returned and `out` is left unchanged. See
+    /// [Bits::ashr_].
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn ashr_to(&self, s: usize, out: &mut Bits) -> Option<()> {
+        if (out.bw() != self.bw()) || (s >= self.bw()) {
+            return None
+        }
+        out.copy_(self).unwrap();
+        out.ashr_(s)
+    }
+
+    /// Left-shift-assigns by `s` bits like [Bits::shl_], and returns if any
+    /// set bits were shifted out of the most significant end. In other words,
+    /// `true` is returned if the unsigned meaning of `self` times `2^s` does
+    /// not fit in `self.bw()` bits. If `s >= self.bw()`, then `None` is
+    /// returned and the `Bits` are left unchanged.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b0011_u4);
+    /// assert_eq!(x.overflowing_shl_(2), Some(false));
+    /// assert_eq!(x, inlawi!(0b1100_u4));
+    /// assert_eq!(x.overflowing_shl_(1), Some(true));
+    /// assert_eq!(x, inlawi!(0b1000_u4));
+    /// assert_eq!(x.overflowing_shl_(4), None);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn overflowing_shl_(&mut self, s: usize) -> Option<bool> {
+        if s >= self.bw() {
+            return None
+        }
+        let oflow = self.lz() < s;
+        self.shl_(s).unwrap();
+        Some(oflow)
+    }
+
+    /// Arithmetically-right-shift-assigns by `s` bits like [Bits::ashr_], and
+    /// returns if any set bits were shifted out of the least significant end.
+    /// In other words, `true` is returned if the result is not exactly the
+    /// signed meaning of `self` divided by `2^s`, because the arithmetic shift
+    /// rounds toward negative infinity. If `s >= self.bw()`, then `None` is
+    /// returned and the `Bits` are left unchanged.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(-12i8);
+    /// assert_eq!(x.overflowing_ashr_(2), Some(false));
+    /// assert_eq!(x, inlawi!(-3i8));
+    /// // -3 / 2 is not exact, and the result is rounded to -2
+    /// assert_eq!(x.overflowing_ashr_(1), Some(true));
+    /// assert_eq!(x, inlawi!(-2i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn overflowing_ashr_(&mut self, s: usize) -> Option<bool> {
+        if s >= self.bw() {
+            return None
+        }
+        let inexact = self.tz() < s;
+        self.ashr_(s).unwrap();
+        Some(inexact)
+    }
+
+    /// Arithmetically-right-shift-assigns by `s` bits, rounding toward zero
+    /// instead of toward negative infinity like [Bits::ashr_] does. The result
+    /// matches the signed division of `self` by `2^s` (see
+    /// [Bits::idivide]), which is `ashr_` with one added if `self` was
+    /// negative and any set bits were shifted out. If `s >= self.bw()`, then
+    /// `None` is returned and the `Bits` are left unchanged.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(-7i8);
+    /// x.ashr_round_to_zero_(1).unwrap();
+    /// assert_eq!(x, inlawi!(-3i8));
+    /// // compare to the arithmetic shift
+    /// let mut y = inlawi!(-7i8);
+    /// y.ashr_(1).unwrap();
+    /// assert_eq!(y, inlawi!(-4i8));
+    /// let mut z = inlawi!(7i8);
+    /// z.ashr_round_to_zero_(1).unwrap();
+    /// assert_eq!(z, inlawi!(3i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn ashr_round_to_zero_(&mut self, s: usize) -> Option<()> {
+        if s >= self.bw() {
+            return None
+        }
+        let round = self.msb() && (self.tz() < s);
+        self.ashr_(s).unwrap();
+        // the result is negative if rounding, so this cannot overflow
+        self.inc_(round);
+        Some(())
+    }
+
+    /// Unsigned-remainder-assigns `self` by `2^k`, keeping only the least
+    /// significant `k` bits. This is much cheaper than using
+    /// [Bits::udivide] with a power of two divisor. If `k > self.bw()`, then
+    /// `None` is returned and the `Bits` are left unchanged.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b1011_0110u8);
+    /// x.rem_power_of_two_(4).unwrap();
+    /// assert_eq!(x, inlawi!(0b0110u8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn rem_power_of_two_(&mut self, k: usize) -> Option<()> {
+        if k > self.bw() {
+            return None
+        }
+        self.range_and_(0..k)
+    }
+
+    /// Signed-remainder-assigns `self` by `2^k`. The remainder has the same
+    /// sign as the dividend, matching the remainder of [Bits::idivide] and
+    /// pairing with the quotient of [Bits::ashr_round_to_zero_]. A nonzero
+    /// remainder of a negative `self` is the least significant `k` bits minus
+    /// `2^k`. If `k > self.bw()`, then `None` is returned and the `Bits` are
+    /// left unchanged.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(-13i8);
+    /// x.srem_power_of_two_(2).unwrap();
+    /// assert_eq!(x, inlawi!(-1i8));
+    /// let mut y = inlawi!(13i8);
+    /// y.srem_power_of_two_(2).unwrap();
+    /// assert_eq!(y, inlawi!(1i8));
+    /// let mut z = inlawi!(-12i8);
+    /// z.srem_power_of_two_(2).unwrap();
+    /// assert_eq!(z, inlawi!(0i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn srem_power_of_two_(&mut self, k: usize) -> Option<()> {
+        if k > self.bw() {
+            return None
+        }
+        let neg_rem = self.msb() && (self.tz() < k);
+        self.range_and_(0..k).unwrap();
+        if neg_rem {
+            // sign extend from bit `k`
+            self.range_or_(k..self.bw()).unwrap();
+        }
+        Some(())
+    }
+
     /// Left-rotate-assigns by `s` bits. If `s >= self.bw()`, then
     /// `None` is returned and the `Bits` are left unchanged.
     ///
@@ -440,6 +662,251 @@ impl Bits {
         self.rotl_(w - s)
     }
 
+    /// Rotate-through-carry-left-assigns by one bit. This is a left shift by
+    /// one where the old value of `*carry` is shifted into the least
+    /// significant bit and the most significant bit that is shifted out is
+    /// assigned to `*carry`. This is equivalent to left rotating the
+    /// concatenation of `*carry` and `self` by one bit.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b1001_u4);
+    /// let mut carry = false;
+    /// x.rcl_(&mut carry);
+    /// assert_eq!(x, inlawi!(0b0010_u4));
+    /// assert!(carry);
+    /// x.rcl_(&mut carry);
+    /// assert_eq!(x, inlawi!(0b0101_u4));
+    /// assert!(!carry);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn rcl_(&mut self, carry: &mut bool) {
+        let msb = self.msb();
+        let mut c = *carry as Digit;
+        // Safety: we only access digits in `0..self.total_digits()`
+        unsafe {
+            const_for!(i in {0..self.total_digits()} {
+                let x = self.get_unchecked(i);
+                *self.get_unchecked_mut(i) = (x << 1) | c;
+                c = x >> (BITS - 1);
+            });
+        }
+        self.clear_unused_bits();
+        *carry = msb;
+    }
+
+    /// Rotate-through-carry-right-assigns by one bit. This is a logical right
+    /// shift by one where the old value of `*carry` is shifted into the most
+    /// significant bit and the least significant bit that is shifted out is
+    /// assigned to `*carry`. This is the inverse of [Bits::rcl_].
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b1001_u4);
+    /// let mut carry = false;
+    /// x.rcr_(&mut carry);
+    /// assert_eq!(x, inlawi!(0b0100_u4));
+    /// assert!(carry);
+    /// x.rcr_(&mut carry);
+    /// assert_eq!(x, inlawi!(0b1010_u4));
+    /// assert!(!carry);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn rcr_(&mut self, carry: &mut bool) {
+        let lsb = self.lsb();
+        // the most significant digit has the incoming carry placed at the position of
+        // the most significant bit
+        let x = self.last();
+        let msb_pos = if self.extra() == 0 {
+            BITS - 1
+        } else {
+            self.extra() - 1
+        };
+        *self.last_mut() = (x >> 1) | ((*carry as Digit) << msb_pos);
+        let mut c = x & 1;
+        // Safety: we only access digits in `0..(self.total_digits() - 1)`
+        unsafe {
+            const_for!(i in {0..(self.total_digits() - 1)}.rev() {
+                let x = self.get_unchecked(i);
+                *self.get_unchecked_mut(i) = (x >> 1) | (c << (BITS - 1));
+                c = x & 1;
+            });
+        }
+        *carry = lsb;
+    }
+
+    /// Shift-left-assigns by `n` whole `Digit`s, which is a shift by `n *
+    /// BITS` bits. Note that the shift amount is in digits and not bits. If
+    /// `n * BITS >= self.bw()`, then `None` is returned and the `Bits` are
+    /// left unchanged. This is equivalent to `self.shl_(n * BITS)`, but only
+    /// moves whole digits.
+    ///
+    /// ```
+    /// use awint::{awint_internals::BITS, inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x1234u300);
+    /// let mut y = x;
+    /// x.shl_digits_(2).unwrap();
+    /// y.shl_(2 * BITS).unwrap();
+    /// assert_eq!(x, y);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn shl_digits_(&mut self, n: usize) -> Option<()> {
+        let s = match n.checked_mul(BITS) {
+            Some(s) => s,
+            None => return None,
+        };
+        match NonZeroUsize::new(s) {
+            None => Some(()),
+            Some(s) if s.get() < self.bw() => {
+                self.digit_shl_(s);
+                self.clear_unused_bits();
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    /// Logically-right-shift-assigns by `n` whole `Digit`s, which is a shift
+    /// by `n * BITS` bits. Note that the shift amount is in digits and not
+    /// bits. If `n * BITS >= self.bw()`, then `None` is returned and the
+    /// `Bits` are left unchanged. This is equivalent to `self.lshr_(n *
+    /// BITS)`, but only moves whole digits.
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn lshr_digits_(&mut self, n: usize) -> Option<()> {
+        let s = match n.checked_mul(BITS) {
+            Some(s) => s,
+            None => return None,
+        };
+        match NonZeroUsize::new(s) {
+            None => Some(()),
+            Some(s) if s.get() < self.bw() => {
+                self.digit_shr_(s, false, true);
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    /// Left-rotate-assigns by `n` whole `Digit`s, which is a rotation by `n *
+    /// BITS` bits. Note that the shift amount is in digits and not bits. If
+    /// `n * BITS >= self.bw()`, then `None` is returned and the `Bits` are
+    /// left unchanged. This is equivalent to `self.rotl_(n * BITS)`. If
+    /// `self.bw()` is a multiple of `BITS`, only whole digits are moved,
+    /// otherwise this falls back to `rotl_`.
+    ///
+    /// ```
+    /// use awint::{awint_internals::BITS, inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x1234u256);
+    /// x.set(255, true).unwrap();
+    /// let mut y = x;
+    /// x.rotl_digits_(1).unwrap();
+    /// y.rotl_(BITS).unwrap();
+    /// assert_eq!(x, y);
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn rotl_digits_(&mut self, n: usize) -> Option<()> {
+        let s = match n.checked_mul(BITS) {
+            Some(s) => s,
+            None => return None,
+        };
+        if s >= self.bw() {
+            return None
+        }
+        if self.extra() != 0 {
+            return self.rotl_(s)
+        }
+        if n != 0 {
+            // rotating the digits up by `n` is the same as reversing the lower and upper
+            // parts separately and then reversing the whole slice
+            let len = self.total_digits();
+            // Safety: `0 < n < len` because `n * BITS < self.bw()`
+            unsafe {
+                self.digit_reverse_range_(0, len - n);
+                self.digit_reverse_range_(len - n, len);
+                self.digit_reverse_range_(0, len);
+            }
+        }
+        Some(())
+    }
+
+    /// Swaps the bits at indexes `i` and `j`. `None` is returned if `i >=
+    /// self.bw()` or `j >= self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b0001_u4);
+    /// x.swap_bits_(0, 3).unwrap();
+    /// assert_eq!(x, inlawi!(0b1000_u4));
+    /// assert!(x.swap_bits_(0, 4).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn swap_bits_(&mut self, i: usize, j: usize) -> Option<()> {
+        if (i >= self.bw()) || (j >= self.bw()) {
+            return None
+        }
+        let x = self.get(i).unwrap();
+        let y = self.get(j).unwrap();
+        self.set(i, y).unwrap();
+        self.set(j, x)
+    }
+
+    /// Swaps the bits in the range `a` with the bits in the range `b`. `None`
+    /// is returned if either range is reversed or extends beyond `self.bw()`,
+    /// if the ranges have different lengths, or if the ranges overlap.
+    /// Empty ranges do nothing to `self`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0xabcd_u16);
+    /// x.swap_ranges_(0..4, 12..16).unwrap();
+    /// assert_eq!(x, inlawi!(0xdbca_u16));
+    /// // overlapping ranges
+    /// assert!(x.swap_ranges_(0..8, 4..12).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn swap_ranges_(&mut self, a: Range<usize>, b: Range<usize>) -> Option<()> {
+        if (a.start > a.end)
+            || (b.start > b.end)
+            || (a.end > self.bw())
+            || (b.end > self.bw())
+            || ((a.end - a.start) != (b.end - b.start))
+        {
+            return None
+        }
+        let len = a.end - a.start;
+        if len == 0 {
+            return Some(())
+        }
+        if (a.start < b.end) && (b.start < a.end) {
+            return None
+        }
+        // xor swap one `Digit` sized chunk at a time
+        let mut i = 0;
+        while i < len {
+            let n = if (len - i) < BITS { len - i } else { BITS };
+            let mask = MAX >> (BITS - n);
+            let tmp = (self.get_digit(a.start + i) ^ self.get_digit(b.start + i)) & mask;
+            // Safety: the chunks are within the ranges which are within `self.bw()`
+            unsafe {
+                digit_xor(self, tmp, a.start + i);
+                digit_xor(self, tmp, b.start + i);
+            }
+            i += BITS;
+        }
+        Some(())
+    }
+
     /// Reverse-bit-order-assigns `self`. The least significant bit becomes the
     /// most significant bit, the second least significant bit becomes the
     /// second most significant bit, etc.
@@ -527,6 +994,202 @@ impl Bits {
         }
     }
 
+    /// Reverse-bit-order-assigns the bits of `self` in `range`, leaving the
+    /// bits outside of the range unchanged. An empty or reversed range does
+    /// nothing to `self`. `None` is returned if `range.start > self.bw()` or
+    /// `range.end > self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b1100_0001_1010_u12);
+    /// x.reverse_range_(4..12).unwrap();
+    /// assert_eq!(x, inlawi!(0b1000_0011_1010_u12));
+    /// assert!(x.reverse_range_(4..13).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn reverse_range_(&mut self, range: Range<usize>) -> Option<()> {
+        if (range.start > self.bw()) || (range.end > self.bw()) {
+            return None
+        }
+        if range.start >= range.end {
+            return Some(())
+        }
+        // two pointers that move inwards, swapping and reversing whole `Digit` sized
+        // chunks while they do not overlap
+        let mut lo = range.start;
+        let mut hi = range.end;
+        while (hi - lo) >= (2 * BITS) {
+            let x = self.get_digit(lo);
+            let y = self.get_digit(hi - BITS);
+            // Safety: the chunks are within `range` which is within `self.bw()`
+            unsafe {
+                digit_xor(self, x ^ y.reverse_bits(), lo);
+                digit_xor(self, y ^ x.reverse_bits(), hi - BITS);
+            }
+            lo += BITS;
+            hi -= BITS;
+        }
+        // swap the remaining bits individually
+        while (lo + 1) < hi {
+            hi -= 1;
+            let x = self.get(lo).unwrap();
+            let y = self.get(hi).unwrap();
+            self.set(lo, y).unwrap();
+            self.set(hi, x).unwrap();
+            lo += 1;
+        }
+        Some(())
+    }
+
+    /// Reverse-chunk-order-assigns `self`. `self` is divided into `chunk` bit
+    /// sized groups, and the order of the groups is reversed while the order
+    /// of bits within each group is preserved. Returns `None` if `self.bw()` is
+    /// not a multiple of `chunk`.
+    ///
+    /// ```
+    /// use awint::{bw, inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x123456u24);
+    /// x.reverse_chunks_(bw(4)).unwrap();
+    /// assert_eq!(x, inlawi!(0x654321u24));
+    /// x.reverse_chunks_(bw(8)).unwrap();
+    /// assert_eq!(x, inlawi!(0x214365u24));
+    /// assert!(x.reverse_chunks_(bw(5)).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn reverse_chunks_(&mut self, chunk: NonZeroUsize) -> Option<()> {
+        let chunk = chunk.get();
+        let w = self.bw();
+        let n = w / chunk;
+        if (n * chunk) != w {
+            return None
+        }
+        const_for!(i in {0..(n / 2)} {
+            let lo = i * chunk;
+            let hi = (n - 1 - i) * chunk;
+            // swap the two chunks up to a digit at a time
+            let mut o = 0;
+            loop {
+                if o >= chunk {
+                    break
+                }
+                let width = if (chunk - o) < BITS { chunk - o } else { BITS };
+                let mask = MAX >> (BITS - width);
+                let t = (self.get_digit(lo + o) ^ self.get_digit(hi + o)) & mask;
+                // Safety: `t` is masked to `width` bits, and `width` bits starting at
+                // either position are within `self.bw()`
+                unsafe {
+                    digit_xor(self, t, lo + o);
+                    digit_xor(self, t, hi + o);
+                }
+                o += BITS;
+            }
+        });
+        Some(())
+    }
+
+    /// Transpose-assigns `self`, treating `self` as an `order` by `order` bit
+    /// matrix in row-major order (the bit at row `r` and column `c` is at
+    /// position `r * order + c`). Returns `None` if `self.bw() != order *
+    /// order`.
+    ///
+    /// If `order` is a power of two, this uses the recursive block swap
+    /// algorithm, which needs `log2(order)` passes over the digits of `self`.
+    /// Otherwise, the bits are swapped individually.
+    ///
+    /// ```
+    /// use awint::{bw, inlawi, Bits, InlAwi};
+    ///
+    /// // row 0 is the least significant 4 bits
+    /// let mut x = inlawi!(0b0011_0000_0001_0111_u16);
+    /// x.transpose_square_(bw(4)).unwrap();
+    /// assert_eq!(x, inlawi!(0b0000_0001_1001_1011_u16));
+    /// // the transpose of a 3 by 3 matrix
+    /// let mut y = inlawi!(0b000_100_011_u9);
+    /// y.transpose_square_(bw(3)).unwrap();
+    /// assert_eq!(y, inlawi!(0b010_001_001_u9));
+    /// assert!(y.transpose_square_(bw(2)).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn transpose_square_(&mut self, order: NonZeroUsize) -> Option<()> {
+        let n = order.get();
+        match n.checked_mul(n) {
+            Some(sq) if sq == self.bw() => (),
+            _ => return None,
+        }
+        if !n.is_power_of_two() {
+            const_for!(r in {0..n} {
+                const_for!(c in {(r + 1)..n} {
+                    let x = self.get((r * n) + c).unwrap();
+                    let y = self.get((c * n) + r).unwrap();
+                    self.set((r * n) + c, y).unwrap();
+                    self.set((c * n) + r, x).unwrap();
+                });
+            });
+            return Some(())
+        }
+        // For each power of two `j` from `n / 2` down to 1, the bits at row `r`
+        // and column `c` with `r & j == 0` and `c & j != 0` are swapped with the
+        // bits at row `r + j` and column `c - j`, which are `j * (n - 1)` bits
+        // more significant. Position `p` has `c` in the lower `e` bits of `p`
+        // and `r` in the next `e` bits.
+        let e = n.trailing_zeros() as usize;
+        let b = BITS.trailing_zeros() as usize;
+        const_for!(lj in {0..e}.rev() {
+            let d = (1usize << lj) * (n - 1);
+            let tc = lj;
+            let tr = e + lj;
+            // masks for where the bits of `p` at `tc` are set and at `tr` are
+            // unset, for each digit
+            let pat_c = if tc < b {
+                let s = 1usize << tc;
+                (MAX / ((1 << s) + 1)) << s
+            } else {
+                0
+            };
+            let pat_r = if tr < b {
+                let s = 1usize << tr;
+                !((MAX / ((1 << s) + 1)) << s)
+            } else {
+                0
+            };
+            const_for!(k in {0..self.total_digits()} {
+                let mc = if tc < b {
+                    pat_c
+                } else if ((k >> (tc - b)) & 1) != 0 {
+                    MAX
+                } else {
+                    0
+                };
+                let mr = if tr < b {
+                    pat_r
+                } else if ((k >> (tr - b)) & 1) == 0 {
+                    MAX
+                } else {
+                    0
+                };
+                let m = mc & mr;
+                if m != 0 {
+                    // Safety: `k < self.total_digits()`
+                    let t = (self.get_digit((k * BITS) + d) ^ unsafe { self.get_unchecked(k) }) & m;
+                    if t != 0 {
+                        // Safety: the bits of `t` are at positions whose partners are within
+                        // `self.bw()`
+                        unsafe {
+                            *self.get_unchecked_mut(k) ^= t;
+                            digit_xor(self, t, (k * BITS) + d);
+                        }
+                    }
+                }
+            });
+        });
+        Some(())
+    }
+
     /// Funnel shift with power-of-two bitwidths. Returns `None` if
     /// `2*self.bw() != rhs.bw() || 2^s.bw() != self.bw()`. A `self.bw()` sized
     /// field is assigned to `self` from `rhs` starting from the bit position
@@ -1,7 +1,7 @@
 use awint_internals::*;
 use const_fn::const_fn;
 
-use crate::Bits;
+use crate::{Bits, InlAwi};
 
 // TODO these could probably be optimized, check assembly
 
@@ -181,6 +181,201 @@ impl Bits {
     pub const fn to_digit(&self) -> Digit {
         self.first()
     }
+
+    /// Returns the value of `self` as `N` little endian `usize` words, or
+    /// `None` if `self.bw() > N * USIZE_BITS`. Words above `self.bw()` are
+    /// zeroed.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0x1234u16);
+    /// assert_eq!(x.to_usize_array::<2>(), Some([0x1234, 0]));
+    /// let y = inlawi!(0u200);
+    /// assert_eq!(y.to_usize_array::<1>(), None);
+    /// ```
+    // `Digit` is `usize` on some platforms
+    #[allow(clippy::unnecessary_cast)]
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn to_usize_array<const N: usize>(&self) -> Option<[usize; N]> {
+        match N.checked_mul(USIZE_BITS) {
+            Some(w) if self.bw() <= w => (),
+            _ => return None,
+        }
+        let mut res = [0usize; N];
+        if BITS <= USIZE_BITS {
+            // multiple digits per word, this cannot overflow `res` because `BITS`
+            // divides `USIZE_BITS`
+            const_for!(i in {0..self.total_digits()} {
+                let bit = i * BITS;
+                res[bit / USIZE_BITS] |=
+                    (unsafe { self.get_unchecked(i) } as usize) << (bit % USIZE_BITS);
+            });
+        } else {
+            // multiple words per digit
+            const_for!(j in {0..N} {
+                let bit = j * USIZE_BITS;
+                if bit < self.bw() {
+                    res[j] = (unsafe { self.get_unchecked(bit / BITS) } >> (bit % BITS)) as usize;
+                }
+            });
+        }
+        Some(res)
+    }
+}
+
+macro_rules! bits_try_convert {
+    ($($unsigned_name:ident, $to_u:ident, $uX:ident, $signed_name:ident, $to_i:ident,
+        $iX:ident);*;) => {
+        $(
+            #[const_fn(cfg(feature = "const_support"))]
+            #[must_use]
+            pub const fn $unsigned_name(&self) -> Option<$uX> {
+                if self.sig() > ($uX::BITS as usize) {
+                    None
+                } else {
+                    Some(self.$to_u())
+                }
+            }
+
+            #[const_fn(cfg(feature = "const_support"))]
+            #[must_use]
+            pub const fn $signed_name(&self) -> Option<$iX> {
+                if self.fits_signed($iX::BITS as usize) {
+                    Some(self.$to_i())
+                } else {
+                    None
+                }
+            }
+        )*
+    };
+}
+
+/// # Checked primitive conversion
+///
+/// These are the checked counterparts to the primitive conversion functions.
+/// The unsigned functions return `None` if the unsigned value of `self` does
+/// not fit in the primitive (i.e. `self.sig()` is larger than the primitive
+/// bitwidth). The signed functions return `None` if the signed value of `self`
+/// is outside the two's complement range of the primitive.
+impl Bits {
+    bits_try_convert!(
+        try_to_u8, to_u8, u8, try_to_i8, to_i8, i8;
+        try_to_u16, to_u16, u16, try_to_i16, to_i16, i16;
+        try_to_u32, to_u32, u32, try_to_i32, to_i32, i32;
+        try_to_u64, to_u64, u64, try_to_i64, to_i64, i64;
+        try_to_u128, to_u128, u128, try_to_i128, to_i128, i128;
+        try_to_usize, to_usize, usize, try_to_isize, to_isize, isize;
+    );
+
+    /// Returns if the signed value of `self` fits in a signed integer of
+    /// bitwidth `w`, meaning that all bits at and above position `w - 1` are
+    /// equal to the sign bit
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    const fn fits_signed(&self, w: usize) -> bool {
+        if self.bw() <= w {
+            return true
+        }
+        let fill = if self.msb() { MAX } else { 0 };
+        let mut i = w - 1;
+        while i < self.bw() {
+            // `get_digit` zeroes bits beyond the bitwidth, so we mask them out
+            let remaining = self.bw() - i;
+            let mask = if remaining >= BITS {
+                MAX
+            } else {
+                MAX >> (BITS - remaining)
+            };
+            if ((self.get_digit(i) ^ fill) & mask) != 0 {
+                return false
+            }
+            i += BITS;
+        }
+        true
+    }
+}
+
+macro_rules! bits_saturating_convert {
+    ($($unsigned_name:ident, $to_u:ident, $uX:ident, $signed_name:ident, $to_i:ident,
+        $iX:ident);*;) => {
+        $(
+            #[const_fn(cfg(feature = "const_support"))]
+            #[must_use]
+            pub const fn $unsigned_name(&self) -> $uX {
+                if self.sig() > ($uX::BITS as usize) {
+                    $uX::MAX
+                } else {
+                    self.$to_u()
+                }
+            }
+
+            #[const_fn(cfg(feature = "const_support"))]
+            #[must_use]
+            pub const fn $signed_name(&self) -> $iX {
+                if self.fits_signed($iX::BITS as usize) {
+                    self.$to_i()
+                } else if self.msb() {
+                    $iX::MIN
+                } else {
+                    $iX::MAX
+                }
+            }
+        )*
+    };
+}
+
+/// # Saturating primitive conversion
+///
+/// These are the saturating counterparts to the primitive conversion
+/// functions. Instead of truncating, the unsigned functions return the maximum
+/// value of the primitive if the unsigned value of `self` does not fit in it.
+/// The signed functions return the minimum or maximum value of the primitive
+/// if the signed value of `self` is below or above its two's complement range.
+///
+/// ```
+/// use awint::{inlawi, Bits, InlAwi};
+///
+/// assert_eq!(inlawi!(1000u100).saturating_to_u8(), u8::MAX);
+/// assert_eq!(inlawi!(100u100).saturating_to_u8(), 100);
+/// assert_eq!(inlawi!(-1000i100).saturating_to_i8(), i8::MIN);
+/// assert_eq!(inlawi!(1000i100).saturating_to_i8(), i8::MAX);
+/// // the unsigned functions interpret `self` as unsigned
+/// assert_eq!(inlawi!(-1i100).saturating_to_u64(), u64::MAX);
+/// ```
+impl Bits {
+    bits_saturating_convert!(
+        saturating_to_u8, to_u8, u8, saturating_to_i8, to_i8, i8;
+        saturating_to_u16, to_u16, u16, saturating_to_i16, to_i16, i16;
+        saturating_to_u32, to_u32, u32, saturating_to_i32, to_i32, i32;
+        saturating_to_u64, to_u64, u64, saturating_to_i64, to_i64, i64;
+        saturating_to_u128, to_u128, u128, saturating_to_i128, to_i128, i128;
+        saturating_to_usize, to_usize, usize, saturating_to_isize, to_isize, isize;
+    );
+}
+
+impl Bits {
+    /// Copies the `width` least significant bits of `val` into `self` at bit
+    /// position `to`. This is [Bits::field_to] with `val` as the source,
+    /// without having to wrap `val` in an `InlAwi` first. Only the `width`
+    /// bits in `self` are mutated. `None` is returned if `width > 128` or if
+    /// `to + width > self.bw()`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x0000_ffff_u32);
+    /// x.field_from_u128_(8, 0xab_u128, 12).unwrap();
+    /// assert_eq!(x, inlawi!(0x0000_abff_u32));
+    /// assert!(x.field_from_u128_(24, 0, 9).is_none());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn field_from_u128_(&mut self, to: usize, val: u128, width: usize) -> Option<()> {
+        let val = InlAwi::from_u128(val);
+        self.field_to(to, val.const_as_ref(), width)
+    }
 }
 
 impl From<&Bits> for bool {
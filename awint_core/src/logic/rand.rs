@@ -50,4 +50,107 @@ impl Bits {
         self.clear_unused_bits();
         result
     }
+
+    /// Probabilistically tests if the unsigned value of `self` is prime using
+    /// the Miller-Rabin test with `rounds` random witnesses drawn from `rng`.
+    /// If `false` is returned, `self` is definitely composite. If `true` is
+    /// returned, `self` is prime with high probability: a composite number
+    /// passes each round with a probability of at most 1/4, so the chance of
+    /// a false positive is at most `4^-rounds`. Values less than 4 are tested
+    /// exactly regardless of `rounds`.
+    ///
+    /// `pads` is a set of scratchpads that will be mutated arbitrarily. It
+    /// must have at least 6 elements, each with a bitwidth of `2 *
+    /// self.bw()`, otherwise `None` is returned. `None` is also returned if
+    /// `rng` returns an error.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    /// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro128StarStar};
+    ///
+    /// let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    /// let mut pads = [inlawi!(0u128); 6];
+    /// let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.const_as_mut()).collect();
+    /// // the Mersenne prime 2^61 - 1
+    /// let p = inlawi!(0x1fff_ffff_ffff_ffff_u64);
+    /// assert_eq!(p.is_probably_prime(32, &mut rng, &mut pads), Some(true));
+    /// // 2^61 + 1 is divisible by 3
+    /// let c = inlawi!(0x2000_0000_0000_0001_u64);
+    /// assert_eq!(c.is_probably_prime(32, &mut rng, &mut pads), Some(false));
+    /// ```
+    #[must_use]
+    pub fn is_probably_prime<R>(
+        &self,
+        rounds: usize,
+        rng: &mut R,
+        pads: &mut [&mut Bits],
+    ) -> Option<bool>
+    where
+        R: rand_core::RngCore,
+    {
+        let [n, n_sub1, a, x, prod, quo, ..] = pads else {
+            return None
+        };
+        let w = self.bw() * 2;
+        if (n.bw() != w)
+            || (n_sub1.bw() != w)
+            || (a.bw() != w)
+            || (x.bw() != w)
+            || (prod.bw() != w)
+            || (quo.bw() != w)
+        {
+            return None
+        }
+        // small and even cases
+        if self.sig() <= 2 {
+            return Some(self.to_u8() >= 2)
+        }
+        if !self.lsb() {
+            return Some(false)
+        }
+        n.zero_resize_(self);
+        n_sub1.copy_(n).unwrap();
+        n_sub1.dec_(false);
+        // `n - 1 = d * 2^s` with `d` odd
+        let s = n_sub1.tz();
+        let sig = n_sub1.sig();
+        // because `n` is odd and at least 5, `n - 3` is nonzero
+        prod.copy_(n_sub1).unwrap();
+        prod.digit_sub_(2);
+        'outer: for _ in 0..rounds {
+            // random witness in `[2, n - 2]`
+            x.rand_(rng).ok()?;
+            Bits::udivide(quo, a, x, prod).unwrap();
+            a.digit_add_(2);
+            // `x = a^d mod n` by left to right binary exponentiation over the bits
+            // of `n - 1` above the `s` trailing zeros
+            x.uone_();
+            for i in (s..sig).rev() {
+                quo.zero_();
+                quo.mul_add_(x, x).unwrap();
+                Bits::udivide(prod, x, quo, n).unwrap();
+                if n_sub1.get(i).unwrap() {
+                    quo.zero_();
+                    quo.mul_add_(x, a).unwrap();
+                    Bits::udivide(prod, x, quo, n).unwrap();
+                }
+            }
+            // restore `n - 3`
+            prod.copy_(n_sub1).unwrap();
+            prod.digit_sub_(2);
+            if x.is_uone() || x.const_eq(n_sub1).unwrap() {
+                continue
+            }
+            for _ in 1..s {
+                a.zero_();
+                a.mul_add_(x, x).unwrap();
+                Bits::udivide(quo, x, a, n).unwrap();
+                if x.const_eq(n_sub1).unwrap() {
+                    continue 'outer
+                }
+            }
+            return Some(false)
+        }
+        Some(true)
+    }
 }
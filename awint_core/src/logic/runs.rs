@@ -0,0 +1,72 @@
+use core::{cmp, iter::FusedIterator, ops::Range};
+
+use awint_internals::*;
+
+use crate::Bits;
+
+/// An iterator over the maximal runs of equal bits in a `Bits`, returned by
+/// [Bits::runs]
+#[derive(Debug, Clone)]
+pub struct Runs<'a> {
+    bits: &'a Bits,
+    pos: usize,
+}
+
+impl<'a> Iterator for Runs<'a> {
+    type Item = (Range<usize>, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let w = self.bits.bw();
+        if self.pos >= w {
+            return None
+        }
+        let start = self.pos;
+        let value = self.bits.get(start).unwrap();
+        // skip over whole digits of equal bits at a time
+        let mut end = start;
+        loop {
+            let x = self.bits.get_digit(end);
+            let tz = if value { !x } else { x }.trailing_zeros() as usize;
+            end += tz;
+            if (tz < BITS) || (end >= w) {
+                break
+            }
+        }
+        // `get_digit` zeroes bits beyond the bitwidth
+        let end = cmp::min(end, w);
+        self.pos = end;
+        Some((start..end, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rem = self.bits.bw() - self.pos;
+        ((rem != 0) as usize, Some(rem))
+    }
+}
+
+impl<'a> FusedIterator for Runs<'a> {}
+
+/// # Runs
+impl Bits {
+    /// Returns an iterator over the maximal runs of equal bits in `self`, from
+    /// the least significant bit to the most significant bit. Each item is the
+    /// range of bit indexes of the run along with the value of its bits.
+    /// Homogeneous `Digit`s are skipped over at a time instead of scanning
+    /// bit by bit.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0b0011_1000_0001_u12);
+    /// let runs: Vec<_> = x.runs().collect();
+    /// assert_eq!(runs, [
+    ///     (0..1, true),
+    ///     (1..7, false),
+    ///     (7..10, true),
+    ///     (10..12, false)
+    /// ]);
+    /// ```
+    pub fn runs(&self) -> Runs<'_> {
+        Runs { bits: self, pos: 0 }
+    }
+}
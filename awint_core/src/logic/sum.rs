@@ -80,6 +80,96 @@ impl Bits {
         }
     }
 
+    /// Add-assigns the `Digit` `rhs` to `self` and returns the carry-out bit.
+    /// `false` is always returned unless the unsigned sum is at least
+    /// `2^self.bw()`, in which case the sum is wrapped. Carry propagation
+    /// stops at the first digit that does not overflow.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0xfff0u16);
+    /// assert!(!x.digit_add_(0xf));
+    /// assert_eq!(x, inlawi!(0xffffu16));
+    /// assert!(x.digit_add_(2));
+    /// assert_eq!(x, inlawi!(1u16));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn digit_add_(&mut self, rhs: Digit) -> bool {
+        let mut carry = rhs;
+        unsafe_for_each_mut!(
+            self,
+            x,
+            {0..(self.total_digits() - 1)}
+            {
+                let tmp = widen_add(*x, carry, 0);
+                *x = tmp.0;
+                if tmp.1 == 0 {
+                    return false
+                }
+                carry = tmp.1;
+            },
+            false
+        );
+        let (last, oflow) = self.last().overflowing_add(carry);
+        if self.extra() == 0 {
+            *self.last_mut() = last;
+            oflow
+        } else {
+            let mask = MAX << self.extra();
+            *self.last_mut() = last & (!mask);
+            oflow || ((last & mask) != 0)
+        }
+    }
+
+    /// Subtract-assigns the `Digit` `rhs` from `self` and returns the
+    /// borrow-out bit. `false` is always returned unless `rhs` is larger than
+    /// the unsigned value of `self`, in which case the difference is wrapped.
+    /// Borrow propagation stops at the first digit that does not underflow.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0x100u16);
+    /// assert!(!x.digit_sub_(1));
+    /// assert_eq!(x, inlawi!(0xffu16));
+    /// assert!(!x.digit_sub_(0xff));
+    /// assert!(x.is_zero());
+    /// assert!(x.digit_sub_(1));
+    /// assert_eq!(x, inlawi!(0xffffu16));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn digit_sub_(&mut self, rhs: Digit) -> bool {
+        let mut borrow = rhs;
+        unsafe_for_each_mut!(
+            self,
+            x,
+            {0..(self.total_digits() - 1)}
+            {
+                match x.overflowing_sub(borrow) {
+                    (v, false) => {
+                        *x = v;
+                        return false
+                    }
+                    (v, true) => {
+                        *x = v;
+                        borrow = 1;
+                    }
+                }
+            },
+            false
+        );
+        let (last, oflow) = self.last().overflowing_sub(borrow);
+        if self.extra() == 0 {
+            *self.last_mut() = last;
+            oflow
+        } else {
+            let mask = MAX << self.extra();
+            *self.last_mut() = last & (!mask);
+            oflow || ((last & mask) != 0)
+        }
+    }
+
     /// Negate-assigns `self` if `neg` is true. Note that signed minimum values
     /// will overflow.
     #[const_fn(cfg(feature = "const_support"))]
@@ -93,6 +183,24 @@ impl Bits {
         }
     }
 
+    /// Negate-assigns `self` unconditionally, the same as `self.neg_(true)`.
+    /// Note that signed minimum values will overflow.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(42i8);
+    /// x.wrapping_neg_();
+    /// assert_eq!(x, inlawi!(-42i8));
+    /// let mut x = inlawi!(-128i8);
+    /// x.wrapping_neg_();
+    /// assert_eq!(x, inlawi!(-128i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn wrapping_neg_(&mut self) {
+        self.neg_(true);
+    }
+
     /// Absolute-value-assigns `self`. Note that signed minimum values will
     /// overflow, unless `self` is interpreted as unsigned after a call to this
     /// function.
@@ -101,6 +209,63 @@ impl Bits {
         self.neg_(self.msb());
     }
 
+    /// Unsigned-absolute-value-assigns `self`. `self` is interpreted as signed
+    /// before the call, and should be interpreted as unsigned after the call,
+    /// mirroring `iN::unsigned_abs`. Unlike the signed interpretation of
+    /// [Bits::abs_], this never overflows, because the magnitude of the signed
+    /// minimum value is representable as an unsigned integer.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(-128i8);
+    /// x.unsigned_abs_();
+    /// assert_eq!(x, inlawi!(128u8));
+    /// let mut x = inlawi!(-42i8);
+    /// x.unsigned_abs_();
+    /// assert_eq!(x, inlawi!(42u8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn unsigned_abs_(&mut self) {
+        // the bit pattern of the wrapping absolute value is the unsigned magnitude
+        self.abs_();
+    }
+
+    /// Signum-assigns `self`, interpreted as signed. `self` is set to `-1`
+    /// (all ones) if it is negative, `1` if it is positive, and `0` if it is
+    /// zero. Note that when `self.bw() == 1`, both `-1` and `1` are
+    /// represented by the single set bit.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(-42i100);
+    /// x.signum_();
+    /// assert_eq!(x, inlawi!(-1i100));
+    /// let mut x = inlawi!(42i100);
+    /// x.signum_();
+    /// assert_eq!(x, inlawi!(1i100));
+    /// let mut x = inlawi!(0i100);
+    /// x.signum_();
+    /// assert!(x.is_zero());
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    pub const fn signum_(&mut self) {
+        let neg = self.msb();
+        let nonzero = !self.is_zero();
+        // `-1` is all ones, `1` is only the least significant bit
+        unsafe { self.digit_set(neg, 0..self.total_digits(), true) }
+        *self.first_mut() |= nonzero as Digit;
+    }
+
+    /// Returns the sign of `self` interpreted as signed, `-1` if it is
+    /// negative, `1` if it is positive, and `0` if it is zero
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn sign(&self) -> i8 {
+        ((!self.is_zero()) as i8) - (2 * (self.msb() as i8))
+    }
+
     /// Add-assigns by `rhs`
     #[const_fn(cfg(feature = "const_support"))]
     #[must_use]
@@ -169,6 +334,86 @@ impl Bits {
         }
     }
 
+    /// Add-assigns by `rhs` if `cond` is true. `rhs` is masked by `cond` and
+    /// added for every digit regardless of `cond`, so there are no data
+    /// dependent branches. This is the building block for constant-time
+    /// modular reduction, where the modulus is added back only if a
+    /// subtraction borrowed. `None` is returned if the bitwidths of `self` and
+    /// `rhs` are not equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// // `(3 - 5) mod 13`
+    /// let modulus = inlawi!(13u64);
+    /// let mut x = inlawi!(3u64);
+    /// let rhs = inlawi!(5u64);
+    /// let borrow = x.ult(&rhs).unwrap();
+    /// x.sub_(&rhs).unwrap();
+    /// x.conditional_add_(&modulus, borrow).unwrap();
+    /// assert_eq!(x, inlawi!(11u64));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn conditional_add_(&mut self, rhs: &Self, cond: bool) -> Option<()> {
+        let mask = (0 as Digit).wrapping_sub(cond as Digit);
+        let mut carry = 0;
+        unsafe_binop_for_each_mut!(
+            self,
+            rhs,
+            x,
+            y,
+            {
+                let tmp = widen_add(*x, y & mask, carry);
+                *x = tmp.0;
+                carry = tmp.1;
+            },
+            true
+        )
+    }
+
+    /// Add-assigns by `rhs` like [Bits::add_], and also assigns the carry
+    /// chain of the addition to `carries`. Bit `i` of `carries` is set to the
+    /// carry into bit position `i` of the sum, so that bit `i` of the sum is
+    /// the XOR of bit `i` of the original `self`, `rhs`, and `carries`. The
+    /// carry into the least significant bit is always zero, and the carry out
+    /// of the most significant bit is not included. `None` is returned if the
+    /// bitwidths of `self`, `rhs`, and `carries` are not all equal.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0b0110_1011u8);
+    /// let mut carries = inlawi!(0u8);
+    /// x.add_with_carries_(&inlawi!(0b0000_0111u8), &mut carries)
+    ///     .unwrap();
+    /// assert_eq!(x, inlawi!(0b0111_0010u8));
+    /// // the carry ripples through the low run of ones in the operands
+    /// assert_eq!(carries, inlawi!(0b0001_1110u8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn add_with_carries_(&mut self, rhs: &Self, carries: &mut Self) -> Option<()> {
+        if (self.bw() != rhs.bw()) || (self.bw() != carries.bw()) {
+            return None
+        }
+        let mut carry = 0;
+        // Safety: the bitwidths are equal, so `i` is within all of the inputs
+        unsafe {
+            const_for!(i in {0..self.total_digits()} {
+                let x = self.get_unchecked(i);
+                let y = rhs.get_unchecked(i);
+                let tmp = widen_add(x, y, carry);
+                *self.get_unchecked_mut(i) = tmp.0;
+                *carries.get_unchecked_mut(i) = tmp.0 ^ x ^ y;
+                carry = tmp.1;
+            });
+        }
+        self.clear_unused_bits();
+        carries.clear_unused_bits();
+        Some(())
+    }
+
     /// A general summation with carry-in `cin` and two inputs `lhs` and `rhs`.
     /// `self` is set to the sum. The unsigned overflow (equivalent to the
     /// carry-out bit) and the signed overflow is returned as a tuple. `None` is
@@ -214,4 +459,162 @@ impl Bits {
             )
         })
     }
+
+    /// Widening-add-assigns `self` to the sum of `lhs` and `rhs` interpreted
+    /// as unsigned. Because `self` is one bit wider than the inputs, the
+    /// result includes the carry-out bit and can never overflow. `None` is
+    /// returned unless `lhs.bw() == rhs.bw()` and `self.bw() == lhs.bw() + 1`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0u9);
+    /// x.widening_add_(&inlawi!(200u8), &inlawi!(100u8)).unwrap();
+    /// assert_eq!(x, inlawi!(300u9));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn widening_add_(&mut self, lhs: &Self, rhs: &Self) -> Option<()> {
+        if (lhs.bw() != rhs.bw()) || (self.bw() != lhs.bw().wrapping_add(1)) {
+            return None
+        }
+        let mut carry = 0;
+        // the inputs are zero extended to `self.total_digits()`, which is at most one
+        // more than `lhs.total_digits()`
+        const_for!(i in {0..self.total_digits()} {
+            let (x, y) = if i < lhs.total_digits() {
+                // Safety: `i` is less than the number of digits in `lhs` and `rhs`
+                unsafe { (lhs.get_unchecked(i), rhs.get_unchecked(i)) }
+            } else {
+                (0, 0)
+            };
+            let tmp = widen_add(x, y, carry);
+            // Safety: `i < self.total_digits()`
+            unsafe {
+                *self.get_unchecked_mut(i) = tmp.0;
+            }
+            carry = tmp.1;
+        });
+        Some(())
+    }
+
+    /// Widening-subtract-assigns `self` to the difference `lhs - rhs`, where
+    /// `lhs` and `rhs` are interpreted as unsigned and `self` is interpreted as
+    /// signed. Because `self` is one bit wider than the inputs, the difference
+    /// can never overflow. `None` is returned unless `lhs.bw() == rhs.bw()` and
+    /// `self.bw() == lhs.bw() + 1`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(0i9);
+    /// x.widening_sub_(&inlawi!(0u8), &inlawi!(255u8)).unwrap();
+    /// assert_eq!(x, inlawi!(-255i9));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn widening_sub_(&mut self, lhs: &Self, rhs: &Self) -> Option<()> {
+        if (lhs.bw() != rhs.bw()) || (self.bw() != lhs.bw().wrapping_add(1)) {
+            return None
+        }
+        let mut carry = 1;
+        const_for!(i in {0..self.total_digits()} {
+            let (x, y) = if i < lhs.total_digits() {
+                // Safety: `i` is less than the number of digits in `lhs` and `rhs`
+                unsafe { (lhs.get_unchecked(i), rhs.get_unchecked(i)) }
+            } else {
+                (0, 0)
+            };
+            let tmp = widen_add(x, !y, carry);
+            // Safety: `i < self.total_digits()`
+            unsafe {
+                *self.get_unchecked_mut(i) = tmp.0;
+            }
+            carry = tmp.1;
+        });
+        self.clear_unused_bits();
+        Some(())
+    }
+
+    /// Shared implementation of the averaging functions, using
+    /// `(x & y) + ((x ^ y) >> 1)` for rounding down and
+    /// `(x | y) - ((x ^ y) >> 1)` for rounding up
+    #[const_fn(cfg(feature = "const_support"))]
+    const fn average(&mut self, rhs: &Self, signed: bool, ceil: bool) -> Option<()> {
+        if self.bw() != rhs.bw() {
+            return None
+        }
+        let len = self.total_digits();
+        // the bit shifted into the top of `(x ^ y) >> 1`
+        let fill = signed && (self.msb() != rhs.msb());
+        let top = if self.extra() == 0 {
+            BITS - 1
+        } else {
+            self.extra() - 1
+        };
+        let mut carry = ceil as Digit;
+        unsafe {
+            const_for!(i in {0..len} {
+                let x = self.get_unchecked(i);
+                let y = rhs.get_unchecked(i);
+                let mut half = (x ^ y) >> 1;
+                if (i + 1) < len {
+                    half |= (self.get_unchecked(i + 1) ^ rhs.get_unchecked(i + 1)) << (BITS - 1);
+                } else if fill {
+                    half |= 1 << top;
+                }
+                let tmp = if ceil {
+                    widen_add(x | y, !half, carry)
+                } else {
+                    widen_add(x & y, half, carry)
+                };
+                *self.get_unchecked_mut(i) = tmp.0;
+                carry = tmp.1;
+            });
+        }
+        self.clear_unused_bits();
+        Some(())
+    }
+
+    /// Average-assigns `self` with `rhs`, rounding toward negative infinity.
+    /// Sets `self` to `floor((self + rhs) / 2)` without the intermediate sum
+    /// overflowing. `self` and `rhs` are interpreted as signed if `signed`.
+    /// `None` is returned if the bitwidths do not match.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(255u8);
+    /// x.average_floor_(&inlawi!(254u8), false).unwrap();
+    /// assert_eq!(x, inlawi!(254u8));
+    /// let mut x = inlawi!(-3i8);
+    /// x.average_floor_(&inlawi!(0i8), true).unwrap();
+    /// assert_eq!(x, inlawi!(-2i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn average_floor_(&mut self, rhs: &Self, signed: bool) -> Option<()> {
+        self.average(rhs, signed, false)
+    }
+
+    /// Average-assigns `self` with `rhs`, rounding toward positive infinity.
+    /// Sets `self` to `ceil((self + rhs) / 2)` without the intermediate sum
+    /// overflowing. `self` and `rhs` are interpreted as signed if `signed`.
+    /// `None` is returned if the bitwidths do not match.
+    ///
+    /// ```
+    /// use awint::{inlawi, Bits, InlAwi};
+    ///
+    /// let mut x = inlawi!(255u8);
+    /// x.average_ceil_(&inlawi!(254u8), false).unwrap();
+    /// assert_eq!(x, inlawi!(255u8));
+    /// let mut x = inlawi!(-3i8);
+    /// x.average_ceil_(&inlawi!(0i8), true).unwrap();
+    /// assert_eq!(x, inlawi!(-1i8));
+    /// ```
+    #[const_fn(cfg(feature = "const_support"))]
+    #[must_use]
+    pub const fn average_ceil_(&mut self, rhs: &Self, signed: bool) -> Option<()> {
+        self.average(rhs, signed, true)
+    }
 }
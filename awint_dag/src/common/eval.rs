@@ -544,24 +544,12 @@ impl Op<EAwi> {
                             }
                         }
                     }
-                    let inx = inx.to_usize();
-                    let inx_known = inx_known.to_usize();
-                    let mut test_inx = Awi::zero(total_width);
-                    let mut common_eval = None;
-                    let mut r = Awi::zero(w);
-                    for i in 0..(1usize << total_width.get()) {
-                        if (inx & inx_known) == (i & inx_known) {
-                            test_inx.usize_(i);
-                            r.lut_(&lit, &test_inx).unwrap();
-                            if let Some(ref common_eval) = common_eval {
-                                if *common_eval != r {
-                                    return Unevaluatable
-                                }
-                            } else {
-                                common_eval = Some(r.clone());
-                            }
-                        }
+                    let (lut, constant) = Awi::lut_reduce(&lit, &inx_known, &inx).unwrap();
+                    if !constant {
+                        return Unevaluatable
                     }
+                    let mut r = Awi::zero(w);
+                    r.field_width(&lut, w.get()).unwrap();
                     Valid(r)
                 }
             }
@@ -674,6 +662,7 @@ impl Op<EAwi> {
             IsUone([a]) => unary_bit!(w, a, is_uone),
             Lsb([a]) => unary_bit!(w, a, lsb),
             Msb([a]) => unary_bit!(w, a, msb),
+            Parity([a]) => unary_bit!(w, a, parity),
             Lz([a]) => unary_usize!(w, a, lz),
             Tz([a]) => unary_usize!(w, a, tz),
             Sig([a]) => unary_usize!(w, a, sig),
@@ -189,6 +189,7 @@ pub enum Op<T: Debug + DummyDefault + Clone> {
     IsUone([T; 1]),
     Lsb([T; 1]),
     Msb([T; 1]),
+    Parity([T; 1]),
 
     // (&self) -> usize
     Lz([T; 1]),
@@ -336,6 +337,7 @@ impl<T: Debug + DummyDefault + Clone> Op<T> {
             IsUone(_) => "is_uone",
             Lsb(_) => "lsb",
             Msb(_) => "msb",
+            Parity(_) => "parity",
             Lz(_) => "lz",
             Tz(_) => "tz",
             Sig(_) => "sig",
@@ -432,9 +434,8 @@ impl<T: Debug + DummyDefault + Clone> Op<T> {
 
             Not(_) | Rev(_) | Abs(_) => v.push("x"),
 
-            IsZero(_) | IsUmax(_) | IsImax(_) | IsImin(_) | IsUone(_) | Lsb(_) | Msb(_) => {
-                v.push("x")
-            }
+            IsZero(_) | IsUmax(_) | IsImax(_) | IsImin(_) | IsUone(_) | Lsb(_) | Msb(_)
+            | Parity(_) => v.push("x"),
 
             Lz(_) | Tz(_) | Sig(_) | CountOnes(_) => v.push("x"),
 
@@ -535,6 +536,7 @@ impl<T: Debug + DummyDefault + Clone> Op<T> {
             IsUone(v) => v,
             Lsb(v) => v,
             Msb(v) => v,
+            Parity(v) => v,
             Lz(v) => v,
             Tz(v) => v,
             Sig(v) => v,
@@ -615,6 +617,7 @@ impl<T: Debug + DummyDefault + Clone> Op<T> {
             IsUone(v) => v,
             Lsb(v) => v,
             Msb(v) => v,
+            Parity(v) => v,
             Lz(v) => v,
             Tz(v) => v,
             Sig(v) => v,
@@ -748,6 +751,7 @@ impl<T: Debug + DummyDefault + Clone> Op<T> {
             IsUone(v) => IsUone(map1!(m, v)),
             Lsb(v) => Lsb(map1!(m, v)),
             Msb(v) => Msb(map1!(m, v)),
+            Parity(v) => Parity(map1!(m, v)),
             Lz(v) => Lz(map1!(m, v)),
             Tz(v) => Tz(map1!(m, v)),
             Sig(v) => Sig(map1!(m, v)),
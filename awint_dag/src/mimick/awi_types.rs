@@ -415,6 +415,13 @@ impl ExtAwi {
         Self::new(w, Op::Literal(awi::Awi::uone(w)))
     }
 
+    #[must_use]
+    pub fn one_hot_encode(inx: impl Into<dag::usize>, w: NonZeroUsize) -> dag::Option<Self> {
+        let mut tmp = Self::uone(w);
+        let res = tmp.shl_(inx);
+        dag::Option::some_at_dagtime(tmp, res.is_some())
+    }
+
     #[doc(hidden)]
     #[track_caller]
     pub fn panicking_opaque(w: impl Into<dag::usize>) -> Self {
@@ -728,6 +735,13 @@ impl Awi {
         Self::new(w, Op::Literal(awi::Awi::uone(w)))
     }
 
+    #[must_use]
+    pub fn one_hot_encode(inx: impl Into<dag::usize>, w: NonZeroUsize) -> dag::Option<Self> {
+        let mut tmp = Self::uone(w);
+        let res = tmp.shl_(inx);
+        dag::Option::some_at_dagtime(tmp, res.is_some())
+    }
+
     pub fn from_bits_with_capacity(bits: &Bits, _min_capacity: NonZeroUsize) -> Awi {
         Self::from_state(bits.state())
     }
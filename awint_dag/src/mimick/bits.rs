@@ -103,6 +103,29 @@ impl Bits {
         self.nzbw().get()
     }
 
+    #[track_caller]
+    pub fn expect_width(&self, w: usize) -> &Self {
+        if self.bw() != w {
+            panic!(
+                "expected a bitwidth of {w}, found a bitwidth of {}",
+                self.bw()
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    pub fn expect_eq_width(&self, rhs: &Self) -> &Self {
+        if self.bw() != rhs.bw() {
+            panic!(
+                "mismatched bitwidths: `self.bw()` is {}, `rhs.bw()` is {}",
+                self.bw(),
+                rhs.bw()
+            );
+        }
+        self
+    }
+
     pub fn const_as_ref(&self) -> &Self {
         self
     }
@@ -231,6 +231,7 @@ impl Bits {
         is_uone IsUone,
         lsb Lsb,
         msb Msb,
+        parity Parity,
     );
 
     compare!(
@@ -494,6 +495,204 @@ impl Bits {
         )
     }
 
+    #[must_use]
+    pub fn shl_to(&self, s: impl Into<dag::usize>, out: &mut Self) -> Option<()> {
+        if self.bw() != out.bw() {
+            return None
+        }
+        let mut tmp = dag::Awi::from_bits(self);
+        let res = tmp.shl_(s);
+        out.mux_(&tmp, res.is_some()).unwrap_at_runtime();
+        res
+    }
+
+    #[must_use]
+    pub fn lshr_to(&self, s: impl Into<dag::usize>, out: &mut Self) -> Option<()> {
+        if self.bw() != out.bw() {
+            return None
+        }
+        let mut tmp = dag::Awi::from_bits(self);
+        let res = tmp.lshr_(s);
+        out.mux_(&tmp, res.is_some()).unwrap_at_runtime();
+        res
+    }
+
+    #[must_use]
+    pub fn ashr_to(&self, s: impl Into<dag::usize>, out: &mut Self) -> Option<()> {
+        if self.bw() != out.bw() {
+            return None
+        }
+        let mut tmp = dag::Awi::from_bits(self);
+        let res = tmp.ashr_(s);
+        out.mux_(&tmp, res.is_some()).unwrap_at_runtime();
+        res
+    }
+
+    #[must_use]
+    pub fn overflowing_shl_(&mut self, s: impl Into<dag::usize>) -> Option<dag::bool> {
+        let s = s.into();
+        let orig = dag::Awi::from_bits(self);
+        let res = self.shl_(s);
+        // shifting back recovers the original value iff no set bits were lost
+        let mut tmp = dag::Awi::from_bits(self);
+        let _ = tmp.lshr_(s);
+        Option::some_at_dagtime(orig.const_ne(&tmp).unwrap(), res.is_some())
+    }
+
+    #[must_use]
+    pub fn overflowing_ashr_(&mut self, s: impl Into<dag::usize>) -> Option<dag::bool> {
+        let s = s.into();
+        let orig = dag::Awi::from_bits(self);
+        let res = self.ashr_(s);
+        let mut tmp = dag::Awi::from_bits(self);
+        let _ = tmp.shl_(s);
+        Option::some_at_dagtime(orig.const_ne(&tmp).unwrap(), res.is_some())
+    }
+
+    #[must_use]
+    pub fn ashr_round_to_zero_(&mut self, s: impl Into<dag::usize>) -> Option<()> {
+        let s = s.into();
+        let orig = dag::Awi::from_bits(self);
+        let res = self.ashr_(s);
+        let mut tmp = dag::Awi::from_bits(self);
+        let _ = tmp.shl_(s);
+        let inexact = orig.const_ne(&tmp).unwrap();
+        self.inc_(orig.msb() & inexact & res.is_some());
+        res
+    }
+
+    #[must_use]
+    pub fn is_aligned_to(&self, align: &Self) -> Option<dag::bool> {
+        if self.bw() != align.bw() {
+            return None
+        }
+        let mut tmp = dag::Awi::from_bits(self);
+        let _ = tmp.range_and_(dag::usize::from(0)..align.tz());
+        Option::some_at_dagtime(tmp.is_zero(), align.one_hot_decode().is_some())
+    }
+
+    #[must_use]
+    pub fn align_down_(&mut self, align: &Self) -> Option<()> {
+        if self.bw() != align.bw() {
+            return None
+        }
+        let is_pow2 = align.one_hot_decode().is_some();
+        let mut tmp = dag::Awi::from_bits(self);
+        let _ = tmp.range_and_(align.tz()..dag::usize::from(self.bw()));
+        self.mux_(&tmp, is_pow2).unwrap();
+        Option::some_at_dagtime((), is_pow2)
+    }
+
+    #[must_use]
+    pub fn align_up_(&mut self, align: &Self, pad: &mut Self) -> Option<dag::bool> {
+        if (self.bw() != align.bw()) || (self.bw() != pad.bw()) {
+            return None
+        }
+        let is_pow2 = align.one_hot_decode().is_some();
+        let k = align.tz();
+        let mut tmp = dag::Awi::from_bits(self);
+        let _ = tmp.range_and_(dag::usize::from(0)..k);
+        let round = is_pow2 & !tmp.is_zero();
+        pad.copy_(self).unwrap();
+        let _ = pad.range_and_(k..dag::usize::from(self.bw()));
+        let (oflow, _) = tmp.cin_sum_(false, pad, align).unwrap();
+        self.mux_(&tmp, round).unwrap();
+        Option::some_at_dagtime(oflow & round, is_pow2)
+    }
+
+    #[must_use]
+    pub fn rem_power_of_two_(&mut self, k: impl Into<dag::usize>) -> Option<()> {
+        self.range_and_(dag::usize::from(0)..k.into())
+    }
+
+    #[must_use]
+    pub fn srem_power_of_two_(&mut self, k: impl Into<dag::usize>) -> Option<()> {
+        let k = k.into();
+        let neg = self.msb();
+        let res = self.range_and_(dag::usize::from(0)..k);
+        let mut tmp = dag::Awi::from_bits(self);
+        let _ = tmp.range_or_(k..dag::usize::from(self.bw()));
+        let neg_rem = neg & !self.is_zero() & res.is_some();
+        self.mux_(&tmp, neg_rem).unwrap();
+        res
+    }
+
+    #[must_use]
+    pub fn priority_encode(&self) -> Option<dag::usize> {
+        let inx = dag::usize::from(self.bw() - 1).wrapping_sub(self.lz());
+        Option::some_at_dagtime(inx, !self.is_zero())
+    }
+
+    #[must_use]
+    pub fn one_hot_decode(&self) -> Option<dag::usize> {
+        // one hot if nonzero and clearing the least significant set bit results
+        // in zero
+        let mut tmp = dag::Awi::from_bits(self);
+        tmp.dec_(false);
+        tmp.and_(self).unwrap_at_runtime();
+        Option::some_at_dagtime(self.tz(), tmp.is_zero() & !self.is_zero())
+    }
+
+    #[must_use]
+    pub fn or_reduce(&self) -> dag::bool {
+        !self.is_zero()
+    }
+
+    #[must_use]
+    pub fn and_reduce(&self) -> dag::bool {
+        self.is_umax()
+    }
+
+    #[must_use]
+    pub fn xor_reduce(&self) -> dag::bool {
+        self.parity()
+    }
+
+    #[must_use]
+    pub fn conditional_assign_(&mut self, src: &Self, cond: impl Into<dag::bool>) -> Option<()> {
+        self.mux_(src, cond)
+    }
+
+    #[must_use]
+    pub fn leading_run(&self, value: impl Into<dag::bool>) -> dag::usize {
+        let mut inv = dag::Awi::from_bits(self);
+        inv.not_();
+        let mut tmp = dag::Awi::from_bits(self);
+        tmp.mux_(&inv, value).unwrap();
+        tmp.lz()
+    }
+
+    #[must_use]
+    pub fn trailing_run(&self, value: impl Into<dag::bool>) -> dag::usize {
+        let mut inv = dag::Awi::from_bits(self);
+        inv.not_();
+        let mut tmp = dag::Awi::from_bits(self);
+        tmp.mux_(&inv, value).unwrap();
+        tmp.tz()
+    }
+
+    #[must_use]
+    pub fn add_with_carries_(&mut self, rhs: &Self, carries: &mut Self) -> Option<()> {
+        if (self.bw() != rhs.bw()) || (self.bw() != carries.bw()) {
+            return None
+        }
+        carries.copy_(self).unwrap();
+        carries.xor_(rhs).unwrap();
+        self.add_(rhs).unwrap();
+        carries.xor_(self).unwrap();
+        Some(())
+    }
+
+    #[must_use]
+    pub fn conditional_add_(&mut self, rhs: &Self, cond: impl Into<dag::bool>) -> Option<()> {
+        if self.bw() != rhs.bw() {
+            return None
+        }
+        let mut tmp = dag::Awi::from_bits(self);
+        tmp.add_(rhs).unwrap();
+        self.mux_(&tmp, cond)
+    }
+
     #[must_use]
     pub fn lut_(&mut self, lut: &Self, inx: &Self) -> Option<()> {
         let mut res = false;
@@ -704,11 +903,41 @@ impl Bits {
         )
     }
 
+    #[must_use]
+    pub fn set_parity_bit_(
+        &mut self,
+        inx: impl Into<dag::usize>,
+        odd: impl Into<dag::bool>,
+    ) -> Option<()> {
+        let inx = inx.into();
+        // the validity of the second `set` is the same as the first
+        let res = self.set(inx, false);
+        let p = self.parity() ^ odd.into();
+        let _ = self.set(inx, p);
+        res
+    }
+
+    #[must_use]
+    pub fn gf2_dot(&self, rhs: &Self) -> Option<dag::bool> {
+        if self.bw() != rhs.bw() {
+            return None
+        }
+        let mut tmp = dag::Awi::from_bits(self);
+        tmp.and_(rhs).unwrap();
+        Some(tmp.parity())
+    }
+
     pub fn repeat_(&mut self, rhs: &Self) {
         self.update_state(self.state_nzbw(), Repeat([rhs.state()]))
             .unwrap_at_runtime();
     }
 
+    #[must_use]
+    pub fn replicate_(&mut self, pattern: &Self) -> Option<()> {
+        self.repeat_(pattern);
+        Some(())
+    }
+
     pub fn resize_(&mut self, rhs: &Self, extension: impl Into<dag::bool>) {
         self.update_state(
             self.state_nzbw(),
@@ -731,6 +960,18 @@ impl Bits {
         b
     }
 
+    pub fn resize_clamped_(&mut self, rhs: &Self, signed: impl Into<dag::bool>) {
+        let w = self.nzbw();
+        let mut signed_clamp = dag::Awi::imax(w);
+        signed_clamp.mux_(&dag::Awi::imin(w), rhs.msb()).unwrap();
+        let mut signed_res = dag::Awi::zero(w);
+        let signed_oflow = signed_res.sign_resize_(rhs);
+        signed_res.mux_(&signed_clamp, signed_oflow).unwrap();
+        let unsigned_oflow = self.zero_resize_(rhs);
+        self.mux_(&dag::Awi::umax(w), unsigned_oflow).unwrap();
+        self.mux_(&signed_res, signed).unwrap();
+    }
+
     #[must_use]
     pub fn funnel_(&mut self, rhs: &Self, s: &Self) -> Option<()> {
         self.update_state(self.state_nzbw(), Funnel([rhs.state(), s.state()]))
@@ -789,6 +1030,31 @@ impl Bits {
         }
     }
 
+    #[must_use]
+    pub fn clmul_div_rem_(&mut self, div: &Self, rem: &mut Self, pad: &mut Self) -> Option<()> {
+        let w = self.bw();
+        if (w != div.bw()) || (w != rem.bw()) || (w != pad.bw()) {
+            return None
+        }
+        // shift register version that brings in one bit of `self` at a time
+        let duo = dag::Awi::from_bits(self);
+        rem.zero_();
+        for i in (0..w).rev() {
+            // the degree of `rem` is less than that of `div`, so this cannot overflow
+            if w > 1 {
+                rem.shl_(1).unwrap();
+            }
+            rem.set(0, duo.get(i).unwrap()).unwrap();
+            // the degrees are equal iff clearing the leading term makes `rem` smaller
+            pad.copy_(rem).unwrap();
+            pad.xor_(div).unwrap();
+            let b = pad.ult(rem).unwrap();
+            rem.mux_(pad, b).unwrap();
+            self.set(i, b).unwrap();
+        }
+        Option::some_at_dagtime((), !div.is_zero())
+    }
+
     #[must_use]
     pub fn mul_add_(&mut self, lhs: &Self, rhs: &Self) -> Option<()> {
         if (self.bw() != lhs.bw()) || (self.bw() != rhs.bw()) {
@@ -850,6 +1116,100 @@ impl Bits {
             .unwrap_at_runtime();
     }
 
+    pub fn wrapping_neg_(&mut self) {
+        self.neg_(true);
+    }
+
+    pub fn unsigned_abs_(&mut self) {
+        self.abs_();
+    }
+
+    #[must_use]
+    pub fn is_negative(&self) -> dag::bool {
+        self.msb()
+    }
+
+    pub fn rcl_(&mut self, carry: &mut dag::bool) {
+        let msb = self.msb();
+        if self.bw() > 1 {
+            self.shl_(1).unwrap_at_runtime();
+        }
+        self.set(0, *carry).unwrap_at_runtime();
+        *carry = msb;
+    }
+
+    pub fn rcr_(&mut self, carry: &mut dag::bool) {
+        let lsb = self.lsb();
+        if self.bw() > 1 {
+            self.lshr_(1).unwrap_at_runtime();
+        }
+        self.set(self.bw() - 1, *carry).unwrap_at_runtime();
+        *carry = lsb;
+    }
+
+    /// Returns `(self ^ rhs) >> 1`, shifted arithmetically if `signed`
+    fn average_half(&self, rhs: &Self, signed: dag::bool) -> dag::Awi {
+        let mut half = dag::Awi::from_bits(self);
+        half.xor_(rhs).unwrap_at_runtime();
+        let msb = half.msb();
+        if self.bw() > 1 {
+            half.lshr_(1).unwrap_at_runtime();
+        }
+        half.set(self.bw() - 1, signed & msb).unwrap_at_runtime();
+        half
+    }
+
+    #[must_use]
+    pub fn average_floor_(&mut self, rhs: &Self, signed: impl Into<dag::bool>) -> Option<()> {
+        if self.bw() != rhs.bw() {
+            return None
+        }
+        let half = self.average_half(rhs, signed.into());
+        self.and_(rhs).unwrap_at_runtime();
+        self.add_(&half)
+    }
+
+    #[must_use]
+    pub fn average_ceil_(&mut self, rhs: &Self, signed: impl Into<dag::bool>) -> Option<()> {
+        if self.bw() != rhs.bw() {
+            return None
+        }
+        let half = self.average_half(rhs, signed.into());
+        self.or_(rhs).unwrap_at_runtime();
+        self.sub_(&half)
+    }
+
+    pub fn signum_(&mut self) {
+        let neg = self.msb();
+        let zero = self.is_zero();
+        let w = self.nzbw();
+        self.uone_();
+        self.mux_(&dag::Awi::umax(w), neg).unwrap_at_runtime();
+        self.mux_(&dag::Awi::zero(w), zero).unwrap_at_runtime();
+    }
+
+    #[must_use]
+    pub fn widening_add_(&mut self, lhs: &Self, rhs: &Self) -> Option<()> {
+        if (lhs.bw() != rhs.bw()) || (self.bw() != (lhs.bw() + 1)) {
+            return None
+        }
+        let mut tmp = dag::Awi::zero(self.nzbw());
+        tmp.zero_resize_(rhs);
+        self.zero_resize_(lhs);
+        self.add_(&tmp)
+    }
+
+    #[must_use]
+    pub fn widening_sub_(&mut self, lhs: &Self, rhs: &Self) -> Option<()> {
+        if (lhs.bw() != rhs.bw()) || (self.bw() != (lhs.bw() + 1)) {
+            return None
+        }
+        let mut tmp = dag::Awi::zero(self.nzbw());
+        tmp.zero_resize_(rhs);
+        self.zero_resize_(lhs);
+        self.sub_(&tmp)
+    }
+
     #[must_use]
     pub fn cin_sum_(
         &mut self,
@@ -260,12 +260,7 @@ impl<T> Option<T> {
             None => panic!("called `Option::unwrap()` on a `None` value"),
             Some(t) => t,
             Opaque(z) => {
-                let tmp = std::panic::Location::caller();
-                let location = Location {
-                    file: tmp.file(),
-                    line: tmp.line(),
-                    col: tmp.column(),
-                };
+                let location = Location::caller();
                 register_assertion_bit_for_current_epoch(z.is_some, location);
                 if let StdSome(t) = z.t {
                     t
@@ -348,12 +343,7 @@ impl<T> std::ops::Try for Option<T> {
             None => ControlFlow::Break(None),
             Some(t) => ControlFlow::Continue(t),
             Opaque(z) => {
-                let tmp = std::panic::Location::caller();
-                let location = Location {
-                    file: tmp.file(),
-                    line: tmp.line(),
-                    col: tmp.column(),
-                };
+                let location = Location::caller();
                 register_assertion_bit_for_current_epoch(z.is_some, location);
                 if let StdSome(t) = z.t {
                     ControlFlow::Continue(t)
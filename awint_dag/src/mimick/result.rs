@@ -273,12 +273,7 @@ impl<T, E> Result<T, E> {
             Ok(t) => t,
             Err(e) => panic!("called `Result::unwrap()` on an `Err` value: {e:?}"),
             Opaque(z) => {
-                let tmp = std::panic::Location::caller();
-                let location = Location {
-                    file: tmp.file(),
-                    line: tmp.line(),
-                    col: tmp.column(),
-                };
+                let location = Location::caller();
                 register_assertion_bit_for_current_epoch(z.is_ok, location);
                 if let StdOk(t) = z.res {
                     t
@@ -298,12 +293,7 @@ impl<T, E> Result<T, E> {
             Ok(t) => panic!("called `Result::unwrap_err()` on an `Ok` value: {t:?}"),
             Err(e) => e,
             Opaque(z) => {
-                let tmp = std::panic::Location::caller();
-                let location = Location {
-                    file: tmp.file(),
-                    line: tmp.line(),
-                    col: tmp.column(),
-                };
+                let location = Location::caller();
                 register_assertion_bit_for_current_epoch(!z.is_ok, location);
                 if let StdErr(e) = z.res {
                     e
@@ -384,22 +374,12 @@ impl<T, E> std::ops::Try for Result<T, E> {
             Err(e) => ControlFlow::Break(Err(e)),
             Opaque(z) => match z.res {
                 StdOk(t) => {
-                    let tmp = std::panic::Location::caller();
-                    let location = Location {
-                        file: tmp.file(),
-                        line: tmp.line(),
-                        col: tmp.column(),
-                    };
+                    let location = Location::caller();
                     register_assertion_bit_for_current_epoch(z.is_ok, location);
                     ControlFlow::Continue(t)
                 }
                 StdErr(e) => {
-                    let tmp = std::panic::Location::caller();
-                    let location = Location {
-                        file: tmp.file(),
-                        line: tmp.line(),
-                        col: tmp.column(),
-                    };
+                    let location = Location::caller();
                     register_assertion_bit_for_current_epoch(!z.is_ok, location);
                     ControlFlow::Break(Err(e))
                 }
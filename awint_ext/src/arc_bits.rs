@@ -0,0 +1,165 @@
+use alloc::sync::Arc;
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, Index, RangeFull},
+};
+
+use awint_core::{Bits, InlAwi};
+
+use crate::Awi;
+
+/// A reference counted, immutably shared [Awi] with copy-on-write semantics.
+/// Cloning an `ArcBits` only increments an atomic reference count, so large
+/// integers can be cheaply shared between threads and data structures.
+///
+/// This struct implements `Deref<Target = Bits>` for reading. Mutation goes
+/// through [ArcBits::make_mut], which clones the underlying `Awi` first if it
+/// is shared with any other `ArcBits` (like `Arc::make_mut`).
+///
+/// `ArcBits` is `Send` and `Sync` because `Awi` is.
+///
+/// ```
+/// use awint::{awi::*, ArcBits};
+///
+/// let a = ArcBits::new(Awi::from_u64(1234));
+/// let mut b = a.clone();
+/// assert!(ArcBits::ptr_eq(&a, &b));
+/// // `b` is shared, so this clones before writing
+/// b.make_mut().inc_(true);
+/// assert!(!ArcBits::ptr_eq(&a, &b));
+/// assert_eq!(a.to_u64(), 1234);
+/// assert_eq!(b.to_u64(), 1235);
+/// // `b` is now unique, so this writes in place
+/// b.make_mut().inc_(true);
+/// assert_eq!(b.to_u64(), 1236);
+/// ```
+#[derive(Clone)]
+pub struct ArcBits {
+    awi: Arc<Awi>,
+}
+
+impl ArcBits {
+    /// Creates a new `ArcBits` that uniquely owns `awi`
+    pub fn new(awi: Awi) -> Self {
+        Self { awi: Arc::new(awi) }
+    }
+
+    /// Returns a mutable reference to the bits. If there are other `ArcBits`
+    /// sharing the same allocation, the bits are first cloned into a new
+    /// allocation owned by `self` only, so that the other `ArcBits` are not
+    /// affected.
+    pub fn make_mut(&mut self) -> &mut Bits {
+        Arc::<Awi>::make_mut(&mut self.awi)
+    }
+
+    /// Returns a mutable reference to the bits if `self` is the only `ArcBits`
+    /// sharing its allocation, otherwise returns `None`
+    pub fn get_mut(&mut self) -> Option<&mut Bits> {
+        Arc::get_mut(&mut self.awi).map(|awi| awi.as_mut())
+    }
+
+    /// Returns if `this` and `other` share the same allocation
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.awi, &other.awi)
+    }
+
+    /// Returns the number of `ArcBits` sharing the same allocation as `this`
+    pub fn strong_count(this: &Self) -> usize {
+        Arc::strong_count(&this.awi)
+    }
+
+    /// Returns the inner `Awi`, avoiding a clone if `self` is the only
+    /// `ArcBits` sharing its allocation
+    pub fn into_awi(self) -> Awi {
+        match Arc::try_unwrap(self.awi) {
+            Ok(awi) => awi,
+            Err(arc) => Awi::clone(&arc),
+        }
+    }
+}
+
+/// If `self` and `other` have unmatching bit widths, `false` will be returned.
+impl PartialEq for ArcBits {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.as_ref() == rhs.as_ref()
+    }
+}
+
+/// If `self` and `other` have unmatching bit widths, `false` will be returned.
+impl Eq for ArcBits {}
+
+macro_rules! impl_fmt {
+    ($($ty:ident)*) => {
+        $(
+            /// Forwards to the corresponding impl for `Bits`
+            impl fmt::$ty for ArcBits {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::$ty::fmt(self.as_ref(), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt!(Debug Display LowerHex UpperHex Octal Binary);
+
+impl Hash for ArcBits {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state);
+    }
+}
+
+impl Deref for ArcBits {
+    type Target = Bits;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.awi
+    }
+}
+
+impl Index<RangeFull> for ArcBits {
+    type Output = Bits;
+
+    #[inline]
+    fn index(&self, _i: RangeFull) -> &Bits {
+        self
+    }
+}
+
+impl Borrow<Bits> for ArcBits {
+    #[inline]
+    fn borrow(&self) -> &Bits {
+        self
+    }
+}
+
+impl AsRef<Bits> for ArcBits {
+    #[inline]
+    fn as_ref(&self) -> &Bits {
+        self
+    }
+}
+
+/// Creates an `ArcBits` that uniquely owns the `Awi`
+impl From<Awi> for ArcBits {
+    fn from(awi: Awi) -> ArcBits {
+        ArcBits::new(awi)
+    }
+}
+
+/// Creates an `ArcBits` from copying a `Bits` reference
+impl From<&Bits> for ArcBits {
+    fn from(bits: &Bits) -> ArcBits {
+        ArcBits::new(Awi::from(bits))
+    }
+}
+
+/// Creates an `ArcBits` from copying an `InlAwi`
+impl<const BW: usize, const LEN: usize> From<InlAwi<BW, LEN>> for ArcBits {
+    fn from(awi: InlAwi<BW, LEN>) -> ArcBits {
+        ArcBits::new(Awi::from(awi))
+    }
+}
@@ -1,12 +1,15 @@
 use alloc::{string::String, vec::Vec};
 use core::num::NonZeroUsize;
 
-use awint_core::{Bits, SerdeError};
+use awint_core::{awint_internals::Digit, Bits, SerdeError};
 
 use crate::{
     string_internals::{
-        bits_to_string_radix, bits_to_vec_radix, internal_from_bytes_general,
-        internal_from_bytes_radix, internal_from_str,
+        bcd_bw, bit_string_bw, bits_to_bit_string, bits_to_portable_bytes, bits_to_radix_digits,
+        bits_to_string_grouped, bits_to_string_radix, bits_to_vec_radix, from_bcd_bw,
+        internal_bits_to_bcd, internal_from_bcd, internal_from_bit_string,
+        internal_from_bytes_general, internal_from_bytes_radix, internal_from_portable_bytes,
+        internal_from_str, portable_bytes_bw,
     },
     Awi,
 };
@@ -50,6 +53,44 @@ impl Awi {
         bits_to_string_radix(bits, signed, radix, upper, min_chars)
     }
 
+    /// Creates a string representing `bits` in the given `radix`, with `sep`
+    /// inserted between every `group` digits counting from the least
+    /// significant digit. No separators are inserted if `group == 0`. At least
+    /// one digit is always included, and prefixes and postfixes are not
+    /// included. If `signed` is true and the most significant bit of `bits` is
+    /// set, the magnitude is formatted and a leading `-` is added, with
+    /// grouping applied to the magnitude digits only. This function performs
+    /// allocation.
+    ///
+    /// ```
+    /// use awint::{awi::*, inlawi};
+    ///
+    /// let x = inlawi!(1000000u32);
+    /// let s = Awi::bits_to_string_grouped(&x, false, 10, false, 3, '_').unwrap();
+    /// assert_eq!(s, "1_000_000");
+    /// let x = inlawi!(0xdeadbeefu32);
+    /// let s = Awi::bits_to_string_grouped(&x, false, 16, false, 4, '_').unwrap();
+    /// assert_eq!(s, "dead_beef");
+    /// let x = inlawi!(-12345i32);
+    /// let s = Awi::bits_to_string_grouped(&x, true, 10, false, 3, ',').unwrap();
+    /// assert_eq!(s, "-12,345");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This can only return an error if `radix` is not in the range 2..=36 or
+    /// if resource exhaustion occurs.
+    pub fn bits_to_string_grouped(
+        bits: &Bits,
+        signed: bool,
+        radix: u8,
+        upper: bool,
+        group: usize,
+        sep: char,
+    ) -> Result<String, SerdeError> {
+        bits_to_string_grouped(bits, signed, radix, upper, group, sep)
+    }
+
     /// Creates an `Awi` representing the given arguments. This function
     /// performs allocation. This is a wrapper around
     /// [awint_core::Bits::bytes_radix_] that zero or sign resizes the
@@ -147,6 +188,131 @@ impl Awi {
         )?;
         Ok(res)
     }
+
+    /// Creates a portable byte representation of `bits`. This is the bitwidth
+    /// as a little-endian `u64`, followed by the `ceil(bits.bw() / 8)` bytes
+    /// of the value in little-endian order. This representation is
+    /// independent of the architecture and the `Digit` type selected by the
+    /// `*_digits` features, so it can be used to exchange values between
+    /// builds with different `Digit`s. This matches the format used by
+    /// `borsh_support`.
+    ///
+    /// ```
+    /// use awint::{inlawi, Awi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0x234u12);
+    /// let bytes = Awi::bits_to_portable_bytes(&x);
+    /// assert_eq!(bytes, vec![12, 0, 0, 0, 0, 0, 0, 0, 0x34, 0x2]);
+    /// assert_eq!(
+    ///     Awi::from_portable_bytes(&bytes).unwrap().as_ref(),
+    ///     x.as_ref()
+    /// );
+    /// ```
+    pub fn bits_to_portable_bytes(bits: &Bits) -> Vec<u8> {
+        bits_to_portable_bytes(bits)
+    }
+
+    /// Creates an `Awi` from the representation created by
+    /// [Awi::bits_to_portable_bytes].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Empty` if `src` is too short to hold the bitwidth,
+    /// `ZeroBitwidth` if the bitwidth is zero, `NonEqualWidths` if the number
+    /// of value bytes does not match the bitwidth, and `Overflow` if the
+    /// bitwidth does not fit in a `usize` or if bits beyond the bitwidth are
+    /// set.
+    pub fn from_portable_bytes(src: &[u8]) -> Result<Awi, SerdeError> {
+        let mut res = Awi::zero(portable_bytes_bw(src)?);
+        internal_from_portable_bytes(&mut res, src)?;
+        Ok(res)
+    }
+
+    /// Creates the packed binary-coded decimal representation of the unsigned
+    /// value of `bits`, with 4 bits per decimal digit and the least
+    /// significant decimal digit in the least significant nibble. The
+    /// bitwidth is 4 times the number of decimal digits in the maximum
+    /// unsigned value of `bits`, so there may be leading zero digits. This uses
+    /// the shift-and-add-3 double dabble algorithm.
+    ///
+    /// ```
+    /// use awint::{inlawi, Awi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(1234u16);
+    /// let bcd = Awi::bits_to_bcd(&x);
+    /// assert_eq!(bcd.as_ref(), inlawi!(0x01234_u20).as_ref());
+    /// assert_eq!(Awi::from_bcd(&bcd).unwrap().to_u16(), 1234);
+    /// ```
+    pub fn bits_to_bcd(bits: &Bits) -> Awi {
+        let mut res = Awi::zero(bcd_bw(bits));
+        internal_bits_to_bcd(&mut res, bits);
+        res
+    }
+
+    /// Creates an `Awi` from the packed binary-coded decimal representation
+    /// `bcd`, in the format created by [Awi::bits_to_bcd]. The bitwidth of
+    /// the result is the number of bits needed for the largest value with as
+    /// many decimal digits as `bcd` has nibbles.
+    /// Returns `None` if any nibble of `bcd` is not in the range 0..=9.
+    pub fn from_bcd(bcd: &Bits) -> Option<Awi> {
+        let mut res = Awi::zero(from_bcd_bw(bcd));
+        internal_from_bcd(&mut res, bcd)?;
+        Some(res)
+    }
+
+    /// Clears `out` and pushes the base `radix` digits of the unsigned value of
+    /// `bits` to it, least significant digit first. Any `radix` in
+    /// `2..=Digit::MAX` is supported, unlike the string formatting functions
+    /// which are limited to radixes up to 36. A zero value results in a single
+    /// zero digit. Returns `None` if `radix < 2`.
+    ///
+    /// ```
+    /// use awint::{awi::*, awint_internals::Digit};
+    ///
+    /// let mut out: Vec<Digit> = vec![];
+    /// Awi::bits_to_radix_digits(&inlawi!(1234u16), 100, &mut out).unwrap();
+    /// assert_eq!(out, vec![34, 12]);
+    /// Awi::bits_to_radix_digits(&inlawi!(0u16), 100, &mut out).unwrap();
+    /// assert_eq!(out, vec![0]);
+    /// ```
+    pub fn bits_to_radix_digits(bits: &Bits, radix: Digit, out: &mut Vec<Digit>) -> Option<()> {
+        bits_to_radix_digits(bits, radix, out)
+    }
+
+    /// Creates a string of exactly `bits.bw()` `'0'` and `'1'` chars
+    /// representing the bits of `bits`, in most significant bit first order if
+    /// `msb_first` or least significant bit first order otherwise. Unlike the
+    /// radix 2 formatting functions, leading zeros are kept and there are no
+    /// prefixes, suffixes, or separators.
+    ///
+    /// ```
+    /// use awint::{inlawi, Awi, Bits, InlAwi};
+    ///
+    /// let x = inlawi!(0b0010_1100_u8);
+    /// assert_eq!(Awi::bits_to_bit_string(&x, true), "00101100");
+    /// assert_eq!(Awi::bits_to_bit_string(&x, false), "00110100");
+    /// assert_eq!(
+    ///     Awi::from_bit_string("00101100").unwrap().as_ref(),
+    ///     x.as_ref()
+    /// );
+    /// ```
+    pub fn bits_to_bit_string(bits: &Bits, msb_first: bool) -> String {
+        bits_to_bit_string(bits, msb_first)
+    }
+
+    /// Creates an `Awi` from a most significant bit first string of `'0'`
+    /// and `'1'` chars, in the format created by [Awi::bits_to_bit_string].
+    /// The bitwidth is the number of chars.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Empty` if `src` is empty, and `InvalidChar` if any char is
+    /// not `'0'` or `'1'`.
+    pub fn from_bit_string(src: &str) -> Result<Awi, SerdeError> {
+        let mut res = Awi::zero(bit_string_bw(src)?);
+        internal_from_bit_string(&mut res, src)?;
+        Ok(res)
+    }
 }
 
 impl core::str::FromStr for Awi {
@@ -0,0 +1,101 @@
+//! Precomputed bit permutations
+
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+use awint_core::Bits;
+
+use crate::awint_internals::*;
+
+/// A run of `len` consecutive bits moving from `src` in the source to `dst`
+/// in the destination. Runs never cross a digit boundary in the destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Run {
+    src: usize,
+    dst: usize,
+    len: usize,
+}
+
+/// A fixed permutation of the bits of a `Bits` of a certain bitwidth. The
+/// permutation is validated and preprocessed once on construction, so that
+/// [BitPermutation::apply] can be used efficiently on many inputs, such as
+/// for the P-boxes of ciphers.
+///
+/// Internally, the permutation is split into runs of consecutive bits that
+/// move together, so that whole groups of bits are gathered with one shift
+/// and mask. Structured permutations such as rotations or byte swaps apply in
+/// time proportional to the number of digits, and arbitrary permutations
+/// apply in time proportional to the number of bits.
+///
+/// ```
+/// use awint::{awi::*, bit_permutation::BitPermutation};
+///
+/// // output bit `i` comes from source bit `map[i]`
+/// let perm = BitPermutation::new(&[1, 2, 3, 0, 7, 6, 5, 4]).unwrap();
+/// let mut dst = inlawi!(0u8);
+/// perm.apply(&inlawi!(0b1100_0110u8), &mut dst).unwrap();
+/// assert_eq!(dst, inlawi!(0b0011_0011u8));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitPermutation {
+    nzbw: NonZeroUsize,
+    runs: Vec<Run>,
+}
+
+impl BitPermutation {
+    /// Creates a `BitPermutation` where output bit `i` is taken from the
+    /// source bit `map[i]`. The bitwidth of the permutation is `map.len()`.
+    /// Returns `None` if `map` is empty or is not a permutation of
+    /// `0..map.len()`.
+    pub fn new(map: &[usize]) -> Option<Self> {
+        let nzbw = NonZeroUsize::new(map.len())?;
+        let mut seen = Vec::new();
+        seen.resize(map.len(), false);
+        for src in map {
+            if (*src >= map.len()) || seen[*src] {
+                return None
+            }
+            seen[*src] = true;
+        }
+        let mut runs: Vec<Run> = Vec::new();
+        for (dst, src) in map.iter().copied().enumerate() {
+            if let Some(run) = runs.last_mut() {
+                if ((run.src + run.len) == src)
+                    && ((dst % BITS) != 0)
+                    && ((run.dst + run.len) == dst)
+                {
+                    run.len += 1;
+                    continue
+                }
+            }
+            runs.push(Run { src, dst, len: 1 });
+        }
+        Some(Self { nzbw, runs })
+    }
+
+    /// Returns the bitwidth of the permutation as a `NonZeroUsize`
+    pub fn nzbw(&self) -> NonZeroUsize {
+        self.nzbw
+    }
+
+    /// Returns the bitwidth of the permutation as a `usize`
+    pub fn bw(&self) -> usize {
+        self.nzbw.get()
+    }
+
+    /// Assigns the permutation of the bits of `src` to `dst`. Returns `None`
+    /// if the bitwidths of `src` and `dst` are not both equal to `self.bw()`.
+    pub fn apply(&self, src: &Bits, dst: &mut Bits) -> Option<()> {
+        if (src.bw() != self.bw()) || (dst.bw() != self.bw()) {
+            return None
+        }
+        dst.zero_();
+        let digits = dst.as_mut_slice();
+        for run in &self.runs {
+            // `run.len <= BITS` because runs do not cross digit boundaries
+            let mask = MAX >> (BITS - run.len);
+            digits[run.dst / BITS] |= (src.get_digit(run.src) & mask) << (run.dst % BITS);
+        }
+        Some(())
+    }
+}
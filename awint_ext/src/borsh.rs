@@ -0,0 +1,98 @@
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+use awint_core::{borsh_deserialize_bits, borsh_deserialize_bw};
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::{string_internals::bytes_len, Awi, ExtAwi};
+
+/// The most bytes that `read_payload` will allocate ahead of actually reading
+/// them
+const PAYLOAD_CHUNK: usize = 1 << 12;
+
+/// Reads the `ceil(w / 8)` value bytes of the `borsh_support` format. The
+/// bitwidth prefix is untrusted, so the buffer only grows in bounded chunks as
+/// bytes are actually read, and a short input returns an error before anything
+/// close to `w` bits is allocated.
+fn read_payload<R: Read>(reader: &mut R, w: NonZeroUsize) -> Result<Vec<u8>> {
+    let len = bytes_len(w.get());
+    let mut buf = Vec::new();
+    while buf.len() < len {
+        let start = buf.len();
+        let n = if (len - start) < PAYLOAD_CHUNK {
+            len - start
+        } else {
+            PAYLOAD_CHUNK
+        };
+        buf.resize(start + n, 0);
+        reader.read_exact(&mut buf[start..])?;
+    }
+    Ok(buf)
+}
+
+/// A `borsh_support` impl
+impl BorshSerialize for ExtAwi {
+    /// Forwards to the corresponding impl for `Bits`, which writes the
+    /// bitwidth as a little-endian `u64` followed by the `ceil(bw / 8)` bytes
+    /// of the value in little-endian order
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_ref().serialize(writer)
+    }
+}
+
+/// A `borsh_support` impl
+impl BorshDeserialize for ExtAwi {
+    /// Deserializes the format of the `BorshSerialize` impl for `Bits`,
+    /// returning an error if the bitwidth is zero or if the bits beyond the
+    /// bitwidth in the last byte are not zero
+    ///
+    /// ```
+    /// use awint::{extawi, Bits, ExtAwi, InlAwi};
+    ///
+    /// let x = extawi!(0xfedcba9876543210u100);
+    /// let bytes = borsh::to_vec(&x).unwrap();
+    /// assert_eq!(borsh::from_slice::<ExtAwi>(&bytes).unwrap(), x);
+    /// ```
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let w = borsh_deserialize_bw(reader)?;
+        let payload = read_payload(reader, w)?;
+        let mut res = ExtAwi::zero(w);
+        borsh_deserialize_bits(&mut res, &mut payload.as_slice())?;
+        Ok(res)
+    }
+}
+
+/// A `borsh_support` impl
+impl BorshSerialize for Awi {
+    /// Forwards to the corresponding impl for `Bits`, which writes the
+    /// bitwidth as a little-endian `u64` followed by the `ceil(bw / 8)` bytes
+    /// of the value in little-endian order
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_ref().serialize(writer)
+    }
+}
+
+/// A `borsh_support` impl
+impl BorshDeserialize for Awi {
+    /// Deserializes the format of the `BorshSerialize` impl for `Bits`,
+    /// returning an error if the bitwidth is zero or if the bits beyond the
+    /// bitwidth in the last byte are not zero
+    ///
+    /// ```
+    /// use awint::{awi, Awi, Bits, InlAwi};
+    ///
+    /// let x = awi!(0xfedcba9876543210u100);
+    /// let bytes = borsh::to_vec(&x).unwrap();
+    /// assert_eq!(borsh::from_slice::<Awi>(&bytes).unwrap(), x);
+    /// ```
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let w = borsh_deserialize_bw(reader)?;
+        let payload = read_payload(reader, w)?;
+        let mut res = Awi::zero(w);
+        borsh_deserialize_bits(&mut res, &mut payload.as_slice())?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,111 @@
+//! Arbitrary width cyclic redundancy checks
+
+use awint_core::Bits;
+
+use crate::ExtAwi;
+
+/// A cyclic redundancy check of arbitrary width, parameterized in the style of
+/// the "Rocksoft" model. The width of the CRC is the bitwidth of `poly`, and
+/// `poly` excludes the implicit leading `x^w` term. The register update is
+/// polynomial division over GF(2) (see [Bits::clmul_div_rem_]) performed one
+/// message bit at a time.
+///
+/// ```
+/// use awint::{awi::*, crc::Crc};
+///
+/// // CRC-32/ISO-HDLC, the CRC used by zlib and Ethernet
+/// let mut crc = Crc::new(
+///     extawi!(0x04c11db7_u32),
+///     extawi!(umax: ..32),
+///     true,
+///     true,
+///     extawi!(umax: ..32),
+/// )
+/// .unwrap();
+/// crc.update(b"123456789");
+/// assert_eq!(crc.finalize(), extawi!(0xcbf43926_u32));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Crc {
+    poly: ExtAwi,
+    init: ExtAwi,
+    reflect_in: bool,
+    reflect_out: bool,
+    xor_out: ExtAwi,
+    state: ExtAwi,
+}
+
+impl Crc {
+    /// Creates a new `Crc` with the state set to `init`. If `reflect_in` is
+    /// set, the bits of each input byte are processed least significant first,
+    /// otherwise they are processed most significant first. If `reflect_out`
+    /// is set, the state is bit reversed before it is XORed with `xor_out` in
+    /// [Crc::finalize]. Returns `None` if the bitwidths of `poly`, `init`, and
+    /// `xor_out` are not all equal.
+    pub fn new(
+        poly: ExtAwi,
+        init: ExtAwi,
+        reflect_in: bool,
+        reflect_out: bool,
+        xor_out: ExtAwi,
+    ) -> Option<Self> {
+        if (poly.bw() != init.bw()) || (poly.bw() != xor_out.bw()) {
+            return None
+        }
+        Some(Self {
+            poly,
+            state: init.clone(),
+            init,
+            reflect_in,
+            reflect_out,
+            xor_out,
+        })
+    }
+
+    /// Returns the width of the CRC
+    pub fn bw(&self) -> usize {
+        self.poly.bw()
+    }
+
+    /// Returns the polynomial of the CRC
+    pub fn poly(&self) -> &Bits {
+        &self.poly
+    }
+
+    /// Resets the state back to the initial value, so that a new message can
+    /// be processed
+    pub fn reset(&mut self) {
+        self.state.copy_(&self.init).unwrap();
+    }
+
+    /// Updates the state with the bytes of `bytes`
+    pub fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            for i in 0..8 {
+                let bit = if self.reflect_in {
+                    (byte >> i) & 1
+                } else {
+                    (byte >> (7 - i)) & 1
+                };
+                // shift the state left, subtracting the polynomial if the
+                // leading term becomes `x^w`
+                let mut carry = false;
+                self.state.rcl_(&mut carry);
+                if carry != (bit != 0) {
+                    self.state.xor_(&self.poly).unwrap();
+                }
+            }
+        }
+    }
+
+    /// Returns the CRC of all the bytes passed to [Crc::update] since the
+    /// last reset. This does not change the state.
+    pub fn finalize(&self) -> ExtAwi {
+        let mut res = self.state.clone();
+        if self.reflect_out {
+            res.rev_();
+        }
+        res.xor_(&self.xor_out).unwrap();
+        res
+    }
+}
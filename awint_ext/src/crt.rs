@@ -0,0 +1,105 @@
+//! Chinese remainder theorem reconstruction
+
+use alloc::vec::Vec;
+
+use awint_core::{bw, Bits};
+
+use crate::ExtAwi;
+
+/// Reconstructs the unique value `x` with `0 <= x < M` that satisfies `x ==
+/// residues[i] (mod moduli[i])` for all `i`, where `M` is the product of the
+/// unsigned `moduli`. Residues are reduced by their moduli first, so they do
+/// not have to be less than their moduli. The operands can have arbitrary
+/// bitwidths, and the result has a bitwidth equal to the sum of the bitwidths
+/// of `moduli`, which is always enough to hold `M - 1`.
+///
+/// `None` is returned if `residues` and `moduli` have different lengths, if
+/// they are empty, if any modulus is zero, or if the moduli are not pairwise
+/// coprime.
+///
+/// ```
+/// use awint::{awi::*, crt::crt};
+///
+/// // x == 2 (mod 3), x == 3 (mod 5), x == 2 (mod 7)
+/// let x = crt(&[&inlawi!(2u8), &inlawi!(3u8), &inlawi!(2u8)], &[
+///     &inlawi!(3u8),
+///     &inlawi!(5u8),
+///     &inlawi!(7u8),
+/// ])
+/// .unwrap();
+/// assert_eq!(x.bw(), 24);
+/// assert_eq!(x.to_u32(), 23);
+/// // 4 and 6 are not coprime
+/// assert!(crt(&[&inlawi!(1u8), &inlawi!(1u8)], &[
+///     &inlawi!(4u8),
+///     &inlawi!(6u8)
+/// ])
+/// .is_none());
+/// ```
+pub fn crt(residues: &[&Bits], moduli: &[&Bits]) -> Option<ExtAwi> {
+    if residues.is_empty() || (residues.len() != moduli.len()) {
+        return None
+    }
+    let mut res_w = 0usize;
+    for m in moduli {
+        if m.is_zero() {
+            return None
+        }
+        res_w = res_w.checked_add(m.bw())?;
+    }
+    // enough for the products of two values less than `M`, and for the
+    // coefficients of `extended_gcd_` to be correct as signed values
+    let mut w = res_w.checked_mul(2)?.checked_add(2)?;
+    for r in residues {
+        w = w.max(r.bw());
+    }
+    let w = bw(w);
+    let mut pads: Vec<ExtAwi> = (0..5).map(|_| ExtAwi::zero(w)).collect();
+    let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.as_mut()).collect();
+    // the product of the moduli so far
+    let mut prod = ExtAwi::zero(w);
+    // the solution so far
+    let mut x = ExtAwi::zero(w);
+    let mut m = ExtAwi::zero(w);
+    let mut r = ExtAwi::zero(w);
+    let mut a = ExtAwi::zero(w);
+    let mut b = ExtAwi::zero(w);
+    let mut u = ExtAwi::zero(w);
+    let mut v = ExtAwi::zero(w);
+    let mut quo = ExtAwi::zero(w);
+    let mut tmp = ExtAwi::zero(w);
+    prod.uone_();
+    for (residue, modulus) in residues.iter().zip(moduli.iter()) {
+        m.zero_resize_(modulus);
+        tmp.zero_resize_(residue);
+        Bits::udivide(&mut quo, &mut r, &tmp, &m).unwrap();
+        // `prod * u + m * v == 1`
+        a.copy_(&prod).unwrap();
+        b.copy_(&m).unwrap();
+        Bits::extended_gcd_(&mut a, &mut b, &mut u, &mut v, &mut pads).unwrap();
+        if !a.is_uone() {
+            return None
+        }
+        // `u` is the inverse of `prod` modulo `m`, make it nonnegative
+        if u.msb() {
+            u.add_(&m).unwrap();
+        }
+        // `k = ((r - x) * u) mod m`
+        Bits::udivide(&mut quo, &mut tmp, &x, &m).unwrap();
+        if r.ult(&tmp).unwrap() {
+            r.add_(&m).unwrap();
+        }
+        r.sub_(&tmp).unwrap();
+        tmp.zero_();
+        tmp.mul_add_(&r, &u).unwrap();
+        Bits::udivide(&mut quo, &mut r, &tmp, &m).unwrap();
+        // `x += prod * k`, which now satisfies all the congruences so far
+        x.mul_add_(&prod, &r).unwrap();
+        tmp.copy_(&prod).unwrap();
+        prod.zero_();
+        prod.mul_add_(&tmp, &m).unwrap();
+    }
+    let mut res = ExtAwi::zero(bw(res_w));
+    res.zero_resize_(&x);
+    Some(res)
+}
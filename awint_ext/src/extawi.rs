@@ -1,4 +1,6 @@
+mod arith;
 mod awi;
+mod floats;
 mod strings;
 
 pub use awi::*;
@@ -0,0 +1,125 @@
+#[cfg(feature = "rand_support")]
+use alloc::vec::Vec;
+use core::cmp;
+#[cfg(feature = "rand_support")]
+use core::num::NonZeroUsize;
+
+use awint_core::{bw, Bits};
+
+use crate::ExtAwi;
+
+/// # Arithmetic
+impl ExtAwi {
+    /// Computes the unsigned dot product `sum(a_i * b_i)` of the pairs in
+    /// `terms`. The result has a bitwidth of `max_operand_bw*2 +
+    /// ceil(log2(n))`, where `max_operand_bw` is the largest bitwidth among
+    /// the operands and `n` is the number of pairs, which is always enough to
+    /// avoid overflow. `None` is returned if `terms` is empty or if the two
+    /// operands of any pair have different bitwidths.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// let a0 = inlawi!(3u8);
+    /// let b0 = inlawi!(255u8);
+    /// let a1 = inlawi!(0xffffu16);
+    /// let b1 = inlawi!(0xffffu16);
+    /// let res = ExtAwi::dot_product(&[(&a0, &b0), (&a1, &b1)]).unwrap();
+    /// assert_eq!(res.bw(), 33);
+    /// assert_eq!(res.to_u64(), 3 * 255 + 0xffff * 0xffff);
+    /// assert!(ExtAwi::dot_product(&[(&a0, &a1)]).is_none());
+    /// ```
+    pub fn dot_product(terms: &[(&Bits, &Bits)]) -> Option<ExtAwi> {
+        if terms.is_empty() {
+            return None
+        }
+        let mut max_w = 0;
+        for (lhs, rhs) in terms {
+            if lhs.bw() != rhs.bw() {
+                return None
+            }
+            max_w = cmp::max(max_w, lhs.bw());
+        }
+        // `ceil(log2(n))` for `n >= 1`
+        let extra = (usize::BITS - (terms.len() - 1).leading_zeros()) as usize;
+        let mut res = ExtAwi::zero(bw(max_w.checked_mul(2)?.checked_add(extra)?));
+        for (lhs, rhs) in terms {
+            res.arb_umul_add_(lhs, rhs);
+        }
+        Some(res)
+    }
+
+    /// Computes the unsigned sum of the equal width operands in `terms`. The
+    /// result has a bitwidth of `operand_bw + ceil(log2(n))`, where `n` is the
+    /// number of operands, which is always enough to avoid overflow. `None` is
+    /// returned if `terms` is empty or if the operands have different
+    /// bitwidths.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// let a = inlawi!(255u8);
+    /// let b = inlawi!(254u8);
+    /// let c = inlawi!(253u8);
+    /// let res = ExtAwi::sum(&[&a, &b, &c]).unwrap();
+    /// assert_eq!(res.bw(), 10);
+    /// assert_eq!(res.to_u64(), 255 + 254 + 253);
+    /// assert!(ExtAwi::sum(&[&a, &inlawi!(0u9)]).is_none());
+    /// ```
+    pub fn sum(terms: &[&Bits]) -> Option<ExtAwi> {
+        let w = terms.first()?.bw();
+        if terms.iter().any(|x| x.bw() != w) {
+            return None
+        }
+        // `ceil(log2(n))` for `n >= 1`
+        let extra = (usize::BITS - (terms.len() - 1).leading_zeros()) as usize;
+        let mut res = ExtAwi::zero(bw(w.checked_add(extra)?));
+        let mut tmp = ExtAwi::zero(res.nzbw());
+        for x in terms {
+            tmp.zero_resize_(x);
+            res.add_(&tmp).unwrap();
+        }
+        Some(res)
+    }
+}
+
+#[cfg(feature = "rand_support")]
+impl ExtAwi {
+    /// Samples random odd candidates of bitwidth `w` with the most significant
+    /// bit set until one passes [Bits::is_probably_prime] with `rounds`
+    /// rounds, and returns it. Because the primality test is probabilistic,
+    /// the result is only prime with high probability (see
+    /// [Bits::is_probably_prime]). Returns `None` if `w < 2`, in which case no
+    /// such prime exists, or if `rng` returns an error.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    /// use rand_xoshiro::{rand_core::SeedableRng, Xoshiro128StarStar};
+    ///
+    /// let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    /// let p = ExtAwi::random_prime(bw(64), 32, &mut rng).unwrap();
+    /// assert_eq!(p.bw(), 64);
+    /// assert!(p.msb() && p.lsb());
+    /// assert!(ExtAwi::random_prime(bw(1), 32, &mut rng).is_none());
+    /// ```
+    pub fn random_prime<R>(w: NonZeroUsize, rounds: usize, rng: &mut R) -> Option<ExtAwi>
+    where
+        R: rand_core::RngCore,
+    {
+        if w.get() < 2 {
+            return None
+        }
+        let pad_w = NonZeroUsize::new(w.get() * 2).unwrap();
+        let mut pads: Vec<ExtAwi> = (0..6).map(|_| ExtAwi::zero(pad_w)).collect();
+        let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.as_mut()).collect();
+        let mut res = ExtAwi::zero(w);
+        loop {
+            res.rand_(rng).ok()?;
+            res.set(0, true).unwrap();
+            res.set(w.get() - 1, true).unwrap();
+            if res.is_probably_prime(rounds, rng, &mut pads)? {
+                return Some(res)
+            }
+        }
+    }
+}
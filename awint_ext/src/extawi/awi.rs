@@ -1,4 +1,7 @@
-use alloc::alloc::{alloc, alloc_zeroed, dealloc, Layout};
+use alloc::{
+    alloc::{alloc, alloc_zeroed, dealloc, Layout},
+    vec::Vec,
+};
 use core::{
     borrow::{Borrow, BorrowMut},
     fmt,
@@ -186,6 +189,29 @@ impl<'a> ExtAwi {
         tmp
     }
 
+    /// Creates an `ExtAwi` with bitwidth `w` from copying a `Bits` reference.
+    /// If `w` is larger than `bits.bw()`, the value is sign extended if
+    /// `signed` and zero extended otherwise. If `w` is smaller, the value is
+    /// truncated. This is equivalent to [ExtAwi::from_bits] followed by
+    /// [Bits::resize_] into a new `ExtAwi`, but with only one allocation.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// let x = inlawi!(-3i8);
+    /// assert_eq!(ExtAwi::from_bits_resize(&x, bw(16), true), extawi!(-3i16));
+    /// assert_eq!(
+    ///     ExtAwi::from_bits_resize(&x, bw(16), false),
+    ///     extawi!(0xfdu16)
+    /// );
+    /// assert_eq!(ExtAwi::from_bits_resize(&x, bw(4), true), extawi!(0xdu4));
+    /// ```
+    pub fn from_bits_resize(bits: &Bits, w: NonZeroUsize, signed: bool) -> ExtAwi {
+        let mut tmp = ExtAwi::zero(w);
+        tmp.const_as_mut().resize_(bits, signed && bits.msb());
+        tmp
+    }
+
     /// Zero-value construction with bitwidth `w`
     pub fn zero(w: NonZeroUsize) -> Self {
         // Safety: This satisfies `ExtAwi::from_raw_parts`
@@ -230,30 +256,35 @@ impl<'a> ExtAwi {
 
     /// Used by `awint_macros` in avoiding a `NonZeroUsize` dependency
     #[doc(hidden)]
+    #[track_caller]
     pub fn panicking_zero(w: usize) -> Self {
         Self::zero(NonZeroUsize::new(w).unwrap())
     }
 
     /// Used by `awint_macros` in avoiding a `NonZeroUsize` dependency
     #[doc(hidden)]
+    #[track_caller]
     pub fn panicking_umax(w: usize) -> Self {
         Self::umax(NonZeroUsize::new(w).unwrap())
     }
 
     /// Used by `awint_macros` in avoiding a `NonZeroUsize` dependency
     #[doc(hidden)]
+    #[track_caller]
     pub fn panicking_imax(w: usize) -> Self {
         Self::imax(NonZeroUsize::new(w).unwrap())
     }
 
     /// Used by `awint_macros` in avoiding a `NonZeroUsize` dependency
     #[doc(hidden)]
+    #[track_caller]
     pub fn panicking_imin(w: usize) -> Self {
         Self::imin(NonZeroUsize::new(w).unwrap())
     }
 
     /// Used by `awint_macros` in avoiding a `NonZeroUsize` dependency
     #[doc(hidden)]
+    #[track_caller]
     pub fn panicking_uone(w: usize) -> Self {
         Self::uone(NonZeroUsize::new(w).unwrap())
     }
@@ -440,6 +471,230 @@ impl ExtAwi {
         tmp.digit_(x);
         tmp
     }
+
+    /// Creates an `ExtAwi` from a slice of `bool`s in least significant bit
+    /// first order, with a bitwidth equal to `bits.len()`. An empty slice
+    /// results in a single zero bit.
+    ///
+    /// ```
+    /// use awint::{awi::*, extawi};
+    ///
+    /// let x = ExtAwi::from_bool_slice(&[true, false, true, true]);
+    /// assert_eq!(x, extawi!(1101));
+    /// assert_eq!(ExtAwi::bits_to_bool_vec(&x), [true, false, true, true]);
+    /// assert_eq!(ExtAwi::from_bool_slice(&[]), extawi!(0));
+    /// ```
+    pub fn from_bool_slice(bits: &[bool]) -> Self {
+        let mut tmp = ExtAwi::zero(NonZeroUsize::new(bits.len()).unwrap_or(bw(1)));
+        for (i, b) in bits.iter().enumerate() {
+            if *b {
+                tmp.set(i, true).unwrap();
+            }
+        }
+        tmp
+    }
+
+    /// Creates a `Vec<bool>` with `bits.bw()` elements in least significant bit
+    /// first order. This is the inverse of [ExtAwi::from_bool_slice] for
+    /// nonempty slices.
+    pub fn bits_to_bool_vec(bits: &Bits) -> Vec<bool> {
+        let mut v = Vec::with_capacity(bits.bw());
+        for i in 0..bits.bw() {
+            v.push(bits.get(i).unwrap());
+        }
+        v
+    }
+
+    /// Creates an `ExtAwi` from `N` little endian `usize` words, with a
+    /// bitwidth of `N * USIZE_BITS`. If `N == 0`, this results in a single
+    /// zero bit. This is the inverse of [Bits::to_usize_array].
+    ///
+    /// ```
+    /// use awint::{awi::*, awint_internals::USIZE_BITS};
+    ///
+    /// let x = ExtAwi::from_usize_array([1, 2]);
+    /// assert_eq!(x.bw(), 2 * USIZE_BITS);
+    /// assert_eq!(x.to_usize_array::<3>(), Some([1, 2, 0]));
+    /// ```
+    pub fn from_usize_array<const N: usize>(words: [usize; N]) -> Self {
+        let w = NonZeroUsize::new(N * USIZE_BITS).unwrap_or(bw(1));
+        let mut tmp = ExtAwi::zero(w);
+        for (i, word) in words.iter().enumerate() {
+            tmp.field_to(i * USIZE_BITS, &InlAwi::from_usize(*word), USIZE_BITS)
+                .unwrap();
+        }
+        tmp
+    }
+
+    /// Creates an `ExtAwi` of bitwidth `w` from the digits yielded by `iter`,
+    /// least significant digit first. Digits beyond `w` are ignored, and
+    /// bits are zeroed if `iter` runs out of digits. This avoids collecting
+    /// the digits into an intermediate buffer. See
+    /// [Bits::fill_from_digit_iter_] for a version that writes to an existing
+    /// value and reports excess digits.
+    pub fn from_digit_iter<I: IntoIterator<Item = Digit>>(iter: I, w: NonZeroUsize) -> Self {
+        let mut tmp = ExtAwi::zero(w);
+        let _ = tmp.fill_from_digit_iter_(iter);
+        tmp
+    }
+
+    /// Creates an `ExtAwi` of bitwidth `w` from `digits` in most significant
+    /// digit first order. This is the inverse of [Bits::to_reversed_digits].
+    /// Returns `None` if `digits.len()` is not the number of digits needed for
+    /// bitwidth `w`, or if bits beyond `w` are set in the first digit.
+    ///
+    /// ```
+    /// use awint::{awi::*, awint_internals::BITS};
+    ///
+    /// let x = ExtAwi::from_reversed_digits(&[1, 0, 3], bw(BITS * 2 + 1)).unwrap();
+    /// assert_eq!(x.to_digit(), 3);
+    /// assert!(x.get(BITS * 2).unwrap());
+    /// assert!(ExtAwi::from_reversed_digits(&[2, 0, 3], bw(BITS * 2 + 1)).is_none());
+    /// assert!(ExtAwi::from_reversed_digits(&[0, 3], bw(BITS * 2 + 1)).is_none());
+    /// ```
+    pub fn from_reversed_digits(digits: &[Digit], w: NonZeroUsize) -> Option<Self> {
+        if digits.len() != total_digits(w).get() {
+            return None
+        }
+        let mut tmp = ExtAwi::zero(w);
+        if tmp.fill_from_digit_iter_(digits.iter().rev().copied()) {
+            return None
+        }
+        Some(tmp)
+    }
+
+    /// Concatenates `parts` into a new `ExtAwi`, with `parts[0]` occupying the
+    /// least significant bits. This is a runtime counterpart to the
+    /// concatenation macros for when the components are not known at compile
+    /// time. `None` is returned if `parts` is empty.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// let lo = inlawi!(0x34u8);
+    /// let hi = inlawi!(0x12u8);
+    /// let x = ExtAwi::concat(&[&lo, &hi]).unwrap();
+    /// assert_eq!(x, extawi!(hi, lo));
+    /// assert_eq!(ExtAwi::split_at(&x, 8), Some((extawi!(lo), extawi!(hi))));
+    /// ```
+    pub fn concat(parts: &[&Bits]) -> Option<Self> {
+        let mut w = 0usize;
+        for part in parts {
+            w = w.checked_add(part.bw())?;
+        }
+        let mut tmp = ExtAwi::zero(NonZeroUsize::new(w)?);
+        let mut to = 0;
+        for part in parts {
+            tmp.field_to(to, part, part.bw()).unwrap();
+            to += part.bw();
+        }
+        Some(tmp)
+    }
+
+    /// Splits `bits` at the bit index `inx`, returning the `inx` least
+    /// significant bits and the `bits.bw() - inx` most significant bits as
+    /// new `ExtAwi`s. `None` is returned if `inx == 0` or `inx >= bits.bw()`,
+    /// because one of the parts would have zero bitwidth.
+    pub fn split_at(bits: &Bits, inx: usize) -> Option<(Self, Self)> {
+        let lo_w = NonZeroUsize::new(inx)?;
+        let hi_w = NonZeroUsize::new(bits.bw().checked_sub(inx)?)?;
+        let mut lo = ExtAwi::zero(lo_w);
+        lo.field_width(bits, inx).unwrap();
+        let mut hi = ExtAwi::zero(hi_w);
+        hi.field_from(bits, inx, hi_w.get()).unwrap();
+        Some((lo, hi))
+    }
+
+    /// Creates an `ExtAwi` of bitwidth `w` with only the bit at `inx` set. This
+    /// is the inverse of [Bits::one_hot_decode]. `None` is returned if
+    /// `inx >= w`.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// let x = ExtAwi::one_hot_encode(5, bw(8)).unwrap();
+    /// assert_eq!(x, extawi!(0b0010_0000_u8));
+    /// assert_eq!(x.one_hot_decode(), Some(5));
+    /// assert_eq!(ExtAwi::one_hot_encode(8, bw(8)), None);
+    /// ```
+    pub fn one_hot_encode(inx: usize, w: NonZeroUsize) -> Option<Self> {
+        if inx >= w.get() {
+            return None
+        }
+        let mut tmp = ExtAwi::zero(w);
+        tmp.set(inx, true).unwrap();
+        Some(tmp)
+    }
+
+    /// Reduces the lookup table `lut` (in the format used by [Bits::lut_])
+    /// given that some of the index bits are known. The index bits set in
+    /// `inx_known_mask` are known to have the corresponding values in
+    /// `inx_known_vals`. The returned lookup table is indexed by only the
+    /// unknown index bits, in order from least to most significant. The
+    /// returned `bool` is `true` if all entries of the reduced table are
+    /// equal, meaning that the output of the lookup is constant.
+    ///
+    /// `None` is returned if `inx_known_mask.bw() != inx_known_vals.bw()` or
+    /// if `lut.bw()` is not a nonzero multiple of `2^inx_known_mask.bw()`.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// // 4 3-bit entries
+    /// let lut = inlawi!(5u3, 1u3, 5u3, 0u3);
+    /// // the most significant index bit is known to be 1
+    /// let inx = inlawi!(0b10_u2);
+    /// let (reduced, constant) = ExtAwi::lut_reduce(&lut, &inx, &inx).unwrap();
+    /// assert_eq!(reduced, extawi!(5u3, 1u3));
+    /// assert!(!constant);
+    /// // the least significant index bit is known to be 1
+    /// let inx = inlawi!(0b01_u2);
+    /// let (reduced, constant) = ExtAwi::lut_reduce(&lut, &inx, &inx).unwrap();
+    /// assert_eq!(reduced, extawi!(5u3, 5u3));
+    /// assert!(constant);
+    /// ```
+    pub fn lut_reduce(
+        lut: &Bits,
+        inx_known_mask: &Bits,
+        inx_known_vals: &Bits,
+    ) -> Option<(Self, bool)> {
+        let inx_w = inx_known_mask.bw();
+        if (inx_w != inx_known_vals.bw()) || (inx_w >= USIZE_BITS) {
+            return None
+        }
+        let entry_w = NonZeroUsize::new(lut.bw() >> inx_w)?;
+        if (entry_w.get() << inx_w) != lut.bw() {
+            return None
+        }
+        let mask = inx_known_mask.to_usize();
+        let vals = inx_known_vals.to_usize() & mask;
+        let unknown_w = inx_w - (mask.count_ones() as usize);
+        let mut res = ExtAwi::zero(NonZeroUsize::new(entry_w.get() << unknown_w).unwrap());
+        let mut first = ExtAwi::zero(entry_w);
+        let mut entry = ExtAwi::zero(entry_w);
+        let mut constant = true;
+        for i in 0..(1usize << unknown_w) {
+            // deposit the bits of `i` into the unknown positions of the index
+            let mut inx = vals;
+            let mut j = 0;
+            for k in 0..inx_w {
+                if (mask & (1 << k)) == 0 {
+                    inx |= ((i >> j) & 1) << k;
+                    j += 1;
+                }
+            }
+            let from = inx * entry_w.get();
+            res.field(i * entry_w.get(), lut, from, entry_w.get())
+                .unwrap();
+            if i == 0 {
+                first.field_from(lut, from, entry_w.get()).unwrap();
+            } else if constant {
+                entry.field_from(lut, from, entry_w.get()).unwrap();
+                constant = entry == first;
+            }
+        }
+        Some((res, constant))
+    }
 }
 
 impl From<bool> for ExtAwi {
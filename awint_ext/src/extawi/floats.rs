@@ -0,0 +1,64 @@
+use core::num::NonZeroUsize;
+
+use awint_core::Bits;
+
+use crate::{
+    float_internals::{bits_to_f32, bits_to_f64, internal_from_f64},
+    ExtAwi, FpRounding,
+};
+
+/// # Floating point conversion
+impl ExtAwi {
+    /// Returns the `f64` nearest to the numerical value of `bits`, interpreted
+    /// as signed if `signed` is set and as unsigned otherwise. This uses
+    /// round-to-nearest-even on the most significant bits, and returns an
+    /// infinity if the value is too large for the range of `f64`.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    /// assert_eq!(ExtAwi::bits_to_f64(&inlawi!(-3i100), true), -3.0);
+    /// // `2^53 + 1` rounds to even
+    /// assert_eq!(
+    ///     ExtAwi::bits_to_f64(&inlawi!(0x20000000000001u100), false),
+    ///     9007199254740992.0
+    /// );
+    /// assert_eq!(ExtAwi::bits_to_f64(&inlawi!(umax: ..1100), false), f64::INFINITY);
+    /// ```
+    pub fn bits_to_f64(bits: &Bits, signed: bool) -> f64 {
+        bits_to_f64(bits, signed)
+    }
+
+    /// The same as [ExtAwi::bits_to_f64] except with an `f32`
+    pub fn bits_to_f32(bits: &Bits, signed: bool) -> f32 {
+        bits_to_f32(bits, signed)
+    }
+
+    /// Creates a signed integer of bitwidth `w` from the numerical value of
+    /// `x`, rounding according to `round`. The mantissa and exponent of `x`
+    /// are decomposed and placed exactly, so this is deterministic across
+    /// platforms. Returns `None` if `x` is an infinity or NaN, or if the
+    /// rounded value does not fit in a signed integer of bitwidth `w`.
+    ///
+    /// ```
+    /// use awint::{awi::*, fp::FpRounding};
+    /// assert_eq!(
+    ///     ExtAwi::from_f64(-2.5, bw(8), FpRounding::NearestEven)
+    ///         .unwrap()
+    ///         .as_ref(),
+    ///     inlawi!(-2i8).as_ref()
+    /// );
+    /// assert_eq!(
+    ///     ExtAwi::from_f64(-2.5, bw(8), FpRounding::Floor)
+    ///         .unwrap()
+    ///         .as_ref(),
+    ///     inlawi!(-3i8).as_ref()
+    /// );
+    /// assert!(ExtAwi::from_f64(128.0, bw(8), FpRounding::Trunc).is_none());
+    /// assert!(ExtAwi::from_f64(f64::NAN, bw(8), FpRounding::Trunc).is_none());
+    /// ```
+    pub fn from_f64(x: f64, w: NonZeroUsize, round: FpRounding) -> Option<ExtAwi> {
+        let mut res = ExtAwi::zero(w);
+        internal_from_f64(&mut res, x, round)?;
+        Some(res)
+    }
+}
@@ -0,0 +1,157 @@
+use awint_core::{Bits, InlAwi};
+
+use crate::{Awi, FpRounding};
+
+/// Rounds the numerical value of `bits` to a mantissa of `man_w < 64` bits
+/// using round-to-nearest-even. Returns the sign, the mantissa, and the left
+/// shift `s` such that the rounded magnitude is `mantissa * 2^s`. If `s` is
+/// nonzero, the mantissa is normalized such that its most significant bit is at
+/// `man_w - 1`.
+fn round_to_mantissa(bits: &Bits, signed: bool, man_w: usize) -> (bool, u64, usize) {
+    // the rounding bit needs to fit above the mantissa
+    debug_assert!(man_w < 64);
+    let neg = signed && bits.msb();
+    let tmp;
+    // note: reinterpret as unsigned so that the signed minimum is handled
+    let mag = if neg {
+        tmp = {
+            let mut tmp = Awi::from_bits(bits);
+            tmp.neg_(true);
+            tmp
+        };
+        tmp.as_ref()
+    } else {
+        bits
+    };
+    let sig = mag.sig();
+    if sig <= man_w {
+        // exactly representable
+        return (neg, mag.to_u64(), 0)
+    }
+    // get the `man_w` most significant bits plus one rounding bit
+    let mut shift = sig - man_w - 1;
+    let mut top = InlAwi::from_u64(0);
+    top.field_from(mag, shift, man_w + 1).unwrap();
+    let top = top.to_u64();
+    let round = (top & 1) != 0;
+    // if any bits below the rounding bit are set
+    let sticky = mag.tz() < shift;
+    let mut man = top >> 1;
+    shift += 1;
+    if round && (sticky || ((man & 1) != 0)) {
+        man += 1;
+        if (man >> man_w) != 0 {
+            // the mantissa overflowed into the next power of two
+            man >>= 1;
+            shift += 1;
+        }
+    }
+    (neg, man, shift)
+}
+
+pub(crate) fn bits_to_f64(bits: &Bits, signed: bool) -> f64 {
+    let (neg, man, shift) = round_to_mantissa(bits, signed, 53);
+    let res = if shift == 0 {
+        // `man < 2^53` so this is exact
+        man as f64
+    } else {
+        let exponent = shift + 52 + 1023;
+        if exponent >= ((1 << 11) - 1) {
+            f64::INFINITY
+        } else {
+            f64::from_bits(((exponent as u64) << 52) | (man & ((1 << 52) - 1)))
+        }
+    };
+    if neg {
+        -res
+    } else {
+        res
+    }
+}
+
+pub(crate) fn bits_to_f32(bits: &Bits, signed: bool) -> f32 {
+    let (neg, man, shift) = round_to_mantissa(bits, signed, 24);
+    let res = if shift == 0 {
+        // `man < 2^24` so this is exact
+        man as f32
+    } else {
+        let exponent = shift + 23 + 127;
+        if exponent >= ((1 << 8) - 1) {
+            f32::INFINITY
+        } else {
+            f32::from_bits(((exponent as u32) << 23) | ((man as u32) & ((1 << 23) - 1)))
+        }
+    };
+    if neg {
+        -res
+    } else {
+        res
+    }
+}
+
+/// Assigns the numerical value of `x` rounded according to `round` to `dst`,
+/// interpreted as a signed integer. Returns `None` if `x` is not finite or the
+/// rounded value cannot fit in `dst`, in which case `dst` is left unchanged.
+pub(crate) fn internal_from_f64(dst: &mut Bits, x: f64, round: FpRounding) -> Option<()> {
+    let raw = x.to_bits();
+    let neg = (raw >> 63) != 0;
+    let exponent = ((raw >> 52) & ((1 << 11) - 1)) as isize;
+    let mantissa = raw & ((1 << 52) - 1);
+    if exponent == ((1 << 11) - 1) {
+        // infinity or NaN
+        return None
+    }
+    // the magnitude is `man * 2^s`
+    let (man, s) = if exponent == 0 {
+        // subnormal or zero
+        (mantissa, -1074isize)
+    } else {
+        (mantissa | (1 << 52), exponent - 1075)
+    };
+    let w = dst.bw();
+    if s >= 0 {
+        // integral, no rounding needed
+        let s = s as usize;
+        let sig = (u64::BITS - man.leading_zeros()) as usize;
+        let fits = if neg {
+            // the magnitude can be exactly `2^(w - 1)`
+            (sig + s < w) || ((sig + s == w) && man.is_power_of_two())
+        } else {
+            sig + s < w
+        };
+        if !fits {
+            return None
+        }
+        dst.u64_(man);
+        dst.shl_(s).unwrap();
+    } else {
+        let r = s.unsigned_abs();
+        // split into integer and fraction parts, `man < 2^53` so capping the shift
+        // preserves the rounding information
+        let r = if r > 64 { 64 } else { r };
+        let man = man as u128;
+        let mut int = (man >> r) as u64;
+        // the fraction bits aligned to the most significant end
+        let frac = (man << (64 - r)) as u64;
+        let half = 1u64 << 63;
+        let inc = match round {
+            FpRounding::Trunc => false,
+            FpRounding::Floor => neg && (frac != 0),
+            FpRounding::Ceil => !neg && (frac != 0),
+            FpRounding::NearestEven => (frac > half) || ((frac == half) && ((int & 1) != 0)),
+        };
+        int += inc as u64;
+        let sig = (u64::BITS - int.leading_zeros()) as usize;
+        let fits = if neg {
+            (sig < w) || ((sig == w) && int.is_power_of_two())
+        } else {
+            sig < w
+        };
+        if !fits {
+            return None
+        }
+        dst.u64_(int);
+    }
+    dst.neg_(neg);
+    Some(())
+}
@@ -48,6 +48,20 @@ impl FPType {
     }
 }
 
+/// Rounding mode used when converting to integers from representations that
+/// can have fractional parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FpRounding {
+    /// Round toward zero
+    Trunc,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceil,
+    /// Round to the nearest integer, with ties going to the even integer
+    NearestEven,
+}
+
 /// Fixed-Point generic struct for `B` that implement `Borrow<Bits>` and
 /// `BorrowMut<Bits>`. Adds on signedness and fixed-point information.
 /// Implements many traits if `B` also implements them.
@@ -1,15 +1,18 @@
 use alloc::{string::String, vec::Vec};
 use core::{
     borrow::BorrowMut,
-    cmp::{max, min},
+    cmp::{max, min, Ordering},
     num::NonZeroUsize,
 };
 
-use awint_core::{awint_internals::Digit, Bits};
+use awint_core::{
+    awint_internals::{Digit, BITS},
+    Bits,
+};
 
 use crate::{
     awint_internals::{bits_upper_bound, SerdeError, SerdeError::*},
-    Awi, FP,
+    Awi, ExtAwi, FPType, FpRounding, FP,
 };
 
 // TODO there are variations of algorithms that can eliminate all the cases
@@ -216,6 +219,286 @@ impl<B: BorrowMut<Bits>> FP<B> {
         Some(())
     }
 
+    /// Add-assigns `rhs` to `this`, aligning the fixed points of the two
+    /// operands. Logically, the operand with fewer fraction bits is shifted up
+    /// to match the other before the exact sum is computed, and then the sum
+    /// is truncated into the type of `this` as by [FP::otruncate_]. Less
+    /// significant numerical bits of the sum that are below the fixed point
+    /// range of `this` are truncated. The signedness of each operand is
+    /// respected. `None` is returned and `this` is left unchanged if the most
+    /// significant numerical bit of the sum would not be representable by
+    /// `this` (including if the sum is negative and `this` is unsigned).
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// // 1.5 with 4 fraction bits
+    /// let mut x = FP::new(true, inlawi!(0b0001_1000_i8), 4).unwrap();
+    /// // -0.25 with 2 fraction bits
+    /// let y = FP::new(true, inlawi!(-1i5), 2).unwrap();
+    /// FP::add_(&mut x, &y).unwrap();
+    /// // 1.25
+    /// assert_eq!(x, FP::new(true, inlawi!(0b0001_0100_i8), 4).unwrap());
+    /// // 7.25 + 1.25 overflows the integer part
+    /// let z = FP::new(false, inlawi!(0b111_01_u5), 2).unwrap();
+    /// assert!(FP::add_(&mut x, &z).is_none());
+    /// assert_eq!(x, FP::new(true, inlawi!(0b0001_0100_i8), 4).unwrap());
+    /// ```
+    #[must_use]
+    pub fn add_<C: BorrowMut<Bits>>(this: &mut Self, rhs: &FP<C>) -> Option<()> {
+        // the sum needs the most fraction bits of either operand, the most integer bits
+        // of either operand, one bit for the carry, and one bit in case the operands
+        // have mixed signedness
+        let fp = max(this.fp(), rhs.fp());
+        let int = max(
+            this.ibw().wrapping_sub(this.fp()),
+            rhs.ibw().wrapping_sub(rhs.fp()),
+        )
+        .wrapping_add(2);
+        let w = NonZeroUsize::new(itousize(int.wrapping_add(fp))?)?;
+        let mut lhs_tmp = FP::new(true, Awi::zero(w), fp)?;
+        let mut rhs_tmp = FP::new(true, Awi::zero(w), fp)?;
+        // these do not truncate because of the widths chosen above
+        FP::truncate_(&mut lhs_tmp, this);
+        FP::truncate_(
+            &mut rhs_tmp,
+            &mut FP::new(rhs.signed(), Awi::from_bits(rhs), rhs.fp())?,
+        );
+        lhs_tmp.add_(&rhs_tmp).unwrap();
+        let mut res = FP::new(this.signed(), Awi::zero(this.nzbw()), this.fp())?;
+        if FP::otruncate_(&mut res, &mut lhs_tmp).1 {
+            return None
+        }
+        this.copy_(&res).unwrap();
+        Some(())
+    }
+
+    /// Multiplies `lhs` by `rhs`, returning the exact product. Because
+    /// `(a * 2^-n) * (b * 2^-m) = (a * b) * 2^-(n + m)`, the product has a
+    /// fixed point of `lhs.fp() + rhs.fp()` and a bitwidth of `lhs.bw() +
+    /// rhs.bw()`, which is always enough to avoid overflow. The product is
+    /// signed if either operand is signed. `None` is returned if the product
+    /// would violate the `FP` invariants.
+    ///
+    /// ```
+    /// use awint::awi::*;
+    ///
+    /// // 1.5 * -2.25 = -3.375
+    /// let x = FP::new(false, inlawi!(0b1_1_u2), 1).unwrap();
+    /// let y = FP::new(true, inlawi!(-9i5), 2).unwrap();
+    /// let z = FP::mul(&x, &y).unwrap();
+    /// assert_eq!(z.fp(), 3);
+    /// assert_eq!(z.bw(), 7);
+    /// assert_eq!(z, FP::new(true, ExtAwi::from(inlawi!(-27i7)), 3).unwrap());
+    /// ```
+    pub fn mul<C: BorrowMut<Bits>>(lhs: &Self, rhs: &FP<C>) -> Option<FP<ExtAwi>> {
+        let w = NonZeroUsize::new(lhs.bw().checked_add(rhs.bw())?)?;
+        let fp = lhs.fp().checked_add(rhs.fp())?;
+        let mut res = FP::new(lhs.signed() || rhs.signed(), ExtAwi::zero(w), fp)?;
+        // multiply the magnitudes, which cannot overflow the width of `res`
+        let mut l = Awi::from_bits(lhs);
+        let lhs_neg = lhs.is_negative();
+        l.neg_(lhs_neg);
+        let mut r = Awi::from_bits(rhs);
+        let rhs_neg = rhs.is_negative();
+        r.neg_(rhs_neg);
+        res.arb_umul_add_(&l, &r);
+        res.neg_(lhs_neg != rhs_neg);
+        Some(res)
+    }
+
+    /// Multiply-rescale-assigns `this`, setting it to the product of `lhs` and
+    /// `rhs` rescaled to the fixed point type of `this`. Fraction bits of the
+    /// exact product (see [FP::mul]) that are below the fixed point range of
+    /// `this` are rounded according to `rounding`. `None` is returned and
+    /// `this` is left unchanged if the rounded product is not representable
+    /// by `this`.
+    ///
+    /// ```
+    /// use awint::{awi::*, fp::FpRounding};
+    ///
+    /// // 1.5 * -2.25 = -3.375, rescaled to 1 fraction bit
+    /// let x = FP::new(false, inlawi!(0b1_1_u2), 1).unwrap();
+    /// let y = FP::new(true, inlawi!(-9i5), 2).unwrap();
+    /// let mut z = FP::new(true, inlawi!(0i8), 1).unwrap();
+    /// FP::mul_rescale_(&mut z, &x, &y, FpRounding::NearestEven).unwrap();
+    /// // -3.5
+    /// assert_eq!(z, FP::new(true, inlawi!(-7i8), 1).unwrap());
+    /// FP::mul_rescale_(&mut z, &x, &y, FpRounding::Trunc).unwrap();
+    /// // -3.0
+    /// assert_eq!(z, FP::new(true, inlawi!(-6i8), 1).unwrap());
+    /// // unsigned `this` cannot hold a negative product
+    /// let mut z = FP::new(false, inlawi!(0u8), 1).unwrap();
+    /// assert!(FP::mul_rescale_(&mut z, &x, &y, FpRounding::Trunc).is_none());
+    /// ```
+    #[must_use]
+    pub fn mul_rescale_<C: BorrowMut<Bits>, D: BorrowMut<Bits>>(
+        this: &mut Self,
+        lhs: &FP<C>,
+        rhs: &FP<D>,
+        rounding: FpRounding,
+    ) -> Option<()> {
+        let mut prod = FP::mul(lhs, rhs)?;
+        let mut res = FP::new(this.signed(), Awi::zero(this.nzbw()), this.fp())?;
+        // the number of fraction bits that need to be rounded away
+        let d = prod.fp().wrapping_sub(this.fp());
+        if d <= 0 {
+            // no rounding is needed
+            if FP::otruncate_(&mut res, &mut prod).1 {
+                return None
+            }
+        } else {
+            let d = d as usize;
+            let neg = prod.is_negative();
+            let w = prod.bw();
+            // work with the magnitude, with an extra bit for the rounding increment
+            let mut tmp = Awi::from_bits(&prod);
+            tmp.neg_(neg);
+            let mut mag = Awi::zero(NonZeroUsize::new(w.checked_add(1)?)?);
+            mag.zero_resize_(&tmp);
+            let nonzero = !mag.is_zero();
+            let tz = mag.tz();
+            let inexact = nonzero && (tz < d);
+            let half = (d <= w) && mag.get(d - 1).unwrap();
+            let above_half = nonzero && (tz < (d - 1));
+            if d < mag.bw() {
+                mag.lshr_(d).unwrap();
+            } else {
+                mag.zero_();
+            }
+            let inc = match rounding {
+                FpRounding::Trunc => false,
+                FpRounding::Floor => neg && inexact,
+                FpRounding::Ceil => !neg && inexact,
+                FpRounding::NearestEven => half && (above_half || mag.lsb()),
+            };
+            mag.inc_(inc);
+            // apply the sign and truncate, which only checks for overflow because the fixed
+            // points are equal
+            let mut signed = FP::new(
+                true,
+                Awi::zero(NonZeroUsize::new(mag.bw().checked_add(1)?)?),
+                this.fp(),
+            )?;
+            signed.zero_resize_(&mag);
+            signed.neg_(neg);
+            if FP::otruncate_(&mut res, &mut signed).1 {
+                return None
+            }
+        }
+        this.copy_(&res).unwrap();
+        Some(())
+    }
+
+    /// Compares the numerical values of `lhs` and `rhs`, which can have
+    /// different signedness, bitwidths, and fixed points. This does not
+    /// allocate and is suitable for sort comparators. Unlike the
+    /// `PartialEq` implementation, this does not compare the signedness and
+    /// fixed points themselves.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    ///
+    /// use awint::awi::*;
+    ///
+    /// // 1.5 with 4 fraction bits
+    /// let x = FP::new(true, inlawi!(0b0001_1000_i8), 4).unwrap();
+    /// // 1.5 with 1 fraction bit
+    /// let y = FP::new(false, inlawi!(0b1_1_u2), 1).unwrap();
+    /// // -0.25 with 2 fraction bits
+    /// let z = FP::new(true, inlawi!(-1i5), 2).unwrap();
+    /// assert_eq!(FP::cmp(&x, &y), Ordering::Equal);
+    /// assert!(FP::eq(&x, &y));
+    /// assert!(FP::lt(&z, &y));
+    /// assert_eq!(FP::cmp(&x, &z), Ordering::Greater);
+    /// ```
+    // these cannot be trait implementations because `rhs` can be a different type
+    #[allow(clippy::should_implement_trait)]
+    pub fn cmp<C: BorrowMut<Bits>>(lhs: &Self, rhs: &FP<C>) -> Ordering {
+        let lhs_neg = lhs.is_negative();
+        let rhs_neg = rhs.is_negative();
+        if lhs_neg != rhs_neg {
+            return if lhs_neg {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }
+        // Both operands are sign extended with the same bit `neg`. Positions are
+        // numerical (the bit at index `i` is at position `i - fp`) and calculated as
+        // `i128`s so that no combination of fixed points and bitwidths can overflow.
+        let neg = lhs_neg;
+        let lhs_fp = lhs.fp() as i128;
+        let rhs_fp = rhs.fp() as i128;
+        // one past the position of the most significant bit that differs from `neg`.
+        // For negative values that are all ones, this is the position of the implicit
+        // zero just below the least significant bit.
+        let lhs_top = ((lhs.bw() - lhs.leading_run(neg)) as i128) - lhs_fp;
+        let rhs_top = ((rhs.bw() - rhs.leading_run(neg)) as i128) - rhs_fp;
+        if !neg {
+            // nonnegative zeros have no bits that differ from `neg` at all
+            match (lhs.is_zero(), rhs.is_zero()) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                (false, false) => (),
+            }
+        }
+        if lhs_top != rhs_top {
+            // the operand with the higher differing bit is further from zero
+            return if (lhs_top > rhs_top) != neg {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            }
+        }
+        // the bits are now the same above `lhs_top`, and the same sign means that the
+        // remaining bits can be compared as unsigned. Compare the window where both
+        // operands have bits, starting from the most significant end.
+        let low = max(-lhs_fp, -rhs_fp);
+        let mut hi = lhs_top;
+        while hi > low {
+            let lo = max(hi - (BITS as i128), low);
+            let width = (hi - lo) as usize;
+            let mask = if width == BITS {
+                Digit::MAX
+            } else {
+                (1 << width) - 1
+            };
+            let lhs_digit = lhs.get_digit((lo + lhs_fp) as usize) & mask;
+            let rhs_digit = rhs.get_digit((lo + rhs_fp) as usize) & mask;
+            match lhs_digit.cmp(&rhs_digit) {
+                Ordering::Equal => (),
+                ordering => return ordering,
+            }
+            hi = lo;
+        }
+        // the coarser operand has implicit zeros below `low`, so the finer operand is
+        // greater if it has any set bits there
+        if lhs_fp > rhs_fp {
+            if lhs.tz() < ((low + lhs_fp) as usize) {
+                return Ordering::Greater
+            }
+        } else if rhs_fp > lhs_fp && rhs.tz() < ((low + rhs_fp) as usize) {
+            return Ordering::Less
+        }
+        Ordering::Equal
+    }
+
+    /// Returns if the numerical values of `lhs` and `rhs` are equal. See
+    /// [FP::cmp].
+    // these cannot be trait implementations because `rhs` can be a different type
+    #[allow(clippy::should_implement_trait)]
+    pub fn eq<C: BorrowMut<Bits>>(lhs: &Self, rhs: &FP<C>) -> bool {
+        FP::cmp(lhs, rhs) == Ordering::Equal
+    }
+
+    /// Returns if the numerical value of `lhs` is less than that of `rhs`. See
+    /// [FP::cmp].
+    pub fn lt<C: BorrowMut<Bits>>(lhs: &Self, rhs: &FP<C>) -> bool {
+        FP::cmp(lhs, rhs) == Ordering::Less
+    }
+
     /// Creates a tuple of `Vec<u8>`s representing the integer and fraction
     /// parts `this` (sign indicators, prefixes, points, and postfixes not
     /// included). This function performs allocation. This is the inverse of
@@ -385,3 +668,102 @@ impl<B: BorrowMut<Bits>> FP<B> {
         Ok((String::from_utf8(i).unwrap(), String::from_utf8(f).unwrap()))
     }
 }
+
+impl FP<ExtAwi> {
+    /// Returns the fixed point approximation of `num / den` with the
+    /// signedness, bitwidth, and fixed point of `fp_type`. `num` and `den` are
+    /// interpreted as signed if `fp_type.signed` and unsigned otherwise, and
+    /// may have any bitwidths. The quotient is computed exactly before being
+    /// rounded to `fp_type.fp` fraction bits according to `rounding`. `None`
+    /// is returned if `den` is zero, if the rounded quotient is not
+    /// representable by `fp_type`, or if `fp_type` would violate the `FP`
+    /// invariants.
+    ///
+    /// ```
+    /// use awint::{awi::*, fp::FpRounding};
+    ///
+    /// let num = inlawi!(3u8);
+    /// let den = inlawi!(7u8);
+    /// let ty = FPType {
+    ///     signed: false,
+    ///     bw: bw(8),
+    ///     fp: 6,
+    /// };
+    /// // 3/7 = 0.428571..., which is 27.428571... ULPs at 6 fraction bits
+    /// let x = FP::from_ratio(&num, &den, ty, FpRounding::NearestEven).unwrap();
+    /// assert_eq!(x, FP::new(false, ExtAwi::from(inlawi!(27u8)), 6).unwrap());
+    /// let x = FP::from_ratio(&num, &den, ty, FpRounding::Ceil).unwrap();
+    /// assert_eq!(x, FP::new(false, ExtAwi::from(inlawi!(28u8)), 6).unwrap());
+    /// // division by zero
+    /// let zero = inlawi!(0u8);
+    /// assert!(FP::from_ratio(&num, &zero, ty, FpRounding::Trunc).is_none());
+    /// ```
+    #[must_use]
+    pub fn from_ratio(
+        num: &Bits,
+        den: &Bits,
+        fp_type: FPType,
+        rounding: FpRounding,
+    ) -> Option<FP<ExtAwi>> {
+        if den.is_zero() {
+            return None
+        }
+        let mut res = FP::new(fp_type.signed, ExtAwi::zero(fp_type.bw), fp_type.fp)?;
+        let num_neg = fp_type.signed && num.msb();
+        let den_neg = fp_type.signed && den.msb();
+        let neg = num_neg != den_neg;
+        // `(num / den) * 2^fp` is computed as `(num << fp) / den` if `fp` is
+        // positive and as `num / (den << -fp)` otherwise. The extra bit allows the
+        // remainder to be doubled for rounding.
+        let num_shl = itousize(max(fp_type.fp, 0))?;
+        let den_shl = itousize(max(fp_type.fp.wrapping_neg(), 0))?;
+        let w = max(
+            num.bw().checked_add(num_shl)?,
+            den.bw().checked_add(den_shl)?,
+        )
+        .checked_add(1)?;
+        let w = NonZeroUsize::new(w)?;
+        // the magnitudes are taken before resizing so that the most negative values
+        // work
+        let mut tmp = Awi::from_bits(num);
+        tmp.neg_(num_neg);
+        let mut duo = Awi::zero(w);
+        duo.zero_resize_(&tmp);
+        duo.shl_(num_shl).unwrap();
+        let mut tmp = Awi::from_bits(den);
+        tmp.neg_(den_neg);
+        let mut div = Awi::zero(w);
+        div.zero_resize_(&tmp);
+        div.shl_(den_shl).unwrap();
+        let mut quo = Awi::zero(w);
+        let mut rem = Awi::zero(w);
+        Bits::udivide(&mut quo, &mut rem, &duo, &div).unwrap();
+        let inexact = !rem.is_zero();
+        rem.shl_(1).unwrap();
+        let inc = match rounding {
+            FpRounding::Trunc => false,
+            FpRounding::Floor => neg && inexact,
+            FpRounding::Ceil => !neg && inexact,
+            FpRounding::NearestEven => {
+                let above_half = div.ult(&rem).unwrap();
+                let tie = rem == div;
+                above_half || (tie && quo.lsb())
+            }
+        };
+        // cannot overflow because `quo` is at most `duo` which has its msb unset
+        quo.inc_(inc);
+        // apply the sign and truncate, which only checks for overflow because the fixed
+        // points are equal
+        let mut signed = FP::new(
+            true,
+            Awi::zero(NonZeroUsize::new(w.get().checked_add(1)?)?),
+            fp_type.fp,
+        )?;
+        signed.zero_resize_(&quo);
+        signed.neg_(neg);
+        if FP::otruncate_(&mut res, &mut signed).1 {
+            return None
+        }
+        Some(res)
+    }
+}
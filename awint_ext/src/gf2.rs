@@ -0,0 +1,43 @@
+//! Linear algebra over GF(2)
+
+use awint_core::Bits;
+
+/// Computes the matrix-vector product `out = M * x` over GF(2), where row `i`
+/// of the matrix `M` is `rows[i]`. Bit `i` of `out` is set to
+/// `rows[i].gf2_dot(x)`, the parity of `rows[i] & x` (see [Bits::gf2_dot]).
+/// This is the encoding step of a linear block code when the rows are the
+/// columns of a generator matrix, or the syndrome computation when the rows
+/// are the rows of a parity check matrix.
+///
+/// `None` is returned if any row has a different bitwidth than `x` or if
+/// `out.bw() != rows.len()`, in which case `out` is left unchanged.
+///
+/// ```
+/// use awint::{awi::*, gf2::gf2_matvec};
+///
+/// // the parity check matrix of the Hamming(7, 4) code
+/// let h = [
+///     inlawi!(0b1010101u7),
+///     inlawi!(0b1100110u7),
+///     inlawi!(0b1111000u7),
+/// ];
+/// let h: Vec<&Bits> = h.iter().map(|row| row.as_ref()).collect();
+/// let mut syndrome = inlawi!(0u3);
+/// // a valid codeword has a zero syndrome
+/// let mut codeword = inlawi!(0b1010101u7);
+/// gf2_matvec(&h, &codeword, &mut syndrome).unwrap();
+/// assert!(syndrome.is_zero());
+/// // the syndrome of a single bit error is the one-based position of the error
+/// codeword.xor_(&inlawi!(0b0010000u7)).unwrap();
+/// gf2_matvec(&h, &codeword, &mut syndrome).unwrap();
+/// assert_eq!(syndrome, inlawi!(5u3));
+/// ```
+pub fn gf2_matvec(rows: &[&Bits], x: &Bits, out: &mut Bits) -> Option<()> {
+    if (out.bw() != rows.len()) || rows.iter().any(|row| row.bw() != x.bw()) {
+        return None
+    }
+    for (i, row) in rows.iter().enumerate() {
+        out.set(i, row.gf2_dot(x).unwrap()).unwrap();
+    }
+    Some(())
+}
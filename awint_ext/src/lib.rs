@@ -26,16 +26,31 @@ extern crate alloc;
 pub use awint_core;
 #[doc(hidden)]
 pub use awint_core::awint_internals;
+#[cfg(target_has_atomic = "ptr")]
+mod arc_bits;
 mod awi_struct;
+pub mod bit_permutation;
+#[cfg(feature = "borsh_support")]
+mod borsh;
+pub mod crc;
+pub mod crt;
 mod extawi;
+pub(crate) mod float_internals;
 mod fp_struct;
+pub mod gf2;
+#[cfg(feature = "rkyv_support")]
+mod rkyv;
 #[cfg(feature = "serde_support")]
 mod serde;
 pub(crate) mod string_internals;
+#[cfg(target_has_atomic = "ptr")]
+pub use arc_bits::ArcBits;
 pub use awi_struct::Awi;
-pub use awint_core::{bw, Bits, InlAwi, OrdBits, SerdeError};
+pub use awint_core::{bw, Bits, InlAwi, OrdBits, Runs, SerdeError};
 pub use extawi::ExtAwi;
-pub use fp_struct::{FPType, FP};
+pub use fp_struct::{FPType, FpRounding, FP};
+#[cfg(feature = "rkyv_support")]
+pub use rkyv::{ArchivedExtAwi, ArchivedExtAwiError, ExtAwiResolver};
 
 /// Subset of `awint::awi`
 pub mod awi {
@@ -49,5 +64,5 @@ pub mod awi {
 /// Fixed point related items
 pub mod fp {
     pub use super::fp_struct::{F32, F64};
-    pub use crate::{FPType, FP};
+    pub use crate::{FPType, FpRounding, FP};
 }
@@ -0,0 +1,221 @@
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::{fmt, mem, num::NonZeroUsize, ptr, ptr::NonNull, slice};
+
+use awint_core::{
+    awint_internals::{total_digits, Digit, RawBits},
+    Bits, SerdeError,
+};
+use rkyv::{
+    bytecheck::{self, CheckBytes},
+    out_field,
+    ser::{ScratchSpace, Serializer},
+    validation::ArchiveContext,
+    vec::{ArchivedVec, VecResolver},
+    Archive, Archived, Deserialize, Fallible, Serialize,
+};
+
+use crate::ExtAwi;
+
+// `rkyv` does not archive `usize` as itself, so we need the fixed size integer
+// with the same layout as `Digit`
+#[cfg(not(any(
+    feature = "u8_digits",
+    feature = "u16_digits",
+    feature = "u32_digits",
+    feature = "u64_digits",
+    feature = "u128_digits",
+    target_arch = "avr",
+)))]
+#[cfg(target_pointer_width = "16")]
+type FixedDigit = u16;
+#[cfg(not(any(
+    feature = "u8_digits",
+    feature = "u16_digits",
+    feature = "u32_digits",
+    feature = "u64_digits",
+    feature = "u128_digits",
+    target_arch = "avr",
+)))]
+#[cfg(target_pointer_width = "32")]
+type FixedDigit = u32;
+#[cfg(not(any(
+    feature = "u8_digits",
+    feature = "u16_digits",
+    feature = "u32_digits",
+    feature = "u64_digits",
+    feature = "u128_digits",
+    target_arch = "avr",
+)))]
+#[cfg(target_pointer_width = "64")]
+type FixedDigit = u64;
+#[cfg(any(feature = "u8_digits", target_arch = "avr"))]
+type FixedDigit = u8;
+#[cfg(feature = "u16_digits")]
+type FixedDigit = u16;
+#[cfg(feature = "u32_digits")]
+type FixedDigit = u32;
+#[cfg(feature = "u64_digits")]
+type FixedDigit = u64;
+#[cfg(feature = "u128_digits")]
+type FixedDigit = u128;
+
+const _: () = assert!(
+    (mem::size_of::<FixedDigit>() == mem::size_of::<Digit>())
+        && (mem::align_of::<FixedDigit>() == mem::align_of::<Digit>())
+);
+
+fn fixed_digits(bits: &Bits) -> &[FixedDigit] {
+    let digits = bits.as_slice();
+    // Safety: `FixedDigit` has the same size and alignment as `Digit`, and both
+    // are plain integers
+    unsafe { slice::from_raw_parts(digits.as_ptr() as *const FixedDigit, digits.len()) }
+}
+
+/// The archived form of an [ExtAwi], available with the "rkyv_support"
+/// feature. This stores the bitwidth and then a relative pointer to the raw
+/// `Digit`s, which are aligned to `Digit` so that the archive can be viewed
+/// directly as a `&Bits` with [ArchivedExtAwi::as_bits].
+///
+/// Note that the layout depends on the size of `Digit` and the endianness of
+/// the platform (see the portability notes in `bits.rs`), so archives should
+/// only be accessed on the same kind of platform they were created on.
+/// Validation through `rkyv::check_archived_root` will catch any mismatch in
+/// the number of digits.
+#[repr(C)]
+pub struct ArchivedExtAwi {
+    bw: Archived<u64>,
+    digits: ArchivedVec<FixedDigit>,
+}
+
+/// The resolver for [ArchivedExtAwi]
+pub struct ExtAwiResolver {
+    digits: VecResolver,
+}
+
+impl ArchivedExtAwi {
+    /// Returns the bitwidth of the archived integer
+    pub fn nzbw(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.bw as usize).unwrap()
+    }
+
+    /// Returns the bitwidth of the archived integer as a `usize`
+    pub fn bw(&self) -> usize {
+        self.nzbw().get()
+    }
+
+    /// Borrows the archived digits as a `&Bits` without any copying
+    pub fn as_bits(&self) -> &Bits {
+        let w = self.nzbw();
+        let digits: &[FixedDigit] = self.digits.as_slice();
+        assert_eq!(digits.len(), total_digits(w).get());
+        // Safety: the length of the slice was checked above, `FixedDigit` has the same
+        // layout as `Digit`, `ArchivedVec` aligns its data, and the lifetime is bound
+        // to `self`
+        unsafe {
+            Bits::from_raw_parts(RawBits::from_raw_parts(
+                NonNull::new_unchecked(digits.as_ptr() as *mut Digit),
+                w,
+            ))
+        }
+    }
+}
+
+/// A `rkyv_support` impl
+impl Archive for ExtAwi {
+    type Archived = ArchivedExtAwi;
+    type Resolver = ExtAwiResolver;
+
+    #[inline]
+    unsafe fn resolve(&self, pos: usize, resolver: Self::Resolver, out: *mut Self::Archived) {
+        let (fp, fo) = out_field!(out.bw);
+        // Safety: `fo` is the out pointer of the `bw` field at offset `fp`
+        unsafe { (self.bw() as u64).resolve(pos + fp, (), fo) };
+        let (fp, fo) = out_field!(out.digits);
+        // Safety: `fo` is the out pointer of the `digits` field at offset `fp`, and
+        // `resolver.digits` was created from the same slice in `serialize`
+        unsafe {
+            ArchivedVec::resolve_from_slice(fixed_digits(self), pos + fp, resolver.digits, fo)
+        };
+    }
+}
+
+/// A `rkyv_support` impl
+impl<S: ScratchSpace + Serializer + ?Sized> Serialize<S> for ExtAwi {
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(ExtAwiResolver {
+            digits: ArchivedVec::serialize_from_slice(fixed_digits(self), serializer)?,
+        })
+    }
+}
+
+/// A `rkyv_support` impl
+impl<D: Fallible + ?Sized> Deserialize<ExtAwi, D> for ArchivedExtAwi {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<ExtAwi, D::Error> {
+        Ok(ExtAwi::from_bits(self.as_bits()))
+    }
+}
+
+/// An error from validating an [ArchivedExtAwi]
+#[derive(Debug)]
+pub enum ArchivedExtAwiError<E> {
+    /// The relative pointer or digit slice failed to validate
+    Digits(E),
+    /// The bitwidth is zero ([SerdeError::ZeroBitwidth]), the number of digits
+    /// does not correspond to the bitwidth ([SerdeError::NonEqualWidths]), or
+    /// some unused bits are set ([SerdeError::Overflow])
+    Invalid(SerdeError),
+}
+
+impl<E: fmt::Display> fmt::Display for ArchivedExtAwiError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchivedExtAwiError::Digits(e) => write!(f, "invalid `ArchivedExtAwi` digits: {e}"),
+            ArchivedExtAwiError::Invalid(e) => write!(f, "invalid `ArchivedExtAwi`: {e}"),
+        }
+    }
+}
+
+// under `rkyv/std`, `bytecheck` requires this
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ArchivedExtAwiError<E> {}
+
+/// A `rkyv_support` impl
+impl<C: ArchiveContext + ?Sized> CheckBytes<C> for ArchivedExtAwi
+where
+    C::Error: bytecheck::Error,
+{
+    type Error = ArchivedExtAwiError<<ArchivedVec<FixedDigit> as CheckBytes<C>>::Error>;
+
+    unsafe fn check_bytes<'a>(
+        value: *const Self,
+        context: &mut C,
+    ) -> Result<&'a Self, Self::Error> {
+        // Safety: `value` is the pointer to a `Self` given by the caller, which is
+        // within the bounds of the archive
+        let digits = unsafe {
+            ArchivedVec::<FixedDigit>::check_bytes(ptr::addr_of!((*value).digits), context)
+                .map_err(ArchivedExtAwiError::Digits)?
+        };
+        // Safety: the `bw` field is a plain integer which is valid for any bit
+        // pattern, and the field was bounds checked as part of `value`
+        let bw = unsafe { ptr::addr_of!((*value).bw).read() };
+        let w = match usize::try_from(bw).ok().and_then(NonZeroUsize::new) {
+            Some(w) => w,
+            None => return Err(ArchivedExtAwiError::Invalid(SerdeError::ZeroBitwidth)),
+        };
+        let digits = digits.as_slice();
+        if digits.len() != total_digits(w).get() {
+            return Err(ArchivedExtAwiError::Invalid(SerdeError::NonEqualWidths))
+        }
+        let extra = w.get() % (FixedDigit::BITS as usize);
+        if (extra != 0) && ((digits[digits.len() - 1] >> extra) != 0) {
+            return Err(ArchivedExtAwiError::Invalid(SerdeError::Overflow))
+        }
+        // Safety: all fields were checked
+        Ok(unsafe { &*value })
+    }
+}
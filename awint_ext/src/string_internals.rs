@@ -79,6 +79,32 @@ pub(crate) fn bits_to_string_radix(
     unsafe { Ok(String::from_utf8_unchecked(v)) }
 }
 
+pub(crate) fn bits_to_string_grouped(
+    bits: &Bits,
+    signed: bool,
+    radix: u8,
+    upper: bool,
+    group: usize,
+    sep: char,
+) -> Result<String, SerdeError> {
+    let v = bits_to_vec_radix(bits, signed, radix, upper, 1)?;
+    let len = v.len();
+    let num_seps = (len - 1).checked_div(group).unwrap_or(0);
+    let mut s = String::with_capacity(1 + len + (num_seps * sep.len_utf8()));
+    if signed && bits.msb() {
+        s.push('-');
+    }
+    for (i, c) in v.into_iter().enumerate() {
+        // the number of digits remaining after this one
+        let rem = len - 1 - i;
+        s.push(char::from(c));
+        if (group != 0) && (rem != 0) && ((rem / group) * group == rem) {
+            s.push(sep);
+        }
+    }
+    Ok(s)
+}
+
 pub(crate) fn internal_from_bytes_radix(
     bits: &mut Bits,
     sign: Option<bool>,
@@ -555,3 +581,169 @@ pub(crate) fn internal_from_str<O: DerefMut<Target = Bits>, F: FnMut(NonZeroUsiz
         Err(EmptyBitwidth)
     }
 }
+
+/// The number of bytes needed to hold `w` bits
+pub(crate) const fn bytes_len(w: usize) -> usize {
+    (w >> 3) + ((w & 0b111) != 0) as usize
+}
+
+/// Creates the portable byte representation of `bits`, which is the bitwidth
+/// as a little-endian `u64` followed by the `ceil(bw / 8)` little-endian bytes
+/// of the value
+pub(crate) fn bits_to_portable_bytes(bits: &Bits) -> Vec<u8> {
+    let mut res = alloc::vec![0u8; 8 + bytes_len(bits.bw())];
+    res[..8].copy_from_slice(&(bits.bw() as u64).to_le_bytes());
+    bits.to_u8_slice(&mut res[8..]);
+    res
+}
+
+/// Returns the bitwidth of a portable byte representation. This also checks
+/// that the length of `src` agrees with the bitwidth, so that a forged header
+/// cannot cause a huge allocation.
+pub(crate) fn portable_bytes_bw(src: &[u8]) -> Result<NonZeroUsize, SerdeError> {
+    if src.len() < 8 {
+        return Err(Empty)
+    }
+    let mut tmp = [0u8; 8];
+    tmp.copy_from_slice(&src[..8]);
+    let w = usize::try_from(u64::from_le_bytes(tmp)).map_err(|_| Overflow)?;
+    let w = NonZeroUsize::new(w).ok_or(ZeroBitwidth)?;
+    if (src.len() - 8) != bytes_len(w.get()) {
+        return Err(NonEqualWidths)
+    }
+    Ok(w)
+}
+
+/// Assigns the value of a portable byte representation to `bits`, which
+/// should already have the bitwidth returned by `portable_bytes_bw`
+pub(crate) fn internal_from_portable_bytes(bits: &mut Bits, src: &[u8]) -> Result<(), SerdeError> {
+    let src = &src[8..];
+    if src.len() != bytes_len(bits.bw()) {
+        return Err(NonEqualWidths)
+    }
+    let extra = bits.bw() & 0b111;
+    if (extra != 0) && ((src[src.len() - 1] >> extra) != 0) {
+        // bits beyond the bitwidth are set
+        return Err(Overflow)
+    }
+    bits.u8_slice_(src);
+    Ok(())
+}
+
+/// Returns the bitwidth of the packed BCD representation used for `bits`,
+/// which is 4 times the number of decimal digits in the maximum unsigned value
+/// of `bits`
+pub(crate) fn bcd_bw(bits: &Bits) -> NonZeroUsize {
+    // `floor(bw * log10(2)) + 1`, where `log10(2)` is a 0.64 fixed point number
+    // rounded up so that the number of digits can never be underestimated
+    const LOG10_2: u128 = 0x4d10_4d42_7de7_fbcd;
+    let digits = (((bits.bw() as u128) * LOG10_2) >> 64) as usize + 1;
+    NonZeroUsize::new(digits.checked_mul(4).unwrap()).unwrap()
+}
+
+/// Assigns the packed BCD representation of the unsigned value of `bits` to
+/// `bcd`, which should already have the bitwidth returned by `bcd_bw`. This
+/// uses the double dabble algorithm.
+pub(crate) fn internal_bits_to_bcd(bcd: &mut Bits, bits: &Bits) {
+    // `BITS` is always a multiple of 4, so nibbles never straddle digits
+    let threes = MAX / 15 * 3;
+    let eights = MAX / 15 * 8;
+    bcd.zero_();
+    for i in (0..bits.bw()).rev() {
+        for x in bcd.as_mut_slice() {
+            // nibbles that are 5 or more get 3 added to them, which never carries
+            // out of the nibble because all nibbles are at most 9
+            let c = (x.wrapping_add(threes) & eights) >> 3;
+            *x = x.wrapping_add((c << 1) | c);
+        }
+        bcd.shl_(1).unwrap();
+        bcd.set(0, bits.get(i).unwrap()).unwrap();
+    }
+}
+
+/// Returns the bitwidth needed by `internal_from_bcd` for `bcd`, which is the
+/// number of bits in `10^d - 1` where `d` is the number of nibbles in `bcd`
+pub(crate) fn from_bcd_bw(bcd: &Bits) -> NonZeroUsize {
+    // `floor(d * log2(10)) + 1`, where `log2(10)` is a 2.64 fixed point number
+    // rounded up so that the number of bits can never be underestimated
+    const LOG2_10: u128 = 0x3_5269_e12f_346e_2bfa;
+    let digits = (bcd.bw() / 4) + ((bcd.bw() & 0b11) != 0) as usize;
+    NonZeroUsize::new((((digits as u128) * LOG2_10) >> 64) as usize + 1).unwrap()
+}
+
+/// Assigns the value of the packed BCD representation `bcd` to `bits`, which
+/// should already have the bitwidth returned by `from_bcd_bw`. This uses the
+/// reverse double dabble algorithm. Returns `None` if a nibble of `bcd` is not
+/// in the range 0..=9.
+pub(crate) fn internal_from_bcd(bits: &mut Bits, bcd: &Bits) -> Option<()> {
+    let eights = MAX / 15 * 8;
+    let mut tmp = Awi::from_bits(bcd);
+    for x in tmp.as_slice() {
+        // a nibble is 10 or more iff its most significant bit is set along with
+        // one of the two bits below it
+        if (x & eights & ((x << 1) | (x << 2))) != 0 {
+            return None
+        }
+    }
+    bits.zero_();
+    let w = bits.bw();
+    for _ in 0..w {
+        bits.lshr_(1).unwrap();
+        bits.set(w - 1, tmp.lsb()).unwrap();
+        tmp.lshr_(1).unwrap();
+        for x in tmp.as_mut_slice() {
+            // nibbles that are 8 or more get 3 subtracted from them, which never
+            // borrows from the next nibble
+            let c = (*x & eights) >> 3;
+            *x = x.wrapping_sub((c << 1) | c);
+        }
+    }
+    Some(())
+}
+
+/// Clears `out` and pushes the little endian base `radix` digits of the
+/// unsigned value of `bits` to it. Returns `None` if `radix < 2`.
+pub(crate) fn bits_to_radix_digits(bits: &Bits, radix: Digit, out: &mut Vec<Digit>) -> Option<()> {
+    if radix < 2 {
+        return None
+    }
+    out.clear();
+    let mut tmp = Awi::from_bits(bits);
+    loop {
+        out.push(tmp.digit_udivide_inplace_(radix).unwrap());
+        if tmp.is_zero() {
+            break
+        }
+    }
+    Some(())
+}
+
+/// Creates a string of exactly `bits.bw()` `'0'` and `'1'` chars
+pub(crate) fn bits_to_bit_string(bits: &Bits, msb_first: bool) -> String {
+    let w = bits.bw();
+    let mut s = String::with_capacity(w);
+    for i in 0..w {
+        let inx = if msb_first { w - 1 - i } else { i };
+        s.push(if bits.get(inx).unwrap() { '1' } else { '0' });
+    }
+    s
+}
+
+/// Returns the bitwidth of a most significant bit first bit string
+pub(crate) fn bit_string_bw(src: &str) -> Result<NonZeroUsize, SerdeError> {
+    NonZeroUsize::new(src.len()).ok_or(Empty)
+}
+
+/// Assigns the value of a most significant bit first bit string to `bits`,
+/// which should already have the bitwidth returned by `bit_string_bw`
+pub(crate) fn internal_from_bit_string(bits: &mut Bits, src: &str) -> Result<(), SerdeError> {
+    for (i, c) in src.bytes().rev().enumerate() {
+        let b = match c {
+            b'0' => false,
+            b'1' => true,
+            _ => return Err(InvalidChar),
+        };
+        bits.set(i, b).unwrap();
+    }
+    Ok(())
+}
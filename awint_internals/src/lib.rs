@@ -183,4 +183,17 @@ impl Location {
             col: u32::MAX,
         }
     }
+
+    /// Returns the `Location` of the caller, this should be called from a
+    /// function with `#[track_caller]` to propagate the location of the
+    /// original caller
+    #[track_caller]
+    pub fn caller() -> Self {
+        let tmp = core::panic::Location::caller();
+        Self {
+            file: tmp.file(),
+            line: tmp.line(),
+            col: tmp.column(),
+        }
+    }
 }
@@ -1,8 +1,8 @@
 //! Common serialization utilities
 
-use core::fmt;
+use core::{fmt, num::NonZeroUsize};
 
-use crate::USIZE_BITS;
+use crate::{bw, USIZE_BITS};
 
 // The reason this is here is because I need the free functions in `awint_core`
 // for speeding up certain serialization tasks, but the free functions also need
@@ -160,3 +160,186 @@ pub const fn panicking_chars_upper_bound(significant_bits: usize, radix: u8) ->
         Err(_e) => panic!(),
     }
 }
+
+/// Binary logarithms of 2 in the bases 2..=36 (or the reciprocal binary
+/// logarithms of 2..=36) rounded down and in 0.192 fixed point format, as
+/// little endian `u64`s. Entries for powers of two are zero because they are
+/// handled exactly without the table.
+const INV_LB_F192: [[u64; 3]; 37] = [
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x090a48ddb0de33c5, 0x043eaf7791f52142, 0xa1849cc1a9a9e94e],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x40584d3d310b8061, 0x33d522368f0d1d89, 0x6e40d1a4143dcb94],
+    [0x8a2cae211bb63e21, 0xff85a5c1b80aaa91, 0x6308c91b702a7cf4],
+    [0x1e1e26dce5b2b5b9, 0x9bd82cc11a7209d2, 0x5b3064eb3aa6d388],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0485246ed86f19e2, 0x021f57bbc8fa90a1, 0x50c24e60d4d4f4a7],
+    [0x13569862a1e8f9a4, 0x47c4acd605be48bc, 0x4d104d427de7fbcc],
+    [0xffb081ec66f6c1e5, 0x70b466920e51e1f7, 0x4a00270775914e88],
+    [0xcada9779fa551f76, 0x7f122e2f4c79f9ca, 0x4768ce0d05818e12],
+    [0x39bd7ac4868ca6a3, 0x2bf75000cfb72251, 0x452e53e365907bda],
+    [0xde00f47ce216659b, 0xc2d2e89586d2b763, 0x433cfffb4b5aae55],
+    [0x324183196c5d392d, 0x37bbdca4fca609de, 0x41867711b4f85355],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x87b2e17d82daae00, 0xe1c51ddbeac65f02, 0x3ea16afd58b10966],
+    [0xe39f2dabe0641d60, 0x0da34544e21084a1, 0x3d64598d154dc4de],
+    [0x4147294d6211b5cc, 0x0369e97d641961e5, 0x3c43c23018bb5563],
+    [0xa52f16a2d22d9ebd, 0x02cceaea8207233f, 0x3b3b9a42873069c7],
+    [0xb48d1499334f53e9, 0x90409adae68a5d43, 0x3a4898f06cf41ac9],
+    [0x2ac45d8b01cf78b3, 0x76f62d7317e2d8bd, 0x39680b13582e7c18],
+    [0xeb175f850572ac2b, 0xb0f3e4b3bda6639c, 0x3897b2b751ae561a],
+    [0x600af2c1222df99d, 0xcd9850af9a126d7e, 0x37d5aed131f19c98],
+    [0xa02c269e9885c030, 0x19ea911b47868ec4, 0x372068d20a1ee5ca],
+    [0x1f67af426b836fc7, 0x1912e33748b4029f, 0x3676867e5d60de29],
+    [0x5858c2f4904a1141, 0x56bf8fd285fc606b, 0x35d6deeb388df86f],
+    [0xdb53f138ad019f05, 0x37ac410062da9305, 0x354071d61c77fa2e],
+    [0x8048d04059cce041, 0xf3315689e7fc958f, 0x34b260c5671b18ac],
+    [0x5a108a5385ece85e, 0x8d5dad3f1f35ccc3, 0x342be986572b45cc],
+    [0x362dee09a17a047e, 0xb55bac355a82ee98, 0x33ac61b998fbbdf2],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x3ac9e5c14273aa66, 0xc220c028e9dbc15a, 0x32bfd90114c12861],
+    [0x15fd5da403d1d56f, 0xbed2f23982c11654, 0x3251dcf6169e45f2],
+    [0x545b9f421c3b12b5, 0x9a55d658e0cac095, 0x31e8d59f180dc630],
+    [0xc51657108ddb1f10, 0x7fc2d2e0dc055548, 0x3184648db8153e7a],
+];
+
+/// Fractional parts of the binary logarithms of the integers 2..=36 rounded
+/// down and in 0.192 fixed point format, as little endian `u64`s. Entries for
+/// powers of two are zero because they are handled exactly without the table.
+const FRACT_LB_F192: [[u64; 3]; 37] = [
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x24f3e6a3a259b040, 0xa00b120a068badd1, 0x95c01a39fbd6879f],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x65b157f8deceb53a, 0x24afdbfd36bf6d33, 0x5269e12f346e2bf9],
+    [0x24f3e6a3a259b040, 0xa00b120a068badd1, 0x95c01a39fbd6879f],
+    [0x38c6a548017167ca, 0x2ac903a413e5a847, 0xceaecfea80859b33],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x49e7cd4744b36080, 0x401624140d175ba2, 0x2b803473f7ad0f3f],
+    [0x65b157f8deceb53a, 0x24afdbfd36bf6d33, 0x5269e12f346e2bf9],
+    [0x3e730bb7410e895b, 0xfaf866415554d6bf, 0x759d4f80cba83bf8],
+    [0x24f3e6a3a259b040, 0xa00b120a068badd1, 0x95c01a39fbd6879f],
+    [0x1cf483d2900676c7, 0x76da1c872983511e, 0xb35004723c465e69],
+    [0x38c6a548017167ca, 0x2ac903a413e5a847, 0xceaecfea80859b33],
+    [0x8aa53e9c8128657a, 0xc4baee073d4b1b04, 0xe829fb693044b398],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0xca507cfab1d27f98, 0xcc53826144575ac3, 0x1663f6fac913167c],
+    [0x49e7cd4744b36080, 0x401624140d175ba2, 0x2b803473f7ad0f3f],
+    [0x99eaddb5a9520d6a, 0x51b3314f09de6be4, 0x3f782d7204d01447],
+    [0x65b157f8deceb53a, 0x24afdbfd36bf6d33, 0x5269e12f346e2bf9],
+    [0x5dba8beba3cb180b, 0xcad415ae1a715618, 0x646eea247c5c22d2],
+    [0x3e730bb7410e895b, 0xfaf866415554d6bf, 0x759d4f80cba83bf8],
+    [0x3f2869dd6be1d1cc, 0x12ba94db12ef0aa8, 0x86082806b1d532c4],
+    [0x24f3e6a3a259b040, 0xa00b120a068badd1, 0x95c01a39fbd6879f],
+    [0xcb62aff1bd9d6a74, 0x495fb7fa6d7eda66, 0xa4d3c25e68dc57f2],
+    [0x1cf483d2900676c7, 0x76da1c872983511e, 0xb35004723c465e69],
+    [0x6edbb3eae70d10c1, 0xe021361e13a30973, 0xc1404eadf38396de],
+    [0x38c6a548017167ca, 0x2ac903a413e5a847, 0xceaecfea80859b33],
+    [0x05d84c6e2eadff07, 0x5b8a19b1c637671f, 0xdba4a47aa996d25a],
+    [0x8aa53e9c8128657a, 0xc4baee073d4b1b04, 0xe829fb693044b398],
+    [0x06c1f1d26c710872, 0x0d1e3f80fbc71454, 0xf446359b13539551],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x6366f25ae368399c, 0x9b03784b5be08490, 0x0b5d69bac77ec398],
+    [0xca507cfab1d27f98, 0xcc53826144575ac3, 0x1663f6fac913167c],
+    [0x9e77fd40e0401d05, 0x4f78dfa14aa5157a, 0x2118b119b4f3c72c],
+    [0x49e7cd4744b36080, 0x401624140d175ba2, 0x2b803473f7ad0f3f],
+];
+
+#[test]
+fn lb_f192() {
+    for i in 3..=36u8 {
+        if !i.is_power_of_two() {
+            let lb = (i as f64).log2();
+            let inv = (INV_LB_F192[i as usize][2] as f64) / 2.0f64.powi(64);
+            assert!((inv - lb.recip()).abs() < 1e-15);
+            let fract = (FRACT_LB_F192[i as usize][2] as f64) / 2.0f64.powi(64);
+            assert!((fract - lb.fract()).abs() < 1e-15);
+        }
+    }
+}
+
+/// Returns `floor(x * c)` where `c` is in 0.192 fixed point format
+const fn mul_floor_f192(x: usize, c: &[u64; 3]) -> u128 {
+    let x = x as u128;
+    let tmp0 = x * (c[0] as u128);
+    let tmp1 = (x * (c[1] as u128)) + (tmp0 >> 64);
+    let tmp2 = (x * (c[2] as u128)) + (tmp1 >> 64);
+    tmp2 >> 64
+}
+
+/// Returns the maximum number of characters needed to represent an unsigned
+/// value of bitwidth `bw` in the given `radix`, which is the number of
+/// characters in the representation of `2^bw - 1`. This is exact, unlike
+/// [chars_upper_bound](crate::chars_upper_bound), and is `const` so that it
+/// can be used for sizing stack arrays. At least 1 is always returned because
+/// a zero value is represented with a single character.
+///
+/// # Panics
+///
+/// If `radix` is not in the range `2..=36`
+pub const fn max_str_len(bw: usize, radix: u8) -> usize {
+    if radix < 2 || radix > 36 {
+        panic!("`radix` is not in the range `2..=36`")
+    }
+    if bw == 0 {
+        return 1
+    }
+    if radix.is_power_of_two() {
+        // `ceil(bw / log2(radix))`
+        let lb = radix.trailing_zeros() as usize;
+        let quo = bw / lb;
+        if (quo * lb) == bw {
+            quo
+        } else {
+            quo + 1
+        }
+    } else {
+        // `bw / log2(radix)` is never an integer, and `INV_LB_F192` has enough
+        // precision that the floor is exact for any `bw` representable in a `usize`
+        (mul_floor_f192(bw, &INV_LB_F192[radix as usize]) as usize) + 1
+    }
+}
+
+/// Returns the minimum bitwidth that can represent the unsigned value of any
+/// string of `len` characters in the given `radix`, which is the number of
+/// significant bits in `radix^len - 1`. This is the inverse of
+/// [max_str_len](crate::max_str_len) in the sense that
+/// `max_str_len(bits_for_str_len(len, radix).get(), radix) >= len`. This is
+/// exact, unlike [bits_upper_bound](crate::bits_upper_bound). A bitwidth of
+/// 1 is returned if `len == 0`.
+///
+/// # Panics
+///
+/// If `radix` is not in the range `2..=36` or the result would overflow
+pub const fn bits_for_str_len(len: usize, radix: u8) -> NonZeroUsize {
+    if radix < 2 || radix > 36 {
+        panic!("`radix` is not in the range `2..=36`")
+    }
+    // `floor(log2(radix))`
+    let lb = (u8::BITS - 1 - radix.leading_zeros()) as usize;
+    let bits = if let Some(bits) = len.checked_mul(lb) {
+        bits
+    } else {
+        panic!("overflow in `bits_for_str_len`")
+    };
+    let bits = if radix.is_power_of_two() {
+        bits
+    } else {
+        // `ceil(len * log2(radix))`, where the product is never an integer if `len`
+        // is nonzero
+        let fract = mul_floor_f192(len, &FRACT_LB_F192[radix as usize]) as usize;
+        if let Some(bits) = bits.checked_add(fract + 1) {
+            bits
+        } else {
+            panic!("overflow in `bits_for_str_len`")
+        }
+    };
+    if bits == 0 {
+        bw(1)
+    } else {
+        bw(bits)
+    }
+}
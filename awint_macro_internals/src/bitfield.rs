@@ -0,0 +1,233 @@
+use std::str::FromStr;
+
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+
+use crate::{
+    error_and_help, i128_to_usize, i128_try_parse, parse_range, token_stream_to_ast,
+    unstable_native_inlawi_ty, Usb,
+};
+
+const BITFIELD_HELP: &str = "the input should look like `bitfield!(pub struct Name(32) { field0: \
+                             0..7, pub field1: 7..12, ... })`";
+
+/// A named field with a static range, parsed from the input of `bitfield!`
+#[derive(Debug, Clone)]
+struct BitfieldField {
+    /// attributes and visibility applied to the getter and setter
+    attrs: String,
+    vis: String,
+    name: String,
+    start: usize,
+    end: usize,
+}
+
+fn bitfield_error(error: &str) -> String {
+    error_and_help(error, BITFIELD_HELP)
+}
+
+/// Takes leading `#[...]` attributes and a visibility qualifier from the front
+/// of `tts`
+fn take_attrs_and_vis(tts: &mut Vec<TokenTree>) -> (String, String) {
+    let mut attrs = TokenStream::new();
+    loop {
+        match (tts.first(), tts.get(1)) {
+            (Some(TokenTree::Punct(p)), Some(TokenTree::Group(g)))
+                if (p.as_char() == '#') && (g.delimiter() == Delimiter::Bracket) =>
+            {
+                attrs.extend(tts.drain(..2));
+            }
+            _ => break,
+        }
+    }
+    let mut vis = TokenStream::new();
+    if let Some(TokenTree::Ident(i)) = tts.first() {
+        if i == "pub" {
+            vis.extend(tts.drain(..1));
+            if let Some(TokenTree::Group(g)) = tts.first() {
+                if g.delimiter() == Delimiter::Parenthesis {
+                    vis.extend(tts.drain(..1));
+                }
+            }
+        }
+    }
+    (attrs.to_string(), vis.to_string())
+}
+
+/// Parses a field range with the same range parsing used by the concatenation
+/// macros, but requiring a statically known range
+fn parse_field_range(name: &str, range: TokenStream, w: usize) -> Result<(usize, usize), String> {
+    let mut ast = token_stream_to_ast(range);
+    if (ast.cc.len() != 1) || (ast.cc[0].comps.len() != 1) {
+        return Err(bitfield_error(&format!(
+            "field `{name}` has an unexpected `;` in its range"
+        )))
+    }
+    let txt = ast.cc[0].comps[0].txt;
+    if ast.txt[txt].is_empty() {
+        return Err(bitfield_error(&format!("field `{name}` has no range")))
+    }
+    let mut usbr = match parse_range(&mut ast, txt, true) {
+        Ok(usbr) => usbr,
+        Err(Some(e)) => {
+            return Err(format!(
+                "error in range of field `{name}`: {}",
+                e.ast_error(&ast)
+            ))
+        }
+        Err(None) => unreachable!(),
+    };
+    if usbr.end.is_none() {
+        usbr.end = Some(Usb::val(w as i128));
+    }
+    usbr.simplify()
+        .map_err(|e| format!("error in range of field `{name}`: {e}"))?;
+    if let Some((start, end)) = usbr.static_range() {
+        let start = i128_to_usize(start)?;
+        let end = i128_to_usize(end)?;
+        if end > w {
+            return Err(format!(
+                "the range {start}..{end} of field `{name}` exceeds the bitwidth {w} of the \
+                 bitfield"
+            ))
+        }
+        Ok((start, end))
+    } else {
+        Err(bitfield_error(&format!(
+            "the range of field `{name}` could not be determined statically"
+        )))
+    }
+}
+
+/// Input parsing and code generation function for `bitfield!`
+pub fn bitfield_macro(input: &str) -> Result<String, String> {
+    let ts = TokenStream::from_str(input)
+        .map_err(|e| bitfield_error(&format!("input failed to tokenize: {e}")))?;
+    let mut tts: Vec<TokenTree> = ts.into_iter().collect();
+    let (attrs, vis) = take_attrs_and_vis(&mut tts);
+    let mut tts = tts.into_iter();
+    match tts.next() {
+        Some(TokenTree::Ident(i)) if i == "struct" => (),
+        _ => {
+            return Err(bitfield_error(
+                "expected `struct` after any attributes and visibility",
+            ))
+        }
+    }
+    let name = match tts.next() {
+        Some(TokenTree::Ident(i)) => i.to_string(),
+        _ => return Err(bitfield_error("expected a struct name after `struct`")),
+    };
+    let w = match tts.next() {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => {
+            let s: Vec<char> = g
+                .stream()
+                .to_string()
+                .chars()
+                .filter(|c| *c != '_')
+                .collect();
+            match i128_try_parse(&s) {
+                Some(w) if w > 0 => i128_to_usize(w)?,
+                _ => {
+                    return Err(bitfield_error(&format!(
+                        "the bitwidth of struct `{name}` needs to be a nonzero integer literal"
+                    )))
+                }
+            }
+        }
+        _ => {
+            return Err(bitfield_error(&format!(
+                "expected a parenthesized bitwidth after `{name}`"
+            )))
+        }
+    };
+    let body = match tts.next() {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Brace => g.stream(),
+        _ => return Err(bitfield_error("expected a brace delimited group of fields")),
+    };
+    if tts.next().is_some() {
+        return Err(bitfield_error("unexpected input after the fields"))
+    }
+
+    // split the body by top level commas
+    let mut raw_fields: Vec<Vec<TokenTree>> = vec![vec![]];
+    for tt in body {
+        match tt {
+            TokenTree::Punct(ref p) if p.as_char() == ',' => raw_fields.push(vec![]),
+            _ => raw_fields.last_mut().unwrap().push(tt),
+        }
+    }
+    if raw_fields.last().unwrap().is_empty() {
+        // trailing comma
+        raw_fields.pop();
+    }
+    if raw_fields.is_empty() {
+        return Err(bitfield_error(&format!("struct `{name}` has no fields")))
+    }
+    let mut fields = vec![];
+    for mut raw in raw_fields {
+        let (field_attrs, field_vis) = take_attrs_and_vis(&mut raw);
+        let mut raw = raw.into_iter();
+        let field_name = match raw.next() {
+            Some(TokenTree::Ident(i)) => i.to_string(),
+            _ => return Err(bitfield_error("expected a field name")),
+        };
+        match raw.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == ':' => (),
+            _ => {
+                return Err(bitfield_error(&format!(
+                    "expected `:` after field name `{field_name}`"
+                )))
+            }
+        }
+        if fields.iter().any(|f: &BitfieldField| f.name == field_name) {
+            return Err(bitfield_error(&format!(
+                "field `{field_name}` is defined more than once"
+            )))
+        }
+        let (start, end) = parse_field_range(&field_name, raw.collect(), w)?;
+        fields.push(BitfieldField {
+            attrs: field_attrs,
+            vis: field_vis,
+            name: field_name,
+            start,
+            end,
+        });
+    }
+
+    // check for overlap
+    let mut sorted: Vec<&BitfieldField> = fields.iter().collect();
+    sorted.sort_by_key(|f| f.start);
+    for pair in sorted.windows(2) {
+        if pair[0].end > pair[1].start {
+            return Err(format!(
+                "field `{}` with range {}..{} overlaps with field `{}` with range {}..{}",
+                pair[0].name, pair[0].start, pair[0].end, pair[1].name, pair[1].start, pair[1].end
+            ))
+        }
+    }
+
+    let ty = unstable_native_inlawi_ty(w as u128);
+    let mut s = format!(
+        "{attrs} {vis} struct {name}(pub {ty});\n#[allow(dead_code)]\nimpl {name} {{\n{vis} fn \
+         zero() -> Self {{ Self({ty}::zero()) }}\n"
+    );
+    for f in &fields {
+        let field_w = f.end - f.start;
+        let field_ty = unstable_native_inlawi_ty(field_w as u128);
+        let BitfieldField {
+            attrs,
+            vis,
+            name,
+            start,
+            ..
+        } = f;
+        s += &format!(
+            "{attrs} {vis} fn {name}(&self) -> {field_ty} {{ let mut tmp = {field_ty}::zero(); \
+             tmp.field_from(&self.0, {start}, {field_w}).unwrap(); tmp }}\n{attrs} {vis} fn \
+             set_{name}(&mut self, val: &{field_ty}) {{ self.0.field_to({start}, val, \
+             {field_w}).unwrap() }}\n"
+        );
+    }
+    s += "}";
+    Ok(s)
+}
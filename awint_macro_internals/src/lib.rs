@@ -10,6 +10,7 @@
 #![allow(clippy::comparison_chain)]
 #![cfg_attr(feature = "const_support", feature(const_trait_impl))]
 
+mod bitfield;
 mod cc_macro;
 mod component;
 mod concatenation;
@@ -23,6 +24,7 @@ mod token_stream;
 mod token_tree;
 
 pub use awint_ext::{self, awint_core};
+pub use bitfield::*;
 pub use cc_macro::*;
 pub use component::*;
 pub use concatenation::*;
@@ -42,6 +44,7 @@ pub fn awint_macro_cc(input: &str) -> Result<String, String> {
     let code_gen = CodeGen {
         static_width: false,
         return_type: None,
+        result_mode: false,
         must_use: awint_must_use,
         static_construction_fn: awint_static_construction_fn,
         lit_construction_fn: awint_unreachable_construction_fn,
@@ -56,6 +59,7 @@ pub fn awint_macro_inlawi(input: &str) -> Result<String, String> {
     let code_gen = CodeGen {
         static_width: true,
         return_type: Some("InlAwi"),
+        result_mode: false,
         must_use: awint_must_use,
         static_construction_fn: awint_static_construction_fn,
         lit_construction_fn: awint_inlawi_lit_construction_fn,
@@ -70,6 +74,7 @@ pub fn awint_macro_extawi(input: &str) -> Result<String, String> {
     let code_gen = CodeGen {
         static_width: false,
         return_type: Some("ExtAwi"),
+        result_mode: false,
         must_use: awint_must_use,
         static_construction_fn: awint_static_construction_fn,
         lit_construction_fn: awint_extawi_lit_construction_fn,
@@ -84,6 +89,37 @@ pub fn awint_macro_awi(input: &str) -> Result<String, String> {
     let code_gen = CodeGen {
         static_width: false,
         return_type: Some("Awi"),
+        result_mode: false,
+        must_use: awint_must_use,
+        static_construction_fn: awint_static_construction_fn,
+        lit_construction_fn: awint_awi_lit_construction_fn,
+        construction_fn: awi_construction_fn,
+        const_wrapper: identity_const_wrapper,
+        fn_names: AWINT_FN_NAMES,
+    };
+    cc_macro(input, code_gen, AWINT_NAMES)
+}
+
+pub fn awint_macro_try_extawi(input: &str) -> Result<String, String> {
+    let code_gen = CodeGen {
+        static_width: false,
+        return_type: Some("ExtAwi"),
+        result_mode: true,
+        must_use: awint_must_use,
+        static_construction_fn: awint_static_construction_fn,
+        lit_construction_fn: awint_extawi_lit_construction_fn,
+        construction_fn: extawi_construction_fn,
+        const_wrapper: identity_const_wrapper,
+        fn_names: AWINT_FN_NAMES,
+    };
+    cc_macro(input, code_gen, AWINT_NAMES)
+}
+
+pub fn awint_macro_try_awi(input: &str) -> Result<String, String> {
+    let code_gen = CodeGen {
+        static_width: false,
+        return_type: Some("Awi"),
+        result_mode: true,
         must_use: awint_must_use,
         static_construction_fn: awint_static_construction_fn,
         lit_construction_fn: awint_awi_lit_construction_fn,
@@ -98,6 +134,7 @@ pub fn awint_macro_bits(input: &str) -> Result<String, String> {
     let code_gen = CodeGen {
         static_width: true,
         return_type: Some("&'static Bits"),
+        result_mode: false,
         must_use: awint_must_use,
         static_construction_fn: awint_static_construction_fn,
         lit_construction_fn: awint_bits_lit_construction_fn,
@@ -107,3 +144,7 @@ pub fn awint_macro_bits(input: &str) -> Result<String, String> {
     };
     cc_macro(input, code_gen, AWINT_NAMES)
 }
+
+pub fn awint_macro_bitfield(input: &str) -> Result<String, String> {
+    bitfield_macro(input)
+}
@@ -80,8 +80,13 @@ pub fn cc_macro_code_gen<
 ) -> String {
     let is_returning = code_gen.return_type.is_some();
 
-    // first check for simple infallible constant return
-    if is_returning && (ast.cc.len() == 1) && (ast.cc[0].comps.len() == 1) {
+    // first check for simple infallible constant return, which is not done in
+    // result mode because the return value needs to be wrapped
+    if is_returning
+        && (!code_gen.result_mode)
+        && (ast.cc.len() == 1)
+        && (ast.cc[0].comps.len() == 1)
+    {
         let comp = &ast.cc[0].comps[0];
         if let Literal(ref lit) = comp.c_type {
             // constants have been normalized and combined by now
@@ -208,18 +213,28 @@ pub fn cc_macro_code_gen<
     };
     let wrap_must_use = !returning.is_empty();
 
+    // in result mode, returning macros always go through the checks so that the
+    // return value is wrapped in a `Result`
+    let result_mode = is_returning && code_gen.result_mode;
+    let wrap_infallible = infallible && !result_mode;
+    let (wrap, wrap_none) = if result_mode {
+        ("wrap_ok", "wrap_err")
+    } else {
+        ("wrap", "wrap_none")
+    };
+
     // inner code consisting of construction of Awis, fielding, and returning
     let mut inner = format!("{construction}{fielding}{returning}");
 
     // very tricky, there are too many corner and optimization cases to do this in a
     // more compact way
     if ast.guaranteed_nonzero_width {
-        if infallible {
+        if wrap_infallible {
             // do nothing
         } else {
             inner = format!(
                 "let {} = {}([{}],[{}],[{}],[{}],{},false,false);\nif {}.run_fielding() \
-                 {{{}.wrap({{\n{}\n}})}} else {{{}.wrap_none()}}",
+                 {{{}.{}({{\n{}\n}})}} else {{{}.{}()}}",
                 names.res,
                 fn_names.cc_checks_fn,
                 lt_checks0,
@@ -229,19 +244,21 @@ pub fn cc_macro_code_gen<
                 names.cw,
                 names.res,
                 names.res,
+                wrap,
                 inner,
                 names.res,
+                wrap_none,
             )
         }
     } else if is_returning {
-        if infallible {
+        if wrap_infallible {
             // do nothing
         } else {
             // avoid creating the return value and return `None` because it is a condition
             // of construction macros
             inner = format!(
                 "let {} = {}([{}],[{}],[{}],[{}],{},true,false);\nif {}.run_fielding() \
-                 {{{}.wrap({{\n{}\n}})}} else {{{}.wrap_none()}}",
+                 {{{}.{}({{\n{}\n}})}} else {{{}.{}()}}",
                 names.res,
                 fn_names.cc_checks_fn,
                 lt_checks0,
@@ -251,8 +268,10 @@ pub fn cc_macro_code_gen<
                 names.cw,
                 names.res,
                 names.res,
+                wrap,
                 inner,
                 names.res,
+                wrap_none,
             )
         }
     } else if need_buffer {
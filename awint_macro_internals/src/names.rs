@@ -84,6 +84,9 @@ pub const AWINT_FN_NAMES: FnNames = FnNames {
 ///
 /// - `static_width`: if the type needs a statically known width
 /// - `return_type`: if the bits need to be returned
+/// - `result_mode`: if fallible returning macros should return `Result<_,
+///   SerdeError>` instead of `Option<_>`, in which case they also return
+///   `Result`s when infallible
 /// - `must_use`: wraps return values in a function for insuring `#[must_use]`
 /// - `static_construction_fn`: construction for internal constants
 /// - `lit_construction_fn`: construction function for known literals of the
@@ -104,6 +107,7 @@ pub struct CodeGen<
 > {
     pub static_width: bool,
     pub return_type: Option<&'a str>,
+    pub result_mode: bool,
     pub must_use: F0,
     pub static_construction_fn: F1,
     pub lit_construction_fn: F2,
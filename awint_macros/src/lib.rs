@@ -693,8 +693,9 @@
 
 extern crate proc_macro;
 use awint_macro_internals::{
-    awint_macro_bits, awint_macro_cc, awint_macro_extawi, awint_macro_inlawi,
-    unstable_native_inlawi_ty, awint_macro_awi,
+    awint_macro_bitfield, awint_macro_bits, awint_macro_cc, awint_macro_extawi,
+    awint_macro_inlawi, unstable_native_inlawi_ty, awint_macro_awi, awint_macro_try_awi,
+    awint_macro_try_extawi,
 };
 use proc_macro::TokenStream;
 
@@ -763,6 +764,48 @@ pub fn awi(input: TokenStream) -> TokenStream {
     }
 }
 
+/// The same as [extawi!], except that it returns `Result<ExtAwi, SerdeError>`
+/// instead of `Option<ExtAwi>` or `ExtAwi`. This is for when a dynamic
+/// concatenation can fail and the reason for failure is needed. The error is
+/// `SerdeError::Overflow` if a range is reversed or extends beyond the bitwidth
+/// of its component, `SerdeError::NonEqualWidths` if the concatenations do not
+/// have equal bitwidths, and `SerdeError::ZeroBitwidth` if the result would
+/// have a zero bitwidth. This is not supported for the mimicking types of
+/// `awint_dag`.
+///
+/// ```
+/// use awint::{awi::*, SerdeError};
+///
+/// let x = inlawi!(0xabcdu16);
+/// let (r0, r1) = (4, 12);
+/// assert_eq!(try_extawi!(x[r0..r1]), Ok(extawi!(0xbcu8)));
+/// let r1 = 20;
+/// assert_eq!(try_extawi!(x[r0..r1]), Err(SerdeError::Overflow));
+/// let r1 = 4;
+/// assert_eq!(try_extawi!(x[r0..r1]), Err(SerdeError::ZeroBitwidth));
+/// let w = 12;
+/// assert_eq!(try_extawi!(x[..8]; ..w), Err(SerdeError::NonEqualWidths));
+/// // infallible inputs still return a `Result`
+/// assert_eq!(try_extawi!(x), Ok(extawi!(0xabcdu16)));
+/// ```
+#[proc_macro]
+pub fn try_extawi(input: TokenStream) -> TokenStream {
+    match awint_macro_try_extawi(&input.to_string()) {
+        Ok(s) => s.parse().unwrap(),
+        Err(s) => panic!("{}", s),
+    }
+}
+
+/// The same as [awi!], except that it returns `Result<Awi, SerdeError>`. See
+/// [try_extawi!] for more.
+#[proc_macro]
+pub fn try_awi(input: TokenStream) -> TokenStream {
+    match awint_macro_try_awi(&input.to_string()) {
+        Ok(s) => s.parse().unwrap(),
+        Err(s) => panic!("{}", s),
+    }
+}
+
 // We make the `bits` macro `&'static`, because making a relaxed `bits` or
 // `bits_mut` macro typically leads to unoptimality and weird compiler errors.
 // Users should use references from `extawi` or `inlawi` in any other case.
@@ -780,3 +823,47 @@ pub fn bits(input: TokenStream) -> TokenStream {
         Err(s) => panic!("{}", s),
     }
 }
+
+/// Defines a tuple struct wrapping an
+/// [InlAwi](awint_macro_internals::awint_core::InlAwi) of a given bitwidth,
+/// with getter and setter methods for named bit fields.
+///
+/// The fields use the same range syntax as the concatenation macros, but the
+/// ranges must be statically known. An unbounded end extends to the bitwidth
+/// of the struct, and a single index is a single bit field. It is a compile
+/// time error for fields to overlap or to exceed the bitwidth. For each field
+/// `x` with width `w`, a getter `x(&self) -> inlawi_ty!(w)` and a setter
+/// `set_x(&mut self, val: &inlawi_ty!(w))` are generated, with the visibility
+/// and attributes of the field. A `zero()` constructor is also generated, and
+/// the inner `InlAwi` is public.
+///
+/// ```
+/// use awint::awi::*;
+///
+/// bitfield!(
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub struct Reg(32) {
+///         pub opcode: 0..7,
+///         pub rd: 7..12,
+///         pub flag: 12,
+///         pub imm: 20..,
+///     }
+/// );
+///
+/// let mut reg = Reg::zero();
+/// reg.set_opcode(&inlawi!(0b011_0011u7));
+/// reg.set_rd(&inlawi!(5u5));
+/// reg.set_flag(&inlawi!(1u1));
+/// reg.set_imm(&inlawi!(0xabcu12));
+/// assert_eq!(reg.opcode(), inlawi!(0b011_0011u7));
+/// assert_eq!(reg.rd(), inlawi!(5u5));
+/// assert_eq!(reg.imm(), inlawi!(0xabcu12));
+/// assert_eq!(reg.0, inlawi!(0xabc012b3u32));
+/// ```
+#[proc_macro]
+pub fn bitfield(input: TokenStream) -> TokenStream {
+    match awint_macro_bitfield(&input.to_string()) {
+        Ok(s) => s.parse().unwrap(),
+        Err(s) => panic!("{}", s),
+    }
+}
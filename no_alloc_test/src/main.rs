@@ -3,7 +3,7 @@
 
 extern crate panic_halt;
 
-use awint::awi::*;
+use awint::{awi::*, max_str_len};
 use rand_xoshiro::{rand_core::SeedableRng, Xoshiro128StarStar};
 use riscv_minimal_rt::entry;
 
@@ -88,5 +88,13 @@ fn main() -> ! {
     let r1 = 7;
     assert_eq!(cc!(0x123u12[r0..r1]), Some(()));
 
+    // parsing without allocation
+    let mut x = inlawi!(0u32);
+    x.from_bytes_radix_(b"123_456", 10).unwrap();
+    assert_eq!(x, inlawi!(123456u32));
+    let mut buf = [0u8; max_str_len(32, 10)];
+    let len = x.to_bytes_radix_no_pad(10, &mut buf).unwrap();
+    assert_eq!(&buf[..len], b"123456");
+
     panic!("main is not allowed to return")
 }
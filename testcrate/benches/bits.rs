@@ -0,0 +1,40 @@
+#![feature(test)]
+
+extern crate test;
+use awint::{awint_internals::BITS, bw, Bits, ExtAwi};
+use rand_xoshiro::{rand_core::SeedableRng, Xoshiro128StarStar};
+use test::{black_box, Bencher};
+
+const W: usize = 1 << 16;
+
+fn field_bench(bencher: &mut Bencher, to: usize, from: usize, aligned: bool) {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    let mut lhs = ExtAwi::zero(bw(W));
+    let mut rhs = ExtAwi::zero(bw(W));
+    rhs.rand_(&mut rng).unwrap();
+    let width = W - (4 * BITS);
+    bencher.iter(|| {
+        let rhs: &Bits = black_box(&rhs);
+        if aligned {
+            lhs.copy_digit_aligned_(to, rhs, from, width).unwrap();
+        } else {
+            lhs.field(to, rhs, from, width).unwrap();
+        }
+        black_box(&lhs);
+    })
+}
+
+#[bench]
+fn field_aligned(bencher: &mut Bencher) {
+    field_bench(bencher, BITS, 2 * BITS, false)
+}
+
+#[bench]
+fn field_unaligned(bencher: &mut Bencher) {
+    field_bench(bencher, BITS + 3, 2 * BITS + 5, false)
+}
+
+#[bench]
+fn copy_digit_aligned(bencher: &mut Bencher) {
+    field_bench(bencher, BITS, 2 * BITS, true)
+}
@@ -27,6 +27,7 @@ fn cc(mut f: impl Write, input: &str) {
     let code_gen = CodeGen {
         static_width: false,
         return_type: None,
+        result_mode: false,
         must_use: |s| format!("mu({s})"),
         static_construction_fn: |awi| format!("static({awi})"),
         lit_construction_fn: |awi| format!("lit({awi})"),
@@ -55,6 +56,7 @@ fn static_cc(mut f: impl Write, input: &str) {
     let code_gen = CodeGen {
         static_width: true,
         return_type: Some("StaticAwi"),
+        result_mode: false,
         must_use: |s| format!("mu({s})"),
         static_construction_fn: |awi| format!("static({awi})"),
         lit_construction_fn: |awi| format!("lit({awi})"),
@@ -83,6 +85,7 @@ fn dynamic_cc(mut f: impl Write, input: &str) {
     let code_gen = CodeGen {
         static_width: false,
         return_type: Some("DynamicAwi"),
+        result_mode: false,
         must_use: |s| format!("mu({s})"),
         static_construction_fn: |awi| format!("static({awi})"),
         lit_construction_fn: |awi| format!("lit({awi})"),
@@ -111,6 +114,7 @@ fn const_cc(mut f: impl Write, input: &str) {
     let code_gen = CodeGen {
         static_width: true,
         return_type: Some("ConstAwi"),
+        result_mode: false,
         must_use: |s| format!("mu({s})"),
         static_construction_fn: |awi| format!("static({awi})"),
         lit_construction_fn: |awi| format!("lit({awi})"),
@@ -0,0 +1,52 @@
+use awint::{awi::*, bit_permutation::BitPermutation};
+use rand_xoshiro::{
+    rand_core::{RngCore, SeedableRng},
+    Xoshiro128StarStar,
+};
+
+#[test]
+fn bit_permutation_random() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..1000 {
+        let w = ((rng.next_u32() % 300) + 1) as usize;
+        // Fisher-Yates shuffle, sometimes leaving runs of the identity intact
+        let mut map: Vec<usize> = (0..w).collect();
+        let swaps = if (rng.next_u32() & 1) == 0 {
+            w
+        } else {
+            (rng.next_u32() as usize) % 4
+        };
+        for _ in 0..swaps {
+            let i = (rng.next_u32() as usize) % w;
+            let j = (rng.next_u32() as usize) % w;
+            map.swap(i, j);
+        }
+        let perm = BitPermutation::new(&map).unwrap();
+        assert_eq!(perm.bw(), w);
+        let mut src = ExtAwi::zero(bw(w));
+        src.rand_(&mut rng).unwrap();
+        let mut dst = ExtAwi::zero(bw(w));
+        dst.rand_(&mut rng).unwrap();
+        perm.apply(&src, &mut dst).unwrap();
+        for (i, j) in map.iter().enumerate() {
+            assert_eq!(dst.get(i).unwrap(), src.get(*j).unwrap());
+        }
+        // rotations
+        let s = (rng.next_u32() as usize) % w;
+        let map: Vec<usize> = (0..w).map(|i| (i + w - s) % w).collect();
+        let perm = BitPermutation::new(&map).unwrap();
+        perm.apply(&src, &mut dst).unwrap();
+        src.rotl_(s).unwrap();
+        assert_eq!(dst, src);
+    }
+}
+
+#[test]
+fn bit_permutation_invalid() {
+    assert!(BitPermutation::new(&[]).is_none());
+    assert!(BitPermutation::new(&[0, 0]).is_none());
+    assert!(BitPermutation::new(&[0, 2]).is_none());
+    let perm = BitPermutation::new(&[1, 0]).unwrap();
+    assert!(perm.apply(&inlawi!(0u2), &mut inlawi!(0u3)).is_none());
+    assert!(perm.apply(&inlawi!(0u3), &mut inlawi!(0u2)).is_none());
+}
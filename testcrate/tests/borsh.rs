@@ -0,0 +1,59 @@
+use awint::awi::*;
+
+#[test]
+fn borsh() {
+    // the format is the bitwidth as a little-endian `u64` and then the
+    // little-endian bytes of the value
+    let x = inlawi!(0x1fedcba9876543210u68);
+    let bytes = borsh::to_vec(&x).unwrap();
+    assert_eq!(bytes, vec![
+        68, 0, 0, 0, 0, 0, 0, 0, 0x10, 0x32, 0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe, 0x1
+    ]);
+    assert_eq!(borsh::to_vec(&ExtAwi::from(x)).unwrap(), bytes);
+    assert_eq!(borsh::to_vec(&Awi::from(x)).unwrap(), bytes);
+    assert_eq!(borsh::from_slice::<inlawi_ty!(68)>(&bytes).unwrap(), x);
+    assert_eq!(
+        borsh::from_slice::<ExtAwi>(&bytes).unwrap().as_ref(),
+        x.as_ref()
+    );
+    assert_eq!(
+        borsh::from_slice::<Awi>(&bytes).unwrap().as_ref(),
+        x.as_ref()
+    );
+
+    // mismatched `InlAwi` width
+    assert!(borsh::from_slice::<inlawi_ty!(69)>(&bytes).is_err());
+    // zero width
+    assert!(borsh::from_slice::<ExtAwi>(&[0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    // missing bytes
+    assert!(borsh::from_slice::<Awi>(&bytes[..(bytes.len() - 1)]).is_err());
+    // a huge claimed width with a short payload must error instead of
+    // allocating the claimed width
+    let mut huge = (1u64 << 62).to_le_bytes().to_vec();
+    huge.extend_from_slice(&[0xff; 16]);
+    assert!(borsh::from_slice::<ExtAwi>(&huge).is_err());
+    assert!(borsh::from_slice::<Awi>(&huge).is_err());
+    let huge = u64::MAX.to_le_bytes();
+    assert!(borsh::from_slice::<ExtAwi>(&huge).is_err());
+    assert!(borsh::from_slice::<Awi>(&huge).is_err());
+    // trailing partial-byte bits set
+    let mut bad = bytes.clone();
+    *bad.last_mut().unwrap() |= 0x10;
+    assert!(borsh::from_slice::<inlawi_ty!(68)>(&bad).is_err());
+    assert!(borsh::from_slice::<ExtAwi>(&bad).is_err());
+    assert!(borsh::from_slice::<Awi>(&bad).is_err());
+
+    // round trips across many widths
+    for w in 1..300 {
+        let mut x = Awi::zero(bw(w));
+        x.umax_();
+        x.lshr_(w / 3).unwrap();
+        let bytes = borsh::to_vec(&x).unwrap();
+        assert_eq!(bytes.len(), 8 + (w / 8) + ((w % 8) != 0) as usize);
+        assert_eq!(borsh::from_slice::<Awi>(&bytes).unwrap(), x);
+        assert_eq!(
+            borsh::from_slice::<ExtAwi>(&bytes).unwrap(),
+            ExtAwi::from_bits(&x)
+        );
+    }
+}
@@ -72,6 +72,41 @@ const fn consts() {
     eq(z0.as_ref(), inlawi!(0x3210u16).as_ref());
 }
 
+/// Lookup table of `3^i` computed at compile time from `InlAwi` arithmetic
+const POW3: [inlawi_ty!(64); 16] = {
+    let mut table: [inlawi_ty!(64); 16] = [InlAwi::zero(); 16];
+    let mut x: inlawi_ty!(64) = InlAwi::uone();
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = x;
+        // `x * 3 == (x << 1) + x`
+        let mut tmp = x;
+        tmp.shl_(1).unwrap();
+        x.add_(&tmp).unwrap();
+        i += 1;
+    }
+    table
+};
+
+#[test]
+const fn const_lookup_table() {
+    let mut i = 1;
+    while i < POW3.len() {
+        // `3^i - 3^(i - 1) == 2 * 3^(i - 1)`
+        let mut x = POW3[i];
+        x.sub_(&POW3[i - 1]).unwrap();
+        let mut y = POW3[i - 1];
+        y.shl_(1).unwrap();
+        eq(x.as_ref(), y.as_ref());
+        // `!(!x) == x`
+        x.not_();
+        x.not_();
+        assert!(x.const_eq(&y).unwrap());
+        i += 1;
+    }
+    eq(POW3[15].as_ref(), inlawi!(14348907u64).as_ref());
+}
+
 #[test]
 #[should_panic]
 const fn bw_panics() {
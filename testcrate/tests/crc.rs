@@ -0,0 +1,91 @@
+use awint::{awi::*, crc::Crc};
+
+const CHECK: &[u8] = b"123456789";
+
+fn check(poly: ExtAwi, init: ExtAwi, reflect: bool, xor_out: ExtAwi, expected: ExtAwi) {
+    let mut crc = Crc::new(poly, init, reflect, reflect, xor_out).unwrap();
+    crc.update(CHECK);
+    assert_eq!(crc.finalize(), expected);
+    // splitting the message does not change the result
+    crc.reset();
+    crc.update(&CHECK[..4]);
+    crc.update(&CHECK[4..]);
+    assert_eq!(crc.finalize(), expected);
+}
+
+#[test]
+fn crc_check_values() {
+    // CRC-32/ISO-HDLC
+    check(
+        extawi!(0x04c11db7_u32),
+        extawi!(umax: ..32),
+        true,
+        extawi!(umax: ..32),
+        extawi!(0xcbf43926_u32),
+    );
+    // CRC-32/BZIP2
+    check(
+        extawi!(0x04c11db7_u32),
+        extawi!(umax: ..32),
+        false,
+        extawi!(umax: ..32),
+        extawi!(0xfc891918_u32),
+    );
+    // CRC-16/ARC
+    check(
+        extawi!(0x8005_u16),
+        extawi!(0u16),
+        true,
+        extawi!(0u16),
+        extawi!(0xbb3d_u16),
+    );
+    // CRC-16/IBM-3740
+    check(
+        extawi!(0x1021_u16),
+        extawi!(umax: ..16),
+        false,
+        extawi!(0u16),
+        extawi!(0x29b1_u16),
+    );
+    // CRC-5/USB
+    check(
+        extawi!(0x05_u5),
+        extawi!(umax: ..5),
+        true,
+        extawi!(umax: ..5),
+        extawi!(0x19_u5),
+    );
+    // CRC-82/DARC
+    check(
+        extawi!(0x0308c_0111_0114_0144_0411_u82),
+        extawi!(0u82),
+        true,
+        extawi!(0u82),
+        extawi!(0x09ea_83f6_2502_3801_fd612_u82),
+    );
+    assert!(Crc::new(extawi!(0u8), extawi!(0u8), false, false, extawi!(0u9)).is_none());
+}
+
+#[test]
+fn crc_clmul_div_rem() {
+    // with no initial value, reflection, or final XOR, the CRC is the remainder
+    // of `M(x) * x^w` divided by the full polynomial
+    let poly = extawi!(0x1a2b_u13);
+    let mut crc = Crc::new(poly.clone(), extawi!(0u13), false, false, extawi!(0u13)).unwrap();
+    crc.update(CHECK);
+    let w = bw(CHECK.len() * 8 + 13 + 1);
+    let mut duo = ExtAwi::zero(w);
+    for (i, byte) in CHECK.iter().rev().enumerate() {
+        duo.field_to(13 + (i * 8), &InlAwi::from_u8(*byte), 8)
+            .unwrap();
+    }
+    let mut div = ExtAwi::zero(w);
+    div.zero_resize_(&poly);
+    div.set(13, true).unwrap();
+    let mut rem = ExtAwi::zero(w);
+    let mut pad = ExtAwi::zero(w);
+    duo.clmul_div_rem_(&div, &mut rem, &mut pad).unwrap();
+    let mut expected = extawi!(0u13);
+    expected.zero_resize_(&rem);
+    assert_eq!(crc.finalize(), expected);
+}
@@ -0,0 +1,72 @@
+use awint::{awi::*, crt::crt};
+use rand_xoshiro::{
+    rand_core::{RngCore, SeedableRng},
+    Xoshiro128StarStar,
+};
+
+#[test]
+fn crt_small() {
+    let moduli = [3u64, 4, 5, 7, 11, 1];
+    let prod: u64 = moduli.iter().product();
+    let moduli: Vec<ExtAwi> = moduli.iter().map(|m| ExtAwi::from_u64(*m)).collect();
+    let moduli: Vec<&Bits> = moduli.iter().map(|m| m.as_ref()).collect();
+    for x in 0..prod {
+        let residues: Vec<ExtAwi> = moduli
+            .iter()
+            .map(|m| ExtAwi::from_u8((x % m.to_u64()) as u8))
+            .collect();
+        let residues: Vec<&Bits> = residues.iter().map(|r| r.as_ref()).collect();
+        let res = crt(&residues, &moduli).unwrap();
+        assert_eq!(res.bw(), 64 * moduli.len());
+        assert_eq!(res.to_u64(), x);
+    }
+}
+
+#[test]
+fn crt_wide() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    // pairwise coprime moduli of different bitwidths, including Mersenne primes
+    let mut m61 = ExtAwi::umax(bw(61));
+    m61.set(0, true).unwrap();
+    let m89 = ExtAwi::umax(bw(89));
+    let m107 = ExtAwi::umax(bw(107));
+    // 2^64 as a 65 bit value
+    let mut p64 = ExtAwi::zero(bw(65));
+    p64.set(64, true).unwrap();
+    let moduli: [&Bits; 4] = [&m61, &m89, &m107, &p64];
+    let total = 61 + 89 + 107 + 65;
+    for _ in 0..100 {
+        // random value less than the product, which is more than `2^320`
+        let mut x = ExtAwi::zero(bw(total));
+        x.rand_(&mut rng).unwrap();
+        x.range_and_(0..320).unwrap();
+        let mut residues = vec![];
+        for m in moduli {
+            let mut m_ext = ExtAwi::zero(bw(total));
+            m_ext.zero_resize_(m);
+            let mut quo = ExtAwi::zero(bw(total));
+            let mut rem = ExtAwi::zero(bw(total));
+            Bits::udivide(&mut quo, &mut rem, &x, &m_ext).unwrap();
+            // residues do not have to be reduced or have the same bitwidth
+            if (rng.next_u32() & 1) == 0 {
+                rem.add_(&m_ext).unwrap();
+            }
+            residues.push(rem);
+        }
+        let residues: Vec<&Bits> = residues.iter().map(|r| r.as_ref()).collect();
+        assert_eq!(crt(&residues, &moduli).unwrap(), x);
+    }
+}
+
+#[test]
+fn crt_invalid() {
+    let a = inlawi!(1u8);
+    let m0 = inlawi!(6u8);
+    let m1 = inlawi!(9u8);
+    let zero = inlawi!(0u8);
+    assert!(crt(&[&a, &a], &[&m0, &m1]).is_none());
+    assert!(crt(&[&a, &a], &[&m0]).is_none());
+    assert!(crt(&[], &[]).is_none());
+    assert!(crt(&[&a], &[&zero]).is_none());
+    assert_eq!(crt(&[&a], &[&m0]).unwrap(), extawi!(1u8));
+}
@@ -209,6 +209,8 @@ fn dag_bits_functions_internal(
 
     // assert!(x0.neg_add_(false, x1).is_none());
     assert!(x0.cin_sum_(false, x1, x2).is_none());
+    assert!(x0.widening_add_(x1, x2).is_none());
+    assert!(x0.widening_sub_(x1, x2).is_none());
 
     x0.inc_(false);
     x0.dec_(true);
@@ -353,3 +355,99 @@ fn dag_lut_eval() {
     }
     drop(epoch0);
 }
+
+/// Values covering zero, single bits at both ends, scattered bits, and all
+/// ones, used by the tests comparing dag evaluation against `awi` functions
+fn eval_fixtures() -> [awi::Awi; 6] {
+    use awi::*;
+    [
+        awi!(0u100),
+        awi!(1u100),
+        awi!(0x1_0000_0000_0000u100),
+        awi!(0x1_0000_0000_0003u100),
+        awi!(imin: ..100),
+        awi!(umax: ..100),
+    ]
+}
+
+// checks the reductions (`xor_reduce` lowers to the `Parity` op) against their
+// concrete versions
+#[test]
+fn dag_reduce_eval() {
+    use awi::*;
+    for val in eval_fixtures() {
+        let epoch0 = Epoch::new();
+        let x = LazyAwi::opaque(bw(100));
+        let y = dag::Awi::from_bool(x.or_reduce());
+        let z = dag::Awi::from_bool(x.and_reduce());
+        let p = dag::Awi::from_bool(x.xor_reduce());
+        let (e_y, e_z, e_p) = (EvalAwi::from(&y), EvalAwi::from(&z), EvalAwi::from(&p));
+        x.retro_(&val).unwrap();
+        assert_eq!(e_y.eval().unwrap(), Awi::from_bool(val.or_reduce()));
+        assert_eq!(e_z.eval().unwrap(), Awi::from_bool(val.and_reduce()));
+        assert_eq!(e_p.eval().unwrap(), Awi::from_bool(val.xor_reduce()));
+        drop(epoch0);
+    }
+}
+
+#[test]
+fn dag_priority_encode_eval() {
+    use awi::*;
+    for val in eval_fixtures() {
+        let epoch0 = Epoch::new();
+        let x = LazyAwi::opaque(bw(100));
+        let is_some = dag::Awi::from_bool(x.priority_encode().is_some());
+        let inx = dag::Awi::from_usize(x.priority_encode().unwrap());
+        let (e_is_some, e_inx) = (EvalAwi::from(&is_some), EvalAwi::from(&inx));
+        x.retro_(&val).unwrap();
+        let expected = val.priority_encode();
+        assert_eq!(
+            e_is_some.eval().unwrap(),
+            Awi::from_bool(expected.is_some())
+        );
+        if let Some(expected) = expected {
+            assert_eq!(e_inx.eval().unwrap(), Awi::from_usize(expected));
+        }
+        drop(epoch0);
+    }
+}
+
+#[test]
+fn dag_one_hot_eval() {
+    use awi::*;
+    for val in eval_fixtures() {
+        let epoch0 = Epoch::new();
+        let x = LazyAwi::opaque(bw(100));
+        let is_some = dag::Awi::from_bool(x.one_hot_decode().is_some());
+        let inx = dag::Awi::from_usize(x.one_hot_decode().unwrap());
+        let (e_is_some, e_inx) = (EvalAwi::from(&is_some), EvalAwi::from(&inx));
+        x.retro_(&val).unwrap();
+        let expected = val.one_hot_decode();
+        assert_eq!(
+            e_is_some.eval().unwrap(),
+            Awi::from_bool(expected.is_some())
+        );
+        if let Some(expected) = expected {
+            assert_eq!(e_inx.eval().unwrap(), Awi::from_usize(expected));
+        }
+        drop(epoch0);
+    }
+    for inx in [0, 1, 63, 99, 100, 200] {
+        let epoch0 = Epoch::new();
+        let x = LazyAwi::opaque(bw(64));
+        let res = dag::Awi::one_hot_encode(x.to_usize(), bw(100));
+        let is_some = dag::Awi::from_bool(res.is_some());
+        let e_is_some = EvalAwi::from(&is_some);
+        let e_res = EvalAwi::from(&res.unwrap());
+        x.retro_(&Awi::from_usize(inx)).unwrap();
+        let expected = Awi::one_hot_encode(inx, bw(100));
+        assert_eq!(
+            e_is_some.eval().unwrap(),
+            Awi::from_bool(expected.is_some())
+        );
+        if let Some(expected) = expected {
+            assert_eq!(e_res.eval().unwrap(), expected);
+        }
+        drop(epoch0);
+    }
+}
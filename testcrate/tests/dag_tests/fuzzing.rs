@@ -168,7 +168,7 @@ impl Mem {
 }
 
 fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
-    let next_op = rng.next_u32() % 31;
+    let next_op = rng.next_u32() % 33;
     match next_op {
         // Lut, StaticLut
         0 => {
@@ -325,34 +325,69 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
             m.get_mut_dag(unsigned).bool_(overflow.0);
             m.get_mut_dag(signed).bool_(overflow.1);
         }
-        // Lsb, Msb
+        // Lsb, Msb, Parity, Gf2Dot
         10 => {
-            let x = m.next4().1;
+            let (w, x) = m.next4();
             let out = m.next(1);
-            if (rng.next_u32() & 1) == 0 {
-                let a = m.get_awi(x).lsb();
-                m.get_mut_awi(out).bool_(a);
-                let b = m.get_dag(x).lsb();
-                m.get_mut_dag(out).bool_(b);
-            } else {
-                let a = m.get_awi(x).msb();
-                m.get_mut_awi(out).bool_(a);
-                let b = m.get_dag(x).msb();
-                m.get_mut_dag(out).bool_(b);
+            match rng.next_u32() % 5 {
+                0 => {
+                    let a = m.get_awi(x).lsb();
+                    m.get_mut_awi(out).bool_(a);
+                    let b = m.get_dag(x).lsb();
+                    m.get_mut_dag(out).bool_(b);
+                }
+                1 => {
+                    let a = m.get_awi(x).msb();
+                    m.get_mut_awi(out).bool_(a);
+                    let b = m.get_dag(x).msb();
+                    m.get_mut_dag(out).bool_(b);
+                }
+                2 => {
+                    let a = m.get_awi(x).parity();
+                    m.get_mut_awi(out).bool_(a);
+                    let b = m.get_dag(x).parity();
+                    m.get_mut_dag(out).bool_(b);
+                }
+                3 => {
+                    let y = m.next(w);
+                    let a = m.get_awi(x).gf2_dot(&m.get_awi(y)).unwrap();
+                    m.get_mut_awi(out).bool_(a);
+                    let b = m.get_dag(x).gf2_dot(&m.get_dag(y)).unwrap();
+                    m.get_mut_dag(out).bool_(b);
+                }
+                _ => {
+                    let odd = m.get_awi(out).to_bool();
+                    let inx = (rng.next_u32() as usize) % m.get_awi(x).bw();
+                    m.get_mut_awi(x).set_parity_bit_(inx, odd).unwrap();
+                    let odd = m.get_dag(out).to_bool();
+                    m.get_mut_dag(x).set_parity_bit_(inx, odd).unwrap();
+                }
             }
         }
-        // Neg, Abs
+        // Neg, Abs, Signum
         11 => {
             let x = m.next4().1;
-            if (rng.next_u32() & 1) == 0 {
-                let neg = m.next(1);
-                let a = m.get_awi(neg).to_bool();
-                m.get_mut_awi(x).neg_(a);
-                let b = m.get_dag(neg).to_bool();
-                m.get_mut_dag(x).neg_(b);
-            } else {
-                m.get_mut_awi(x).abs_();
-                m.get_mut_dag(x).abs_();
+            match rng.next_u32() % 3 {
+                0 => {
+                    let neg = m.next(1);
+                    let a = m.get_awi(neg).to_bool();
+                    m.get_mut_awi(x).neg_(a);
+                    let b = m.get_dag(neg).to_bool();
+                    m.get_mut_dag(x).neg_(b);
+                }
+                1 => {
+                    if (rng.next_u32() & 1) == 0 {
+                        m.get_mut_awi(x).abs_();
+                        m.get_mut_dag(x).abs_();
+                    } else {
+                        m.get_mut_awi(x).unsigned_abs_();
+                        m.get_mut_dag(x).unsigned_abs_();
+                    }
+                }
+                _ => {
+                    m.get_mut_awi(x).signum_();
+                    m.get_mut_dag(x).signum_();
+                }
             }
         }
         // Funnel
@@ -442,13 +477,14 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
                 .field_from(&rhs_b, from_b.to_usize(), width_b.to_usize())
                 .unwrap();
         }
-        // Shl, Lshr, Ashr, Rotl, Rotr
+        // Shl, Lshr, Ashr, Rotl, Rotr, rcl_, rcr_, shifts to another destination,
+        // overflowing shifts
         16 => {
             let (w, x) = m.next4();
             let s = m.next_usize(w);
             let s_a = m.get_awi(s);
             let s_b = m.get_dag(s);
-            match rng.next_u32() % 5 {
+            match rng.next_u32() % 11 {
                 0 => {
                     m.get_mut_awi(x).shl_(s_a.to_usize()).unwrap();
                     m.get_mut_dag(x).shl_(s_b.to_usize()).unwrap();
@@ -469,6 +505,79 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
                     m.get_mut_awi(x).rotr_(s_a.to_usize()).unwrap();
                     m.get_mut_dag(x).rotr_(s_b.to_usize()).unwrap();
                 }
+                5 => {
+                    let c = m.next(1);
+                    let mut a = m.get_awi(c).to_bool();
+                    m.get_mut_awi(x).rcl_(&mut a);
+                    m.get_mut_awi(c).bool_(a);
+                    let mut b = m.get_dag(c).to_bool();
+                    m.get_mut_dag(x).rcl_(&mut b);
+                    m.get_mut_dag(c).bool_(b);
+                }
+                6 => {
+                    let c = m.next(1);
+                    let mut a = m.get_awi(c).to_bool();
+                    m.get_mut_awi(x).rcr_(&mut a);
+                    m.get_mut_awi(c).bool_(a);
+                    let mut b = m.get_dag(c).to_bool();
+                    m.get_mut_dag(x).rcr_(&mut b);
+                    m.get_mut_dag(c).bool_(b);
+                }
+                7 => {
+                    let out = m.next(w);
+                    let x_a = m.get_awi(x);
+                    let x_b = m.get_dag(x);
+                    match rng.next_u32() % 3 {
+                        0 => {
+                            x_a.shl_to(s_a.to_usize(), m.get_mut_awi(out)).unwrap();
+                            x_b.shl_to(s_b.to_usize(), m.get_mut_dag(out)).unwrap();
+                        }
+                        1 => {
+                            x_a.lshr_to(s_a.to_usize(), m.get_mut_awi(out)).unwrap();
+                            x_b.lshr_to(s_b.to_usize(), m.get_mut_dag(out)).unwrap();
+                        }
+                        _ => {
+                            x_a.ashr_to(s_a.to_usize(), m.get_mut_awi(out)).unwrap();
+                            x_b.ashr_to(s_b.to_usize(), m.get_mut_dag(out)).unwrap();
+                        }
+                    }
+                }
+                8 => {
+                    let out = m.next(1);
+                    let (o_a, o_b) = if (rng.next_u32() & 1) == 0 {
+                        (
+                            m.get_mut_awi(x).overflowing_shl_(s_a.to_usize()).unwrap(),
+                            m.get_mut_dag(x).overflowing_shl_(s_b.to_usize()).unwrap(),
+                        )
+                    } else {
+                        (
+                            m.get_mut_awi(x).overflowing_ashr_(s_a.to_usize()).unwrap(),
+                            m.get_mut_dag(x).overflowing_ashr_(s_b.to_usize()).unwrap(),
+                        )
+                    };
+                    m.get_mut_awi(out).bool_(o_a);
+                    m.get_mut_dag(out).bool_(o_b);
+                }
+                9 => {
+                    m.get_mut_awi(x)
+                        .ashr_round_to_zero_(s_a.to_usize())
+                        .unwrap();
+                    m.get_mut_dag(x)
+                        .ashr_round_to_zero_(s_b.to_usize())
+                        .unwrap();
+                }
+                10 => {
+                    let k = m.next_usize(w + 1);
+                    let k_a = m.get_awi(k);
+                    let k_b = m.get_dag(k);
+                    if (rng.next_u32() & 1) == 0 {
+                        m.get_mut_awi(x).rem_power_of_two_(k_a.to_usize()).unwrap();
+                        m.get_mut_dag(x).rem_power_of_two_(k_b.to_usize()).unwrap();
+                    } else {
+                        m.get_mut_awi(x).srem_power_of_two_(k_a.to_usize()).unwrap();
+                        m.get_mut_dag(x).srem_power_of_two_(k_b.to_usize()).unwrap();
+                    }
+                }
                 _ => unreachable!(),
             }
         }
@@ -492,13 +601,13 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
                 .field_to(to_b.to_usize(), &rhs_b, width_b.to_usize())
                 .unwrap();
         }
-        // Add, Sub, Rsb
+        // Add, Sub, Rsb, ConditionalAdd, AddWithCarries
         18 => {
             let (w, lhs) = m.next4();
             let rhs = m.next(w);
             let rhs_a = m.get_awi(rhs);
             let rhs_b = m.get_dag(rhs);
-            match rng.next_u32() % 3 {
+            match rng.next_u32() % 5 {
                 0 => {
                     m.get_mut_awi(lhs).add_(&rhs_a).unwrap();
                     m.get_mut_dag(lhs).add_(&rhs_b).unwrap();
@@ -511,6 +620,30 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
                     m.get_mut_awi(lhs).rsb_(&rhs_a).unwrap();
                     m.get_mut_dag(lhs).rsb_(&rhs_b).unwrap();
                 }
+                3 => {
+                    let b = m.next(1);
+                    let b_a = m.get_awi(b);
+                    let b_b = m.get_dag(b);
+                    m.get_mut_awi(lhs)
+                        .conditional_add_(&rhs_a, b_a.to_bool())
+                        .unwrap();
+                    m.get_mut_dag(lhs)
+                        .conditional_add_(&rhs_b, b_b.to_bool())
+                        .unwrap();
+                }
+                4 => {
+                    let carries = m.next(w);
+                    let mut carries_a = m.get_awi(carries);
+                    let mut carries_b = m.get_dag(carries);
+                    m.get_mut_awi(lhs)
+                        .add_with_carries_(&rhs_a, &mut carries_a)
+                        .unwrap();
+                    m.get_mut_dag(lhs)
+                        .add_with_carries_(&rhs_b, &mut carries_b)
+                        .unwrap();
+                    m.get_mut_awi(carries).copy_(&carries_a).unwrap();
+                    m.get_mut_dag(carries).copy_(&carries_b).unwrap();
+                }
                 _ => unreachable!(),
             }
         }
@@ -621,13 +754,13 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
                 _ => unreachable!(),
             }
         }
-        // CountOnes, Lz, Tz, Sig
+        // CountOnes, Lz, Tz, Sig, leading and trailing runs
         23 => {
             let x = m.next4().1;
             let x_a = m.get_awi(x);
             let x_b = m.get_dag(x);
             let out = m.next_usize(usize::MAX);
-            match rng.next_u32() % 4 {
+            match rng.next_u32() % 6 {
                 0 => {
                     m.get_mut_awi(out).usize_(x_a.count_ones());
                     m.get_mut_dag(out).usize_(x_b.count_ones());
@@ -644,6 +777,20 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
                     m.get_mut_awi(out).usize_(x_a.sig());
                     m.get_mut_dag(out).usize_(x_b.sig());
                 }
+                4 => {
+                    let b = m.next(1);
+                    let b_a = m.get_awi(b);
+                    let b_b = m.get_dag(b);
+                    m.get_mut_awi(out).usize_(x_a.leading_run(b_a.to_bool()));
+                    m.get_mut_dag(out).usize_(x_b.leading_run(b_b.to_bool()));
+                }
+                5 => {
+                    let b = m.next(1);
+                    let b_a = m.get_awi(b);
+                    let b_b = m.get_dag(b);
+                    m.get_mut_awi(out).usize_(x_a.trailing_run(b_a.to_bool()));
+                    m.get_mut_dag(out).usize_(x_b.trailing_run(b_b.to_bool()));
+                }
                 _ => unreachable!(),
             }
         }
@@ -660,17 +807,22 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
             let inx_b = m.get_dag(inx);
             m.get_mut_dag(table).lut_set(&entry_b, &inx_b).unwrap();
         }
-        // Resize
+        // Resize, clamped resize
         25 => {
             let lhs = m.next4().1;
             let rhs = m.next4().1;
             let b = m.next(1);
             let rhs_a = m.get_awi(rhs);
             let b_a = m.get_awi(b);
-            m.get_mut_awi(lhs).resize_(&rhs_a, b_a.to_bool());
             let rhs_b = m.get_dag(rhs);
             let b_b = m.get_dag(b);
-            m.get_mut_dag(lhs).resize_(&rhs_b, b_b.to_bool());
+            if (rng.next_u32() & 1) == 0 {
+                m.get_mut_awi(lhs).resize_(&rhs_a, b_a.to_bool());
+                m.get_mut_dag(lhs).resize_(&rhs_b, b_b.to_bool());
+            } else {
+                m.get_mut_awi(lhs).resize_clamped_(&rhs_a, b_a.to_bool());
+                m.get_mut_dag(lhs).resize_clamped_(&rhs_b, b_b.to_bool());
+            }
         }
         // ZeroResizeOverflow, SignResizeOverflow
         26 => {
@@ -742,7 +894,7 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
             let b_b = m.get_dag(b);
             m.get_mut_dag(lhs).mux_(&rhs_b, b_b.to_bool()).unwrap();
         }
-        // UQuo, URem, IQuo, IRem
+        // UQuo, URem, IQuo, IRem, carryless division
         29 => {
             let (w, duo) = m.next4();
             let div = m.next(w);
@@ -764,7 +916,7 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
             let mut div_b = m.get_dag(div);
             let mut quo_b = m.get_dag(quo);
             let mut rem_b = m.get_dag(rem);
-            match rng.next_u32() % 2 {
+            match rng.next_u32() % 3 {
                 0 => {
                     awi::Bits::udivide(&mut quo_a, &mut rem_a, &duo_a, &div_a).unwrap();
                     dag::Bits::udivide(&mut quo_b, &mut rem_b, &duo_b, &div_b).unwrap();
@@ -773,6 +925,17 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
                     awi::Bits::idivide(&mut quo_a, &mut rem_a, &mut duo_a, &mut div_a).unwrap();
                     dag::Bits::idivide(&mut quo_b, &mut rem_b, &mut duo_b, &mut div_b).unwrap();
                 }
+                2 => {
+                    // `duo` is not used later, so it serves as the padding
+                    quo_a.copy_(&duo_a).unwrap();
+                    quo_a
+                        .clmul_div_rem_(&div_a, &mut rem_a, &mut duo_a)
+                        .unwrap();
+                    quo_b.copy_(&duo_b).unwrap();
+                    quo_b
+                        .clmul_div_rem_(&div_b, &mut rem_b, &mut duo_b)
+                        .unwrap();
+                }
                 _ => unreachable!(),
             }
             m.get_mut_awi(out0).copy_(&quo_a).unwrap();
@@ -785,9 +948,77 @@ fn num_dag_duo(rng: &mut Xoshiro128StarStar, m: &mut Mem) {
             let lhs = m.next4().1;
             let rhs = m.next4().1;
             let rhs_a = m.get_awi(rhs);
-            m.get_mut_awi(lhs).repeat_(&rhs_a);
             let rhs_b = m.get_dag(rhs);
-            m.get_mut_dag(lhs).repeat_(&rhs_b);
+            if (rng.next_u32() & 1) == 0 {
+                m.get_mut_awi(lhs).repeat_(&rhs_a);
+                m.get_mut_dag(lhs).repeat_(&rhs_b);
+            } else {
+                m.get_mut_awi(lhs).replicate_(&rhs_a).unwrap();
+                m.get_mut_dag(lhs).replicate_(&rhs_b).unwrap();
+            }
+        }
+        // average
+        31 => {
+            let (w, lhs) = m.next4();
+            let rhs = m.next(w);
+            let b = m.next(1);
+            let rhs_a = m.get_awi(rhs);
+            let b_a = m.get_awi(b);
+            let rhs_b = m.get_dag(rhs);
+            let b_b = m.get_dag(b);
+            if (rng.next_u32() & 1) == 0 {
+                m.get_mut_awi(lhs)
+                    .average_floor_(&rhs_a, b_a.to_bool())
+                    .unwrap();
+                m.get_mut_dag(lhs)
+                    .average_floor_(&rhs_b, b_b.to_bool())
+                    .unwrap();
+            } else {
+                m.get_mut_awi(lhs)
+                    .average_ceil_(&rhs_a, b_a.to_bool())
+                    .unwrap();
+                m.get_mut_dag(lhs)
+                    .average_ceil_(&rhs_b, b_b.to_bool())
+                    .unwrap();
+            }
+        }
+        // alignment
+        32 => {
+            let (w, x) = m.next4();
+            let align = m.next(w);
+            let k = m.next_usize(w);
+            let out = m.next(1);
+            let k_a = m.get_awi(k);
+            let k_b = m.get_dag(k);
+            m.get_mut_awi(align).uone_();
+            m.get_mut_dag(align).uone_();
+            m.get_mut_awi(align).shl_(k_a.to_usize()).unwrap();
+            m.get_mut_dag(align).shl_(k_b.to_usize()).unwrap();
+            let align_a = m.get_awi(align);
+            let align_b = m.get_dag(align);
+            let (o_a, o_b) = match rng.next_u32() % 3 {
+                0 => (
+                    m.get_awi(x).is_aligned_to(&align_a).unwrap(),
+                    m.get_dag(x).is_aligned_to(&align_b).unwrap(),
+                ),
+                1 => {
+                    m.get_mut_awi(x).align_down_(&align_a).unwrap();
+                    m.get_mut_dag(x).align_down_(&align_b).unwrap();
+                    (false, dag::bool::from(false))
+                }
+                2 => {
+                    let nzbw = NonZeroUsize::new(w).unwrap();
+                    let mut pad_a = awi::Awi::zero(nzbw);
+                    let mut pad_b = dag::Awi::zero(nzbw);
+                    (
+                        m.get_mut_awi(x).align_up_(&align_a, &mut pad_a).unwrap(),
+                        m.get_mut_dag(x).align_up_(&align_b, &mut pad_b).unwrap(),
+                    )
+                }
+                _ => unreachable!(),
+            };
+            m.get_mut_awi(out).bool_(o_a);
+            m.get_mut_dag(out).bool_(o_b);
         }
         _ => unreachable!(),
     }
@@ -1,6 +1,9 @@
 use std::num::NonZeroUsize;
 
-use awint::{bw, Awi, Bits, ExtAwi};
+use awint::{
+    awint_internals::{Digit, BITS, USIZE_BITS},
+    bw, inlawi, Awi, Bits, ExtAwi, InlAwi,
+};
 use rand_xoshiro::{
     rand_core::{RngCore, SeedableRng},
     Xoshiro128StarStar,
@@ -148,3 +151,315 @@ fn awi_struct() {
     }
     assert_eq!(iter_max, N.1);
 }
+
+#[test]
+fn awi_extend_and_collect() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let w0 = ((rng.next_u32() % 137) + 1) as usize;
+        let w1 = ((rng.next_u32() % 137) + 1) as usize;
+        let mut x0 = ExtAwi::zero(bw(w0));
+        let mut x1 = ExtAwi::zero(bw(w1));
+        x0.rand_(&mut rng).unwrap();
+        x1.rand_(&mut rng).unwrap();
+        let mut expected = ExtAwi::zero(bw(w0 + w1));
+        expected.field_to(0, &x0, w0).unwrap();
+        expected.field_to(w0, &x1, w1).unwrap();
+
+        let collected: Awi = (0..w0).map(|i| x0.get(i).unwrap()).collect();
+        assert_eq!(collected.as_ref(), x0.as_ref());
+
+        let mut extended = collected.clone();
+        extended.extend((0..w1).map(|i| x1.get(i).unwrap()));
+        assert_eq!(extended.as_ref(), expected.as_ref());
+        assert!(extended.capacity().get() >= (w0 + w1));
+
+        let mut extended = collected;
+        extended.extend([x1.as_ref()]);
+        assert_eq!(extended.as_ref(), expected.as_ref());
+
+        let concat = Awi::concat_iter([x0.as_ref(), x1.as_ref()]).unwrap();
+        assert_eq!(concat.as_ref(), expected.as_ref());
+    }
+    assert!(Awi::concat_iter([]).is_none());
+    let single: Awi = [true].into_iter().collect();
+    assert_eq!(single.as_ref(), Awi::from_bool(true).as_ref());
+    let empty: Awi = core::iter::empty().collect();
+    assert_eq!(empty.as_ref(), Awi::from_bool_slice(&[]).as_ref());
+    assert_eq!(empty.as_ref(), Awi::zero(bw(1)).as_ref());
+}
+
+#[test]
+fn bool_slice_round_trip() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let w = ((rng.next_u32() % 137) + 1) as usize;
+        let mut x = ExtAwi::zero(bw(w));
+        x.rand_(&mut rng).unwrap();
+        let v = ExtAwi::bits_to_bool_vec(&x);
+        assert_eq!(v.len(), w);
+        for (i, b) in v.iter().enumerate() {
+            assert_eq!(*b, x.get(i).unwrap());
+        }
+        assert_eq!(ExtAwi::from_bool_slice(&v), x);
+        assert_eq!(Awi::bits_to_bool_vec(&x), v);
+        assert_eq!(Awi::from_bool_slice(&v).as_ref(), x.as_ref());
+    }
+    assert_eq!(ExtAwi::from_bool_slice(&[]), ExtAwi::zero(bw(1)));
+    assert_eq!(Awi::from_bool_slice(&[]), Awi::zero(bw(1)));
+}
+
+#[test]
+fn from_bits_resize() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let w0 = bw(((rng.next_u32() % 137) + 1) as usize);
+        let w1 = bw(((rng.next_u32() % 137) + 1) as usize);
+        let signed = (rng.next_u32() & 1) == 0;
+        let mut x = ExtAwi::zero(w0);
+        x.rand_(&mut rng).unwrap();
+        let mut expected = ExtAwi::zero(w1);
+        if signed {
+            expected.sign_resize_(&x);
+        } else {
+            expected.zero_resize_(&x);
+        }
+        assert_eq!(ExtAwi::from_bits_resize(&x, w1, signed), expected);
+        assert_eq!(
+            Awi::from_bits_resize(&x, w1, signed).as_ref(),
+            expected.as_ref()
+        );
+    }
+}
+
+#[test]
+fn usize_array() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let w = ((rng.next_u32() as usize) % (5 * USIZE_BITS)) + 1;
+        let mut x = ExtAwi::zero(bw(w));
+        x.rand_(&mut rng).unwrap();
+        let words: [usize; 5] = x.to_usize_array().unwrap();
+        for (j, word) in words.iter().enumerate() {
+            let mut tmp = x.clone();
+            if (j * USIZE_BITS) < w {
+                tmp.lshr_(j * USIZE_BITS).unwrap();
+                assert_eq!(*word, tmp.to_usize());
+            } else {
+                assert_eq!(*word, 0);
+            }
+        }
+        if w > USIZE_BITS {
+            assert!(x.to_usize_array::<1>().is_none());
+        }
+        let y = ExtAwi::from_usize_array(words);
+        let mut z = ExtAwi::zero(y.nzbw());
+        z.zero_resize_(&x);
+        assert_eq!(y, z);
+        assert_eq!(Awi::from_usize_array(words).as_ref(), z.as_ref());
+    }
+    assert_eq!(ExtAwi::from_usize_array([]), ExtAwi::zero(bw(1)));
+}
+
+#[test]
+fn concat_and_split_at() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let n = (rng.next_u32() % 4) as usize + 1;
+        let mut parts = vec![];
+        for _ in 0..n {
+            let mut x = ExtAwi::zero(bw(((rng.next_u32() % 137) + 1) as usize));
+            x.rand_(&mut rng).unwrap();
+            parts.push(x);
+        }
+        let refs: Vec<&Bits> = parts.iter().map(|x| x.as_ref()).collect();
+        let x = ExtAwi::concat(&refs).unwrap();
+        assert_eq!(Awi::concat(&refs).unwrap().as_ref(), x.as_ref());
+        // splitting off the parts one at a time recovers them
+        let mut rest = x.clone();
+        for (i, part) in parts.iter().enumerate() {
+            if (i + 1) == n {
+                assert_eq!(&rest, part);
+                assert!(ExtAwi::split_at(&rest, rest.bw()).is_none());
+            } else {
+                let (lo, hi) = ExtAwi::split_at(&rest, part.bw()).unwrap();
+                let (lo1, hi1) = Awi::split_at(&rest, part.bw()).unwrap();
+                assert_eq!(&lo, part);
+                assert_eq!(lo1.as_ref(), lo.as_ref());
+                assert_eq!(hi1.as_ref(), hi.as_ref());
+                rest = hi;
+            }
+        }
+        assert!(ExtAwi::split_at(&x, 0).is_none());
+    }
+    assert!(ExtAwi::concat(&[]).is_none());
+    assert!(Awi::concat(&[]).is_none());
+}
+
+#[test]
+fn lut_reduce() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let inx_w = (rng.next_u32() % 6) as usize + 1;
+        let entry_w = (rng.next_u32() % 5) as usize + 1;
+        let mut lut = ExtAwi::zero(bw(entry_w << inx_w));
+        lut.rand_(&mut rng).unwrap();
+        // make constant outputs more likely
+        if (rng.next_u32() & 1) == 0 {
+            let first = lut.clone();
+            for i in 0..(1 << inx_w) {
+                if (rng.next_u32() & 1) == 0 {
+                    lut.field(i * entry_w, &first, 0, entry_w).unwrap();
+                }
+            }
+        }
+        let mut mask = ExtAwi::zero(bw(inx_w));
+        mask.rand_(&mut rng).unwrap();
+        let mut vals = ExtAwi::zero(bw(inx_w));
+        vals.rand_(&mut rng).unwrap();
+        let (reduced, constant) = ExtAwi::lut_reduce(&lut, &mask, &vals).unwrap();
+        let (reduced1, constant1) = Awi::lut_reduce(&lut, &mask, &vals).unwrap();
+        assert_eq!(reduced.as_ref(), reduced1.as_ref());
+        assert_eq!(constant, constant1);
+        let unknown_w = inx_w - mask.count_ones();
+        assert_eq!(reduced.bw(), entry_w << unknown_w);
+        // every index consistent with the known bits must look up the same entry
+        let mut inx = ExtAwi::zero(bw(inx_w));
+        let mut entry = ExtAwi::zero(bw(entry_w));
+        let mut reduced_entry = ExtAwi::zero(bw(entry_w));
+        let mut all_same = true;
+        let mut first = None;
+        for i in 0..(1usize << inx_w) {
+            inx.usize_(i);
+            let mut reduced_inx = 0;
+            let mut j = 0;
+            let mut consistent = true;
+            for k in 0..inx_w {
+                if mask.get(k).unwrap() {
+                    consistent &= inx.get(k).unwrap() == vals.get(k).unwrap();
+                } else {
+                    reduced_inx |= (inx.get(k).unwrap() as usize) << j;
+                    j += 1;
+                }
+            }
+            if !consistent {
+                continue
+            }
+            entry.lut_(&lut, &inx).unwrap();
+            reduced_entry
+                .field_from(&reduced, reduced_inx * entry_w, entry_w)
+                .unwrap();
+            assert_eq!(entry, reduced_entry);
+            if let Some(ref first) = first {
+                all_same &= *first == entry;
+            } else {
+                first = Some(entry.clone());
+            }
+        }
+        assert_eq!(constant, all_same);
+    }
+    let lut = inlawi!(0u12);
+    assert!(ExtAwi::lut_reduce(&lut, &inlawi!(0u2), &inlawi!(0u3)).is_none());
+    assert!(ExtAwi::lut_reduce(&lut, &inlawi!(0u3), &inlawi!(0u3)).is_none());
+    assert!(ExtAwi::lut_reduce(&lut, &inlawi!(0u4), &inlawi!(0u4)).is_none());
+    assert!(Awi::lut_reduce(&lut, &inlawi!(0u2), &inlawi!(0u2)).is_some());
+}
+
+#[test]
+fn from_digit_iter() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let w = bw(((rng.next_u32() % 300) + 1) as usize);
+        let mut x = ExtAwi::zero(w);
+        x.rand_(&mut rng).unwrap();
+        let digits = x.as_slice().to_vec();
+        assert_eq!(ExtAwi::from_digit_iter(digits.iter().copied(), w), x);
+        assert_eq!(
+            Awi::from_digit_iter(digits.iter().copied(), w).as_ref(),
+            x.as_ref()
+        );
+        let mut y = ExtAwi::zero(w);
+        y.rand_(&mut rng).unwrap();
+        assert!(!y.fill_from_digit_iter_(digits.iter().copied()));
+        assert_eq!(y, x);
+        // fewer digits zero the rest
+        let len = (rng.next_u32() as usize) % (digits.len() + 1);
+        assert!(!y.fill_from_digit_iter_(digits[..len].iter().copied()));
+        let mut expected = x.clone();
+        expected.range_and_(0..(len * BITS).min(w.get())).unwrap();
+        assert_eq!(y, expected);
+        // excess digits are reported and ignored
+        let excess = digits.iter().copied().chain(Some(0));
+        assert!(y.fill_from_digit_iter_(excess));
+        assert_eq!(y, x);
+        assert_eq!(
+            ExtAwi::from_digit_iter(core::iter::repeat(Digit::MAX), w),
+            ExtAwi::umax(w)
+        );
+        // set bits beyond the bitwidth are reported and cleared
+        let last = digits.len() - 1;
+        let excess = digits[..last].iter().copied().chain(Some(Digit::MAX));
+        assert_eq!(y.fill_from_digit_iter_(excess), (w.get() & (BITS - 1)) != 0);
+        expected.copy_(&x).unwrap();
+        expected.range_or_((last * BITS)..w.get()).unwrap();
+        assert_eq!(y, expected);
+    }
+}
+
+#[test]
+fn reversed_digits() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let w = bw(((rng.next_u32() % 300) + 1) as usize);
+        let mut x = ExtAwi::zero(w);
+        x.rand_(&mut rng).unwrap();
+        let mut digits = x.as_slice().to_vec();
+        let mut out = vec![0; digits.len()];
+        x.to_reversed_digits(&mut out).unwrap();
+        digits.reverse();
+        assert_eq!(out, digits);
+        assert_eq!(ExtAwi::from_reversed_digits(&out, w).unwrap(), x);
+        assert_eq!(
+            Awi::from_reversed_digits(&out, w).unwrap().as_ref(),
+            x.as_ref()
+        );
+        // length mismatches
+        assert!(x.to_reversed_digits(&mut out[1..]).is_none());
+        assert!(ExtAwi::from_reversed_digits(&out[1..], w).is_none());
+        out.push(0);
+        assert!(x.to_reversed_digits(&mut out).is_none());
+        assert!(ExtAwi::from_reversed_digits(&out, w).is_none());
+        out.pop();
+        // set bits beyond the bitwidth
+        out[0] = Digit::MAX;
+        assert_eq!(
+            ExtAwi::from_reversed_digits(&out, w).is_none(),
+            (w.get() & (BITS - 1)) != 0
+        );
+    }
+}
+
+#[test]
+fn fold_digits() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..(N.0 / 100) {
+        let w = bw(((rng.next_u32() % 300) + 1) as usize);
+        let mut x = ExtAwi::zero(w);
+        x.rand_(&mut rng).unwrap();
+        let digits = x.fold_digits(Vec::new(), |mut v, digit| {
+            v.push(digit);
+            v
+        });
+        assert_eq!(digits, x.as_slice());
+        // the unused bits of the last digit are cleared
+        let mut last = ExtAwi::from_digit(*digits.last().unwrap());
+        last.range_and_(0..(w.get() - ((digits.len() - 1) * BITS)))
+            .unwrap();
+        assert_eq!(Some(last.to_digit()), digits.last().copied());
+        // the count of set bits is preserved
+        assert_eq!(
+            x.fold_digits(0, |acc, digit| acc + (digit.count_ones() as usize)),
+            x.count_ones()
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::{cmp::min, num::NonZeroUsize};
 
-use awint::{bw, cc, Awi, Bits, ExtAwi, FP};
+use awint::{bw, cc, Awi, Bits, ExtAwi, FpRounding, FP};
 use rand_xoshiro::{
     rand_core::{RngCore, SeedableRng},
     Xoshiro128StarStar,
@@ -179,6 +179,93 @@ fn fp_identities_inner(
     x2bw1.set_fp(fp1)?;
     x4bw0.set_fp(fp0)?;
 
+    // aligned addition, checked against an exact sum at the alignment of `pad0`
+    let w = bw((MAX_FP as usize) * 3 + 2);
+    let mut sum = FP::new(true, ExtAwi::zero(w), MAX_FP)?;
+    let mut tmp = sum.clone();
+    sum.resize_(x0bw0, x0bw0.is_negative());
+    sum.shl_(align0)?;
+    tmp.resize_(x0bw1, x0bw1.is_negative());
+    tmp.shl_(align1)?;
+    sum.add_(&tmp)?;
+    let mut expected = x0bw1.clone();
+    let o = FP::otruncate_(&mut expected, &mut sum);
+    cc!(x0bw1; x2bw1)?;
+    if o.1 {
+        assert!(FP::add_(x2bw1, x0bw0).is_none());
+        eq(x2bw1, x0bw1);
+    } else {
+        FP::add_(x2bw1, x0bw0).unwrap();
+        eq(x2bw1, &expected);
+    }
+
+    // exact multiplication
+    let prod = FP::mul(x0bw0, x0bw1)?;
+    assert_eq!(prod.signed(), x0bw0.signed() || x0bw1.signed());
+    assert_eq!(prod.fp(), fp0 + fp1);
+    let mut lhs = FP::new(true, ExtAwi::zero(prod.nzbw()), fp0 + fp1)?;
+    lhs.resize_(x0bw0, x0bw0.is_negative());
+    let mut rhs = lhs.clone();
+    rhs.resize_(x0bw1, x0bw1.is_negative());
+    let mut pad = lhs.clone();
+    lhs.mul_(&rhs, &mut pad)?;
+    assert!(lhs.const_eq(&prod)?);
+
+    // multiplication with rescaling to the type of `x2bw1`, checked against
+    // rounding with arithmetic shifts
+    let d = prod.fp() - fp1;
+    for rounding in [
+        FpRounding::Trunc,
+        FpRounding::Floor,
+        FpRounding::Ceil,
+        FpRounding::NearestEven,
+    ] {
+        let mut expected = x0bw1.clone();
+        let overflow = if d <= 0 {
+            FP::otruncate_(&mut expected, &mut prod.clone()).1
+        } else {
+            let d = d as usize;
+            let w = bw(std::cmp::max(prod.bw(), d) + 3);
+            let mut e = ExtAwi::zero(w);
+            e.resize_(&prod, prod.is_negative());
+            let mut low = e.clone();
+            low.range_and_(0..d)?;
+            let inexact = !low.is_zero();
+            let mut half = ExtAwi::zero(w);
+            half.usize_(1);
+            half.shl_(d - 1)?;
+            let tie = low == half;
+            let neg = e.msb();
+            if rounding == FpRounding::NearestEven {
+                e.add_(&half)?;
+            }
+            e.ashr_(d)?;
+            match rounding {
+                FpRounding::Trunc => {
+                    e.inc_(neg && inexact);
+                }
+                FpRounding::Floor => (),
+                FpRounding::Ceil => {
+                    e.inc_(inexact);
+                }
+                FpRounding::NearestEven => {
+                    // ties go to even
+                    let odd = e.lsb();
+                    e.dec_(!(tie && odd));
+                }
+            }
+            FP::otruncate_(&mut expected, &mut FP::new(true, e, fp1)?).1
+        };
+        cc!(x0bw1; x2bw1)?;
+        if overflow {
+            assert!(FP::mul_rescale_(x2bw1, x0bw0, x0bw1, rounding).is_none());
+            eq(x2bw1, x0bw1);
+        } else {
+            FP::mul_rescale_(x2bw1, x0bw0, x0bw1, rounding).unwrap();
+            eq(x2bw1, &expected);
+        }
+    }
+
     Some(())
 }
 
@@ -174,3 +174,104 @@ fn fp_f64() {
         }
     }
 }
+
+#[test]
+fn bits_to_float() {
+    assert_eq!(Awi::bits_to_f64(&inlawi!(0u1), true), 0.0);
+    assert_eq!(Awi::bits_to_f64(&inlawi!(1u1), true), -1.0);
+    assert_eq!(
+        Awi::bits_to_f64(&inlawi!(imin: ..1024), true),
+        -2.0f64.powi(1023)
+    );
+    assert_eq!(
+        Awi::bits_to_f64(&inlawi!(umax: ..1024), false),
+        f64::INFINITY
+    );
+    assert_eq!(
+        Awi::bits_to_f64(&inlawi!(imax: ..1024), true),
+        2.0f64.powi(1023)
+    );
+    let mut max = Awi::umax(bw(1024));
+    max.shl_(1024 - 53).unwrap();
+    assert_eq!(Awi::bits_to_f64(&max, false), f64::MAX);
+    assert_eq!(
+        Awi::bits_to_f32(&inlawi!(imin: ..128), true),
+        -2.0f32.powi(127)
+    );
+    assert_eq!(
+        Awi::bits_to_f32(&inlawi!(umax: ..128), false),
+        f32::INFINITY
+    );
+    assert_eq!(
+        ExtAwi::bits_to_f32(&inlawi!(umax: ..127), false),
+        2.0f32.powi(127)
+    );
+
+    // compare against the casts of primitives, which round to nearest even
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..N {
+        let w = NonZeroUsize::new((rng.next_u64() as usize) % 128 + 1).unwrap();
+        let val = fuzz_awi(&mut rng, w);
+        assert_eq!(Awi::bits_to_f64(&val, false), val.to_u128() as f64);
+        assert_eq!(Awi::bits_to_f64(&val, true), val.to_i128() as f64);
+        assert_eq!(Awi::bits_to_f32(&val, false), val.to_u128() as f32);
+        assert_eq!(Awi::bits_to_f32(&val, true), val.to_i128() as f32);
+        // extending should not change anything except for the exponent
+        let mut ext = ExtAwi::zero(bw(w.get() + 256));
+        ext.zero_resize_(&val);
+        ext.shl_(256).unwrap();
+        let f = ExtAwi::bits_to_f64(&ext, false);
+        assert_eq!(f, (val.to_u128() as f64) * 2.0f64.powi(256));
+    }
+}
+
+#[test]
+fn from_f64_rounding() {
+    use awint::fp::FpRounding::*;
+    assert!(Awi::from_f64(f64::INFINITY, bw(64), Trunc).is_none());
+    assert!(Awi::from_f64(f64::NEG_INFINITY, bw(64), Trunc).is_none());
+    assert!(Awi::from_f64(f64::NAN, bw(64), Trunc).is_none());
+    assert!(Awi::from_f64(0.5, bw(1), Ceil).is_none());
+    assert!(Awi::from_f64(0.5, bw(1), NearestEven).unwrap().is_zero());
+    assert!(Awi::from_f64(-0.5, bw(1), Floor).unwrap().is_umax());
+    assert!(Awi::from_f64(-0.0, bw(1), Floor).unwrap().is_zero());
+    assert!(Awi::from_f64(f64::MIN_POSITIVE / 2.0, bw(1), Trunc)
+        .unwrap()
+        .is_zero());
+    assert!(Awi::from_f64(-2.0f64.powi(1023), bw(1024), Trunc)
+        .unwrap()
+        .is_imin());
+    assert!(Awi::from_f64(2.0f64.powi(1023), bw(1024), Trunc).is_none());
+    // the round trip of the largest finite value
+    let max = ExtAwi::from_f64(f64::MAX, bw(1025), Trunc).unwrap();
+    assert_eq!(ExtAwi::bits_to_f64(&max, true), f64::MAX);
+
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..N {
+        // bias the exponent toward values that are interesting for `i128`
+        let mut x = f64::from_bits(rng.next_u64());
+        if !x.is_finite() || (x.abs() > 2.0f64.powi(130)) {
+            x = x.abs().log2() * (if rng.next_u32() & 1 == 0 { 1.0 } else { -1.0 });
+        }
+        if rng.next_u32() & 1 == 0 {
+            // halfway cases
+            x = (x * 2.0).trunc() / 2.0;
+        }
+        let w = (rng.next_u64() as usize) % 128 + 1;
+        for (round, expected) in [
+            (Trunc, x.trunc()),
+            (Floor, x.floor()),
+            (Ceil, x.ceil()),
+            (NearestEven, x.round_ties_even()),
+        ] {
+            let res = Awi::from_f64(x, bw(w), round);
+            let lo = -2.0f64.powi(w as i32 - 1);
+            let hi = 2.0f64.powi(w as i32 - 1);
+            if expected.is_finite() && (expected >= lo) && (expected < hi) {
+                assert_eq!(res.unwrap().to_i128(), expected as i128);
+            } else {
+                assert!(res.is_none());
+            }
+        }
+    }
+}
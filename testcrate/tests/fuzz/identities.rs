@@ -2,8 +2,8 @@ use core::cmp;
 use std::cmp::Ordering;
 
 use awint::{
-    awint_internals::{Digit, BITS, USIZE_BITS},
-    Bits, ExtAwi,
+    awint_internals::{Digit, BITS, MAX, USIZE_BITS},
+    bw, max_str_len, Bits, ExtAwi,
 };
 use rand_xoshiro::{
     rand_core::{RngCore, SeedableRng},
@@ -40,6 +40,39 @@ macro_rules! primitive_conversion {
     }
 }
 
+macro_rules! checked_conversion {
+    ($x0:ident, $x2:ident, $($fn_:ident, $fn_to:ident, $fn_try:ident);*;) => {
+        $(
+            // the checked conversion succeeds exactly when the value round trips
+            $x2.$fn_($x0.$fn_to());
+            match $x0.$fn_try() {
+                Some(tmp) => {
+                    assert_eq!(tmp, $x0.$fn_to());
+                    eq($x0, $x2);
+                }
+                None => ne($x0, $x2),
+            }
+        )*
+    }
+}
+
+macro_rules! saturating_conversion {
+    ($x0:ident, $($fn_try:ident, $fn_sat:ident, $ty:ident, $signed:expr);*;) => {
+        $(
+            // the saturating conversion agrees with the checked conversion when it
+            // succeeds, and otherwise clamps in the direction of the sign
+            match $x0.$fn_try() {
+                Some(tmp) => assert_eq!(tmp, $x0.$fn_sat()),
+                None => if $signed && $x0.msb() {
+                    assert_eq!($x0.$fn_sat(), $ty::MIN);
+                } else {
+                    assert_eq!($x0.$fn_sat(), $ty::MAX);
+                },
+            }
+        )*
+    }
+}
+
 /// This inner function has `x0` and `x1` as `&Bits`, which eliminates the
 /// chance of accidentally overwriting them and causing false positives.
 fn identities_inner(
@@ -130,6 +163,77 @@ fn identities_inner(
     x3.inc_(true);
     eq(x2, x3);
 
+    // runs of equal bits are contiguous, maximal, and cover the bitwidth
+    let mut end = 0;
+    let mut prev: Option<bool> = None;
+    for (range, value) in x0.runs() {
+        assert_eq!(range.start, end);
+        assert!(range.start < range.end);
+        assert_ne!(prev, Some(value));
+        for i in range.clone() {
+            assert_eq!(x0.get(i)?, value);
+        }
+        end = range.end;
+        prev = Some(value);
+    }
+    assert_eq!(end, w);
+
+    // population count of a range
+    let range = cmp::min(s0, s1)..cmp::max(s0, s1);
+    let ones = range.clone().filter(|i| x0.get(*i).unwrap()).count();
+    assert_eq!(x0.count_ones_in_range(range), Some(ones));
+    assert_eq!(x0.count_ones_in_range(0..w), Some(x0.count_ones()));
+    assert_eq!(x0.count_ones_in_range(s0..(w + 1)), None);
+
+    // scanning for set bits from an arbitrary index
+    let next = (s0..w).find(|i| x0.get(*i).unwrap());
+    assert_eq!(x0.next_set_bit_from(s0), next);
+    let prev = (0..=s0).rev().find(|i| x0.get(*i).unwrap());
+    assert_eq!(x0.prev_set_bit_from(s0), prev);
+    assert_eq!(x0.next_set_bit_from(w), None);
+    assert_eq!(x0.prev_set_bit_from(w), None);
+    assert_eq!(x0.priority_encode(), x0.prev_set_bit_from(w - 1));
+
+    // binary searching for the boundary of a monotone predicate
+    if w <= 257 {
+        x2.find_monotone_boundary(|x| x.ule(x0).unwrap())?;
+        eq(x2, x0);
+        assert!(x2.find_monotone_boundary(|x| x.ult(x0).unwrap()).is_some() != x0.is_zero());
+    }
+
+    // one hot decoding
+    let one_hot = if x0.count_ones() == 1 {
+        x0.next_set_bit_from(0)
+    } else {
+        None
+    };
+    assert_eq!(x0.one_hot_decode(), one_hot);
+    x2.zero_();
+    x2.set(s0, true)?;
+    assert_eq!(x2.one_hot_decode(), Some(s0));
+
+    // lane-wise operations
+    let lane_bw = (s0 + 1..=w).find(|l| w.wrapping_rem(*l) == 0).unwrap();
+    x2.copy_(x0)?;
+    let mut lane = 0;
+    x2.lanes_map_(bw(lane_bw), |x| {
+        x.inc_(true);
+        x.rotl_(lane % lane_bw).unwrap();
+        lane += 1;
+    })?;
+    assert_eq!(lane, w / lane_bw);
+    let mut tmp = ExtAwi::zero(bw(lane_bw));
+    for lane in 0..(w / lane_bw) {
+        tmp.field_from(x0, lane * lane_bw, lane_bw)?;
+        tmp.inc_(true);
+        tmp.rotl_(lane % lane_bw)?;
+        x3.field_to(lane * lane_bw, &tmp, lane_bw)?;
+    }
+    eq(x2, x3);
+    if lane_bw != w {
+        assert!(x2.lanes_map_(bw(w - 1), |_| ()).is_none() != (w == 2));
+    }
+
     // Absolute value
     x2.copy_(x0)?;
     x2.abs_();
@@ -142,6 +246,71 @@ fn identities_inner(
         x3.neg_(true);
         eq(x3, x2);
     }
+    assert_eq!(x0.is_negative(), x0.msb());
+    x3.copy_(x0)?;
+    x3.wrapping_neg_();
+    x2.copy_(x0)?;
+    x2.neg_(true);
+    eq(x2, x3);
+    x2.abs_();
+    // unsigned absolute value, checked against `ExtAwi` with an extra bit
+    x3.copy_(x0)?;
+    x3.unsigned_abs_();
+    eq(x2, x3);
+    let mut wide = ExtAwi::zero(bw(w + 1));
+    wide.sign_resize_(x0);
+    wide.abs_();
+    assert!(!wide.msb());
+    let mut narrow = ExtAwi::zero(x3.nzbw());
+    assert!(!narrow.zero_resize_(&wide));
+    eq(x3, &narrow);
+
+    // averages, checked against `ExtAwi` with an extra bit
+    for signed in [false, true] {
+        let mut wide = ExtAwi::zero(bw(w + 1));
+        let mut wide1 = ExtAwi::zero(bw(w + 1));
+        wide.resize_(x0, signed && x0.msb());
+        wide1.resize_(x1, signed && x1.msb());
+        wide.add_(&wide1)?;
+        for ceil in [false, true] {
+            wide1.copy_(&wide)?;
+            wide1.inc_(ceil);
+            if signed {
+                wide1.ashr_(1)?;
+            } else {
+                wide1.lshr_(1)?;
+            }
+            x2.copy_(x0)?;
+            if ceil {
+                x2.average_ceil_(x1, signed)?;
+            } else {
+                x2.average_floor_(x1, signed)?;
+            }
+            assert!(!narrow.zero_resize_(&wide1) || signed);
+            narrow.resize_(&wide1, signed);
+            eq(x2, &narrow);
+        }
+    }
+
+    // digit addition and subtraction, checked against `ExtAwi` with an extra
+    // digit
+    let mut wide = ExtAwi::zero(bw(w + BITS + 1));
+    let mut wide1 = ExtAwi::zero(bw(w + BITS + 1));
+    wide1.digit_(d0);
+    for sub in [false, true] {
+        wide.zero_resize_(x0);
+        x2.copy_(x0)?;
+        let oflow = if sub {
+            wide.sub_(&wide1)?;
+            x2.digit_sub_(d0)
+        } else {
+            wide.add_(&wide1)?;
+            x2.digit_add_(d0)
+        };
+        let mut narrow = ExtAwi::zero(x2.nzbw());
+        assert_eq!(narrow.zero_resize_(&wide), oflow);
+        eq(x2, &narrow);
+    }
 
     // -(x0 + -x1) == (-x0 + x1)
     x2.copy_(x0)?;
@@ -167,6 +336,189 @@ fn identities_inner(
     x4.rotr_(s0).unwrap();
     eq(x0, x4);
 
+    // swapping bits and ranges
+    x2.copy_(x0)?;
+    x2.swap_bits_(s0, s1)?;
+    assert_eq!(x2.get(s0)?, x0.get(s1)?);
+    assert_eq!(x2.get(s1)?, x0.get(s0)?);
+    x2.swap_bits_(s0, s1)?;
+    eq(x0, x2);
+    assert!(x2.swap_bits_(s0, w).is_none());
+    let lo = cmp::min(s0, s1);
+    let hi = cmp::max(s0, s1);
+    let len = cmp::min(hi - lo, w - hi);
+    x2.copy_(x0)?;
+    x2.swap_ranges_(lo..(lo + len), hi..(hi + len))?;
+    x3.copy_(x0)?;
+    x3.field(lo, x0, hi, len)?;
+    x3.field(hi, x0, lo, len)?;
+    eq(x2, x3);
+    if len != 0 {
+        assert!(x2
+            .swap_ranges_(lo..(lo + len), hi..(hi + len - 1))
+            .is_none());
+    }
+    if (hi - lo) >= 2 {
+        // overlapping
+        assert!(x2.swap_ranges_(lo..hi, (lo + 1)..(hi + 1)).is_none());
+    }
+
+    // reversing a range
+    x2.copy_(x0)?;
+    x2.reverse_range_(lo..hi)?;
+    for i in 0..w {
+        let j = if (lo..hi).contains(&i) {
+            hi - 1 - (i - lo)
+        } else {
+            i
+        };
+        assert_eq!(x2.get(i)?, x0.get(j)?);
+    }
+    x2.reverse_range_(lo..hi)?;
+    eq(x0, x2);
+    x2.reverse_range_(0..w)?;
+    x3.copy_(x0)?;
+    x3.rev_();
+    eq(x2, x3);
+    assert!(x2.reverse_range_(lo..(w + 1)).is_none());
+
+    // shifts by whole digits
+    let n = s0 / BITS;
+    x2.copy_(x0)?;
+    x3.copy_(x0)?;
+    x2.shl_digits_(n)?;
+    x3.shl_(n * BITS)?;
+    eq(x2, x3);
+    x2.copy_(x0)?;
+    x3.copy_(x0)?;
+    x2.lshr_digits_(n)?;
+    x3.lshr_(n * BITS)?;
+    eq(x2, x3);
+    x2.copy_(x0)?;
+    x3.copy_(x0)?;
+    x2.rotl_digits_(n)?;
+    x3.rotl_(n * BITS)?;
+    eq(x2, x3);
+    assert!(x2.shl_digits_((w / BITS) + 1).is_none());
+    assert!(x2.lshr_digits_(usize::MAX).is_none());
+
+    // rotation through carry is rotation of the concatenation of the carry and
+    // `self`
+    let carry = x1.lsb();
+    let mut wide = ExtAwi::zero(bw(w + 1));
+    wide.zero_resize_(x0);
+    wide.set(w, carry)?;
+    wide.rotl_(1)?;
+    let mut c = carry;
+    x4.rcl_(&mut c);
+    let mut narrow = ExtAwi::zero(x4.nzbw());
+    narrow.zero_resize_(&wide);
+    eq(x4, &narrow);
+    assert_eq!(c, wide.msb());
+    x4.rcr_(&mut c);
+    eq(x0, x4);
+    assert_eq!(c, carry);
+
+    // shifting into another destination
+    x0.shl_to(s0, x3)?;
+    x2.copy_(x0)?;
+    x2.shl_(s0)?;
+    eq(x2, x3);
+    x0.lshr_to(s0, x3)?;
+    x2.copy_(x0)?;
+    x2.lshr_(s0)?;
+    eq(x2, x3);
+    x0.ashr_to(s0, x3)?;
+    x2.copy_(x0)?;
+    x2.ashr_(s0)?;
+    eq(x2, x3);
+    x3.copy_(x1)?;
+    assert!(x0.shl_to(w, x3).is_none());
+    assert!(x0.lshr_to(w, x3).is_none());
+    assert!(x0.ashr_to(w, x3).is_none());
+    eq(x1, x3);
+
+    // overflowing shifts lose bits iff shifting back does not restore the value
+    x2.copy_(x0)?;
+    let oflow = x2.overflowing_shl_(s0)?;
+    x3.copy_(x2)?;
+    x3.lshr_(s0)?;
+    assert_eq!(oflow, !x3.const_eq(x0)?);
+    x2.copy_(x0)?;
+    let inexact = x2.overflowing_ashr_(s0)?;
+    x3.copy_(x2)?;
+    x3.shl_(s0)?;
+    assert_eq!(inexact, !x3.const_eq(x0)?);
+    assert!(x2.overflowing_shl_(w).is_none());
+    assert!(x2.overflowing_ashr_(w).is_none());
+    // rounding toward zero matches signed division by a power of two
+    if s0 == w - 1 {
+        // `2^s0` is not representable as a positive signed value
+        if x0.is_imin() {
+            x5.umax_();
+        } else {
+            x5.zero_();
+        }
+    } else {
+        x3.copy_(x0)?;
+        x4.uone_();
+        x4.shl_(s0)?;
+        Bits::idivide(x5, x2, x3, x4)?;
+    }
+    x2.copy_(x0)?;
+    x2.ashr_round_to_zero_(s0)?;
+    eq(x2, x5);
+    assert!(x2.ashr_round_to_zero_(w).is_none());
+    // the power of two remainders match the division remainders
+    let k = s1.min(w - 1);
+    x3.copy_(x0)?;
+    x4.uone_();
+    x4.shl_(k)?;
+    Bits::udivide(x2, x5, x3, x4)?;
+    x2.copy_(x0)?;
+    x2.rem_power_of_two_(k)?;
+    eq(x2, x5);
+    if k != w - 1 {
+        Bits::idivide(x2, x5, x3, x4)?;
+        x2.copy_(x0)?;
+        x2.srem_power_of_two_(k)?;
+        eq(x2, x5);
+    }
+    x2.copy_(x0)?;
+    x2.rem_power_of_two_(w)?;
+    x2.srem_power_of_two_(w)?;
+    eq(x2, x0);
+    assert!(x2.rem_power_of_two_(w + 1).is_none());
+    assert!(x2.srem_power_of_two_(w + 1).is_none());
+
+    // alignment
+    x3.uone_();
+    x3.shl_(s1)?;
+    x2.copy_(x0)?;
+    x2.align_down_(x3)?;
+    x4.copy_(x0)?;
+    x4.rem_power_of_two_(s1)?;
+    assert_eq!(x0.is_aligned_to(x3)?, x4.is_zero());
+    x4.rsb_(x0)?;
+    eq(x2, x4);
+    assert!(x2.is_aligned_to(x3)?);
+    x2.copy_(x0)?;
+    let oflow = x2.align_up_(x3, x4)?;
+    assert!(x2.is_aligned_to(x3)?);
+    // `x2 - x0` is the distance rounded up, which is less than `2^s1`
+    x4.copy_(x2)?;
+    x4.sub_(x0)?;
+    x5.copy_(x4)?;
+    x5.rem_power_of_two_(s1)?;
+    eq(x4, x5);
+    assert_eq!(oflow, x2.ult(x0)?);
+    if s1 != 0 {
+        x3.inc_(true);
+        assert!(x0.is_aligned_to(x3).is_none());
+        assert!(x2.align_down_(x3).is_none());
+        assert!(x2.align_up_(x3, x4).is_none());
+    }
+
     // range_and_
     x2.umax_();
     x2.shl_(s0).unwrap();
@@ -214,6 +566,74 @@ fn identities_inner(
     x4.range_xor_(s0..s1).unwrap();
     eq(x2, x4);
 
+    // range_mask_ and umask_
+    x2.zero_();
+    x2.range_or_(s0..s1).unwrap();
+    x4.copy_(x0)?;
+    x4.range_mask_(s0..s1).unwrap();
+    eq(x2, x4);
+    if s0 != 0 {
+        x2.umax_();
+        x2.lshr_(w - s0).unwrap();
+    } else {
+        x2.zero_();
+    }
+    x4.copy_(x0)?;
+    x4.umask_(s0).unwrap();
+    eq(x2, x4);
+    x4.umask_(w).unwrap();
+    assert!(x4.is_umax());
+    assert!(x4.umask_(w + 1).is_none());
+    assert!(x4.range_mask_(0..(w + 1)).is_none());
+
+    // fill_digits
+    let start_d = cmp::min(s0, s1) / BITS;
+    let end_d = cmp::max(s0, s1) / BITS;
+    x2.copy_(x0)?;
+    x4.copy_(x0)?;
+    x2.range_or_((start_d * BITS)..cmp::min(w, end_d * BITS))
+        .unwrap();
+    x4.fill_digits(true, start_d..end_d).unwrap();
+    eq(x2, x4);
+    x2.copy_(x0)?;
+    x3.umax_();
+    x3.range_xor_((start_d * BITS)..cmp::min(w, end_d * BITS))
+        .unwrap();
+    x2.and_(x3)?;
+    x4.copy_(x0)?;
+    x4.fill_digits(false, start_d..end_d).unwrap();
+    eq(x2, x4);
+    assert!(x4
+        .fill_digits(true, end_d..start_d.wrapping_sub(1))
+        .is_none());
+    assert!(x4.fill_digits(true, 0..(x4.total_digits() + 1)).is_none());
+
+    // digit_at and set_digit_
+    assert_eq!(x0.digit_at(start_d), Some(x0.get_digit(start_d * BITS)));
+    assert!(x0.digit_at(x0.total_digits()).is_none());
+    x2.copy_(x0)?;
+    x2.field(
+        start_d * BITS,
+        x1,
+        start_d * BITS,
+        cmp::min(BITS, w - (start_d * BITS)),
+    )?;
+    x4.copy_(x0)?;
+    x4.set_digit_(start_d, x1.get_digit(start_d * BITS))?;
+    eq(x2, x4);
+    x4.copy_(x0)?;
+    x4.set_digit_(x4.total_digits() - 1, MAX)?;
+    x2.copy_(x0)?;
+    x3.umax_();
+    x2.field(
+        (x2.total_digits() - 1) * BITS,
+        x3,
+        0,
+        w - ((x2.total_digits() - 1) * BITS),
+    )?;
+    eq(x2, x4);
+    assert!(x4.set_digit_(x4.total_digits(), 0).is_none());
+
     // digit or assign
     x2.copy_(x0)?;
     x3.copy_(x0)?;
@@ -249,6 +669,25 @@ fn identities_inner(
     x3.range_and_(s0..w).unwrap();
     assert_eq!(x0.count_ones(), x2.count_ones() + x3.count_ones());
 
+    // parity
+    assert_eq!(x0.parity(), (x0.count_ones() & 1) == 1);
+    assert_eq!(x0.xor_reduce(), x0.parity());
+    assert_eq!(x0.or_reduce(), x0.count_ones() != 0);
+    assert_eq!(x0.and_reduce(), x0.count_ones() == w);
+    assert_eq!(x0.parity(), x2.parity() != x3.parity());
+    x2.copy_(x0)?;
+    x2.set_parity_bit_(s0, false).unwrap();
+    assert!(!x2.parity());
+    x2.set_parity_bit_(s0, true).unwrap();
+    assert!(x2.parity());
+    x2.set(s0, x0.get(s0).unwrap()).unwrap();
+    eq(x0, x2);
+    assert!(x2.set_parity_bit_(w, false).is_none());
+    x2.copy_(x0)?;
+    x2.and_(x1)?;
+    assert_eq!(x0.gf2_dot(x1)?, x2.parity());
+    assert_eq!(x0.gf2_dot(x0)?, x0.parity());
+
     // leading and trailing zeros
     if x0.lz() + x0.tz() >= w {
         assert!(x0.is_zero());
@@ -275,6 +714,37 @@ fn identities_inner(
     x2.mux_(x1, true)?;
     assert_eq!(x2, x1);
 
+    // cswap
+    x2.copy_(x0)?;
+    x3.copy_(x1)?;
+    Bits::cswap(x2, x3, false)?;
+    eq(x0, x2);
+    eq(x1, x3);
+    Bits::cswap(x2, x3, true)?;
+    eq(x1, x2);
+    eq(x0, x3);
+    x2.copy_(x0)?;
+    x2.conditional_assign_(x1, false)?;
+    eq(x0, x2);
+    x2.conditional_assign_(x1, true)?;
+    eq(x1, x2);
+    x2.copy_(x0)?;
+    x2.conditional_add_(x1, false)?;
+    eq(x0, x2);
+    x2.conditional_add_(x1, true)?;
+    x3.copy_(x0)?;
+    x3.add_(x1)?;
+    eq(x2, x3);
+    x2.copy_(x0)?;
+    x2.add_with_carries_(x1, x4)?;
+    eq(x2, x3);
+    // the sum is the XOR of the inputs and the carries
+    x5.copy_(x0)?;
+    x5.xor_(x1)?;
+    x5.xor_(x4)?;
+    eq(x2, x5);
+    assert!(!x4.get(0)?);
+
     // reversal
     x2.copy_(x0)?;
     let lz = x2.lz();
@@ -285,6 +755,30 @@ fn identities_inner(
     x2.rev_();
     eq(x0, x2);
 
+    // chunk reversal
+    let chunk = s0 + 1;
+    x2.copy_(x0)?;
+    let n = w / chunk;
+    if (n * chunk) == w {
+        x2.reverse_chunks_(bw(chunk)).unwrap();
+        for i in 0..n {
+            x3.field((n - 1 - i) * chunk, x0, i * chunk, chunk).unwrap();
+        }
+        eq(x2, x3);
+        x2.reverse_chunks_(bw(chunk)).unwrap();
+        eq(x0, x2);
+    } else {
+        assert!(x2.reverse_chunks_(bw(chunk)).is_none());
+        eq(x0, x2);
+    }
+    x2.reverse_chunks_(bw(1)).unwrap();
+    x3.copy_(x0)?;
+    x3.rev_();
+    eq(x2, x3);
+    x2.copy_(x0)?;
+    x2.reverse_chunks_(x0.nzbw()).unwrap();
+    eq(x0, x2);
+
     // comparison
     if x0.const_eq(x1)? {
         assert!(!x0.const_ne(x1)?);
@@ -307,6 +801,19 @@ fn identities_inner(
         assert!(x0.uge(x1)?);
         assert!(x0.ugt(x1)?);
     }
+    assert_eq!(x0.leading_run(false), x0.lz());
+    assert_eq!(x0.trailing_run(false), x0.tz());
+    x2.copy_(x0)?;
+    x2.not_();
+    assert_eq!(x0.leading_run(true), x2.lz());
+    assert_eq!(x0.trailing_run(true), x2.tz());
+    x2.copy_(x1)?;
+    x2.shl_(s0)?;
+    assert_eq!(x0.ult_shl(x1, s0)?, x0.ult(x2)?);
+    assert!(!x2.ult_shl(x1, s0)?);
+    x2.dec_(false);
+    assert_eq!(x2.ult_shl(x1, s0)?, !x2.is_umax());
+    assert!(x0.ult_shl(x1, x0.bw()).is_none());
     if x0.ilt(x1)? {
         assert!(x0.const_ne(x1)?);
         assert!(!x0.const_eq(x1)?);
@@ -385,6 +892,36 @@ fn identities_inner(
         isize_, to_isize, USIZE_BITS;
         digit_, to_digit, BITS;
     );
+    checked_conversion!(
+        x0, x2,
+        u8_, to_u8, try_to_u8;
+        u16_, to_u16, try_to_u16;
+        u32_, to_u32, try_to_u32;
+        u64_, to_u64, try_to_u64;
+        u128_, to_u128, try_to_u128;
+        usize_, to_usize, try_to_usize;
+        i8_, to_i8, try_to_i8;
+        i16_, to_i16, try_to_i16;
+        i32_, to_i32, try_to_i32;
+        i64_, to_i64, try_to_i64;
+        i128_, to_i128, try_to_i128;
+        isize_, to_isize, try_to_isize;
+    );
+    saturating_conversion!(
+        x0,
+        try_to_u8, saturating_to_u8, u8, false;
+        try_to_u16, saturating_to_u16, u16, false;
+        try_to_u32, saturating_to_u32, u32, false;
+        try_to_u64, saturating_to_u64, u64, false;
+        try_to_u128, saturating_to_u128, u128, false;
+        try_to_usize, saturating_to_usize, usize, false;
+        try_to_i8, saturating_to_i8, i8, true;
+        try_to_i16, saturating_to_i16, i16, true;
+        try_to_i32, saturating_to_i32, i32, true;
+        try_to_i64, saturating_to_i64, i64, true;
+        try_to_i128, saturating_to_i128, i128, true;
+        try_to_isize, saturating_to_isize, isize, true;
+    );
 
     // multiplication and left shift
     x2.uone_();
@@ -442,6 +979,28 @@ fn identities_inner(
     x4.mul_add_(x0, x1)?;
     eq(x2, x4);
 
+    // checked signed multiplication, with operands that are reduced to test both
+    // exact and truncated products
+    for i in 0..2 {
+        x2.copy_(x0)?;
+        x5.copy_(x1)?;
+        if i == 1 {
+            x2.ashr_(s0)?;
+            x5.ashr_(w - 1 - s0)?;
+        }
+        let mut lhs = ExtAwi::zero(x2.nzbw());
+        lhs.copy_(x2)?;
+        let mut rhs = ExtAwi::zero(x5.nzbw());
+        rhs.copy_(x5)?;
+        let mut wide = ExtAwi::zero(bw(2 * w));
+        wide.arb_imul_add_(&mut lhs, &mut rhs);
+        x4.copy_(x2)?;
+        x4.mul_(x5, x3)?;
+        let truncated = x2.checked_signed_mul_(x5, x3)?;
+        eq(x2, x4);
+        assert_eq!(truncated, x4.sign_resize_(&wide));
+    }
+
     // unsigned division and logical right shift
     x2.uone_();
     x2.shl_(s0)?;
@@ -470,6 +1029,28 @@ fn identities_inner(
     }
     // the signed version is handled in `identities`
 
+    // carryless division compared against bitwise long division over GF(2)
+    x2.copy_(x0)?;
+    if x1.is_zero() {
+        assert!(x2.clmul_div_rem_(x1, x3, x4).is_none());
+    } else if w <= 257 {
+        x2.clmul_div_rem_(x1, x3, x4)?;
+        let div_deg = x1.sig() - 1;
+        x4.copy_(x0)?;
+        x5.zero_();
+        for i in (div_deg..w).rev() {
+            if x4.get(i)? {
+                for j in 0..=div_deg {
+                    let tmp = x4.get(i - div_deg + j)? ^ x1.get(j)?;
+                    x4.set(i - div_deg + j, tmp)?;
+                }
+                x5.set(i - div_deg, true)?;
+            }
+        }
+        eq(x2, x5);
+        eq(x3, x4);
+    }
+
     // const string serialization
     if w <= 257 {
         let radix = ((rng.next_u32() % 35) + 2) as u8;
@@ -497,6 +1078,19 @@ fn identities_inner(
         }
         x2.bytes_radix_(sign, &string, radix, x3, x4).unwrap();
         eq(x0, x2);
+        if sign.is_none() {
+            x2.from_bytes_radix_(&string, radix).unwrap();
+            eq(x0, x2);
+            // formatting without a scratchpad, which does not use leading zeros except
+            // for the single zero of a zero value
+            let mut buf = vec![0u8; max_str_len(w, radix)];
+            let len = x0.to_bytes_radix_no_pad(radix, &mut buf).unwrap();
+            let unpadded = ExtAwi::bits_to_vec_radix(x0, false, radix, false, 1).unwrap();
+            assert_eq!(&buf[..len], &unpadded[..]);
+            assert!(x0
+                .to_bytes_radix_no_pad(radix, &mut buf[..(len - 1)])
+                .is_none());
+        }
     }
 
     Some(())
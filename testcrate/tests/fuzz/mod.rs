@@ -18,6 +18,7 @@ fn check_invariants(x: &Bits) {
     if x.extra() != 0 && (x.last() & (Digit::MAX << x.extra())) != 0 {
         panic!("unused bits are set");
     }
+    assert!(x.is_canonical());
 }
 
 /// Checks for equality and that invariants are being kept
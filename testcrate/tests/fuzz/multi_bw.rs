@@ -1,7 +1,7 @@
 use core::cmp;
 use std::cmp::min;
 
-use awint::{bw, cc, Bits, ExtAwi, InlAwi};
+use awint::{awint_internals::BITS, bw, cc, Bits, ExtAwi, InlAwi};
 use rand_xoshiro::{
     rand_core::{RngCore, SeedableRng},
     Xoshiro128StarStar,
@@ -69,6 +69,48 @@ fn multi_bw_inner(
         eq(x0bw0, x1bw0)
     }
 
+    // clamped resize assign
+    x1bw1.resize_clamped_(x0bw0, b);
+    if bw1 < bw0 {
+        // the maximum and minimum values representable in `bw1` bits
+        let s = bw0 - bw1 + usize::from(b);
+        if s < bw0 {
+            x2bw0.umax_();
+            x2bw0.lshr_(s)?;
+        } else {
+            x2bw0.zero_();
+        }
+        x3bw0.copy_(x2bw0)?;
+        if b {
+            x3bw0.not_();
+        } else {
+            x3bw0.zero_();
+        }
+        let expected = if b {
+            if x0bw0.igt(x2bw0)? {
+                &*x2bw0
+            } else if x0bw0.ilt(x3bw0)? {
+                &*x3bw0
+            } else {
+                x0bw0
+            }
+        } else if x0bw0.ugt(x2bw0)? {
+            &*x2bw0
+        } else {
+            x0bw0
+        };
+        if b {
+            x1bw0.sign_resize_(x1bw1);
+        } else {
+            x1bw0.zero_resize_(x1bw1);
+        }
+        eq(x1bw0, expected);
+    } else {
+        // widening is never lossy
+        x2bw1.resize_(x0bw0, b && x0bw0.msb());
+        eq(x1bw1, x2bw1);
+    }
+
     // bitfields
     let width = (rng.next_u32() as usize) % (cmp::min(bw0, bw1) + 1);
     let from = if bw0 - width == 0 {
@@ -111,6 +153,34 @@ fn multi_bw_inner(
     x1bw1.field(to, x0bw0, from, 1).unwrap();
     x2bw1.field_bit(to, x0bw0, from).unwrap();
     eq(x1bw1, x2bw1);
+    // digit aligned fielding
+    let to_aligned = to - (to % BITS);
+    let from_aligned = from - (from % BITS);
+    x1bw1.field(to_aligned, x0bw0, from_aligned, width).unwrap();
+    x2bw1
+        .copy_digit_aligned_(to_aligned, x0bw0, from_aligned, width)
+        .unwrap();
+    eq(x1bw1, x2bw1);
+    if (to % BITS) != 0 || (from % BITS) != 0 {
+        assert!(x2bw1.copy_digit_aligned_(to, x0bw0, from, width).is_none());
+    }
+
+    // range comparisons
+    if bw0 <= bw1 {
+        let start = (rng.next_u32() as usize) % (bw1 - bw0 + 1);
+        x1bw0.field_from(x0bw1, start, bw0).unwrap();
+        assert!(x0bw1.range_eq(start..(start + bw0), x1bw0).unwrap());
+        assert_eq!(
+            x0bw1.range_eq(start..(start + bw0), x0bw0).unwrap(),
+            x1bw0.const_eq(x0bw0).unwrap()
+        );
+        assert_eq!(
+            x0bw1.range_ult(start..(start + bw0), x0bw0).unwrap(),
+            x1bw0.ult(x0bw0).unwrap()
+        );
+        assert!(!x0bw1.range_ult(start..(start + bw0), x1bw0).unwrap());
+    }
+    assert!(x0bw1.range_eq(0..bw1, x0bw0).is_none() == (bw0 != bw1));
 
     // repeat_
     let mut to = 0;
@@ -123,6 +193,9 @@ fn multi_bw_inner(
     }
     x2bw1.repeat_(x0bw0);
     eq(x1bw1, x2bw1);
+    x2bw1.zero_();
+    x2bw1.replicate_(x0bw0).unwrap();
+    eq(x1bw1, x2bw1);
 
     // arbitrary width multiplication
     x1bw0.copy_(x0bw0)?;
@@ -186,6 +259,63 @@ fn multi_bw_inner(
                 assert!(bw1 < bw0);
             }
         }
+
+        // `bits_to_string_grouped` should be the same as `bits_to_string_radix`
+        // with separators inserted
+        let group = (rng.next_u32() % 5) as usize;
+        let grouped = ExtAwi::bits_to_string_grouped(
+            x0bw0,
+            sign.is_some(),
+            radix,
+            (tmp_rng & 0b100) != 0,
+            group,
+            '_',
+        )
+        .unwrap();
+        let mut expected =
+            ExtAwi::bits_to_string_radix(x0bw0, sign.is_some(), radix, (tmp_rng & 0b100) != 0, 1)
+                .unwrap();
+        if group != 0 {
+            let digits: Vec<char> = expected.chars().collect();
+            expected.clear();
+            for (i, c) in digits.iter().enumerate() {
+                expected.push(*c);
+                let rem = digits.len() - 1 - i;
+                if (rem != 0) && (rem.checked_rem(group) == Some(0)) {
+                    expected.push('_');
+                }
+            }
+        }
+        if sign == Some(true) {
+            expected.insert(0, '-');
+        }
+        assert_eq!(grouped, expected);
+    }
+
+    // widening summation
+    x1bw0.copy_(x0bw0)?;
+    x1bw0.rotl_(bw0 / 2)?;
+    x1bw0.xor_(x2bw0)?;
+    let mut lhs = ExtAwi::zero(bw(bw0 + 1));
+    lhs.zero_resize_(x0bw0);
+    let mut rhs = ExtAwi::zero(bw(bw0 + 1));
+    rhs.zero_resize_(x1bw0);
+    let mut wide = ExtAwi::zero(bw(bw0 + 1));
+    wide.widening_add_(x0bw0, x1bw0)?;
+    let mut expected = lhs.clone();
+    expected.add_(&rhs)?;
+    eq(&wide, &expected);
+    wide.widening_sub_(x0bw0, x1bw0)?;
+    expected.copy_(&lhs)?;
+    expected.sub_(&rhs)?;
+    eq(&wide, &expected);
+    // the sign bit should agree with the unsigned comparison
+    assert_eq!(wide.msb(), x0bw0.ult(x1bw0)?);
+    if bw1 != (bw0 + 1) {
+        assert!(x1bw1.widening_add_(x0bw0, x1bw0).is_none());
+    }
+    if bw0 != bw1 {
+        assert!(wide.widening_sub_(x0bw0, x0bw1).is_none());
     }
 
     Some(())
@@ -265,3 +395,81 @@ pub fn u8_slice() {
         }
     }
 }
+
+#[test]
+pub fn dot_product() {
+    use awint::Awi;
+    let rng = &mut Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..1000 {
+        let n = ((rng.next_u32() as usize) % 16) + 1;
+        let mut terms = vec![];
+        let mut expected = 0u128;
+        let mut max_w = 0;
+        for _ in 0..n {
+            // keep the total within `u128`
+            let w = ((rng.next_u32() as usize) % 60) + 1;
+            max_w = cmp::max(max_w, w);
+            let mut lhs = ExtAwi::zero(bw(w));
+            let mut rhs = ExtAwi::zero(bw(w));
+            lhs.rand_(rng).unwrap();
+            rhs.rand_(rng).unwrap();
+            expected += lhs.to_u128() * rhs.to_u128();
+            terms.push((lhs, rhs));
+        }
+        let refs: Vec<(&Bits, &Bits)> = terms
+            .iter()
+            .map(|(l, r)| (l.as_ref(), r.as_ref()))
+            .collect();
+        let res = ExtAwi::dot_product(&refs).unwrap();
+        let log2_n = (usize::BITS - (n - 1).leading_zeros()) as usize;
+        assert_eq!(res.bw(), (max_w * 2) + log2_n);
+        assert_eq!(res.to_u128(), expected);
+        assert_eq!(Awi::dot_product(&refs).unwrap().as_ref(), res.as_ref());
+        // all ones is the worst case for overflow
+        let mut max = Awi::zero(bw(max_w));
+        max.umax_();
+        let maxs = vec![(max.as_ref(), max.as_ref()); n];
+        let res = Awi::dot_product(&maxs).unwrap();
+        let mut max_product = ExtAwi::zero(bw(max_w * 2));
+        max_product.arb_umul_add_(&max, &max);
+        assert_eq!(res.to_u128(), max_product.to_u128() * (n as u128));
+    }
+    assert!(ExtAwi::dot_product(&[]).is_none());
+    let x = InlAwi::from_u8(1);
+    let y = InlAwi::from_u16(1);
+    assert!(ExtAwi::dot_product(&[(&x, &x), (&x, &y)]).is_none());
+}
+
+#[test]
+pub fn sum() {
+    use awint::Awi;
+    let rng = &mut Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..1000 {
+        let n = ((rng.next_u32() as usize) % 64) + 1;
+        // keep the total within `u128`
+        let w = ((rng.next_u32() as usize) % 120) + 1;
+        let mut terms = vec![];
+        let mut expected = 0u128;
+        for _ in 0..n {
+            let mut x = ExtAwi::zero(bw(w));
+            x.rand_(rng).unwrap();
+            expected += x.to_u128();
+            terms.push(x);
+        }
+        let refs: Vec<&Bits> = terms.iter().map(|x| x.as_ref()).collect();
+        let res = ExtAwi::sum(&refs).unwrap();
+        let log2_n = (usize::BITS - (n - 1).leading_zeros()) as usize;
+        assert_eq!(res.bw(), w + log2_n);
+        assert_eq!(res.to_u128(), expected);
+        assert_eq!(Awi::sum(&refs).unwrap().as_ref(), res.as_ref());
+        // all ones is the worst case for overflow
+        let mut max = Awi::zero(bw(w));
+        max.umax_();
+        let res = Awi::sum(&vec![max.as_ref(); n]).unwrap();
+        assert_eq!(res.to_u128(), max.to_u128() * (n as u128));
+    }
+    assert!(ExtAwi::sum(&[]).is_none());
+    let x = InlAwi::from_u8(1);
+    let y = InlAwi::from_u16(1);
+    assert!(ExtAwi::sum(&[&x, &y]).is_none());
+}
@@ -0,0 +1,48 @@
+use awint::{awi::*, gf2::gf2_matvec};
+use rand_xoshiro::{
+    rand_core::{RngCore, SeedableRng},
+    Xoshiro128StarStar,
+};
+
+#[test]
+fn gf2_matvec_reference() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..100 {
+        let n = ((rng.next_u32() % 150) + 1) as usize;
+        let m = ((rng.next_u32() % 100) + 1) as usize;
+        let mut rows = vec![];
+        for _ in 0..m {
+            let mut row = ExtAwi::zero(bw(n));
+            row.rand_(&mut rng).unwrap();
+            rows.push(row);
+        }
+        let rows: Vec<&Bits> = rows.iter().map(|row| row.as_ref()).collect();
+        let mut x = ExtAwi::zero(bw(n));
+        let mut y = ExtAwi::zero(bw(n));
+        x.rand_(&mut rng).unwrap();
+        y.rand_(&mut rng).unwrap();
+        let mut out_x = ExtAwi::zero(bw(m));
+        gf2_matvec(&rows, &x, &mut out_x).unwrap();
+        for (i, row) in rows.iter().enumerate() {
+            let mut sum = false;
+            for j in 0..n {
+                sum ^= row.get(j).unwrap() & x.get(j).unwrap();
+            }
+            assert_eq!(out_x.get(i).unwrap(), sum);
+        }
+        // linearity
+        let mut out_y = ExtAwi::zero(bw(m));
+        gf2_matvec(&rows, &y, &mut out_y).unwrap();
+        y.xor_(&x).unwrap();
+        let mut out_xy = ExtAwi::zero(bw(m));
+        gf2_matvec(&rows, &y, &mut out_xy).unwrap();
+        out_x.xor_(&out_y).unwrap();
+        assert_eq!(out_xy, out_x);
+        // mismatches
+        let mut out = ExtAwi::zero(bw(m + 1));
+        assert!(gf2_matvec(&rows, &x, &mut out).is_none());
+        let mut out = ExtAwi::zero(bw(m));
+        let x = ExtAwi::zero(bw(n + 1));
+        assert!(gf2_matvec(&rows, &x, &mut out).is_none());
+    }
+}
@@ -196,3 +196,93 @@ fn macro_successes() {
         assert_eq!(D, bits!(0xba987_654323ff_fffffedc_u84));
     }
 }
+
+#[test]
+fn try_construction() {
+    use awint::SerdeError::*;
+
+    let x = inlawi!(0x1234_5678_u32);
+    let (r0, r1, r2) = (8, 24, 40);
+    assert_eq!(try_extawi!(x[r0..r1]), Ok(extawi!(0x3456u16)));
+    assert_eq!(try_awi!(x[r0..r1]), Ok(awi!(0x3456u16)));
+    assert_eq!(try_extawi!(x[r0..r2]), Err(Overflow));
+    assert_eq!(try_awi!(x[r1..r0]), Err(Overflow));
+    let r3 = 8;
+    assert_eq!(try_extawi!(x[r0..r3]), Err(ZeroBitwidth));
+    assert_eq!(try_awi!(x[r3..r0]), Err(ZeroBitwidth));
+    // sinks
+    let mut y = extawi!(0u16);
+    assert_eq!(try_extawi!(x[r0..r1]; y), Ok(extawi!(0x3456u16)));
+    assert_eq!(y, extawi!(0x3456u16));
+    let mut z = extawi!(0u12);
+    assert_eq!(try_awi!(x[r0..r1]; z), Err(NonEqualWidths));
+    assert_eq!(try_extawi!(x[..r0]; ..r1), Err(NonEqualWidths));
+    // infallible and literal inputs
+    assert_eq!(try_extawi!(x), Ok(extawi!(0x1234_5678_u32)));
+    assert_eq!(try_awi!(0xabu8), Ok(awi!(0xabu8)));
+    assert_eq!(try_awi!(umax: ..r0), Ok(awi!(umax: ..8)));
+}
+
+bitfield!(
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Reg(32) {
+        opcode: 0..7,
+        rd: 7..12,
+        funct3: (12 + 0)..=14,
+        rs1: 15..20,
+        rs2: 20..25,
+        funct7: 25..,
+    }
+);
+
+bitfield!(
+    struct Wide(140) {
+        lo: 3,
+        mid: 60..130,
+    }
+);
+
+#[test]
+fn bitfield_macro() {
+    use awint::awint_macro_internals::awint_macro_bitfield;
+
+    let mut reg = Reg::zero();
+    reg.set_opcode(&inlawi!(0b0110011u7));
+    reg.set_rd(&inlawi!(1u5));
+    reg.set_funct3(&inlawi!(0u3));
+    reg.set_rs1(&inlawi!(2u5));
+    reg.set_rs2(&inlawi!(3u5));
+    reg.set_funct7(&inlawi!(0b0100000u7));
+    // `sub x1, x2, x3`
+    assert_eq!(reg.0, inlawi!(0x403100b3u32));
+    assert_eq!(reg.rs2(), inlawi!(3u5));
+    assert_eq!(reg.funct7(), inlawi!(0b0100000u7));
+    // setting a field leaves the others alone
+    reg.set_rs1(&inlawi!(31u5));
+    assert_eq!(reg.0, inlawi!(0x403f80b3u32));
+    let copy = reg;
+    assert_eq!(copy, reg);
+
+    let mut wide = Wide::zero();
+    wide.set_lo(&inlawi!(1u1));
+    let mut mid = inlawi!(0u70);
+    mid.umax_();
+    wide.set_mid(&mid);
+    assert_eq!(wide.lo(), inlawi!(1u1));
+    assert_eq!(wide.mid(), mid);
+    assert_eq!(wide.0.count_ones(), 71);
+
+    // compile time errors
+    for input in [
+        "struct A(8) { x: 0..4, y: 3..8 }",
+        "struct A(8) { x: 0..9 }",
+        "struct A(8) { x: 4..4 }",
+        "struct A(8) { x: 0..n }",
+        "struct A(8) { x: 0..4, x: 4..8 }",
+        "struct A(0) { x: 0 }",
+        "struct A(8) {}",
+        "enum A(8) { x: 0 }",
+    ] {
+        assert!(awint_macro_bitfield(input).is_err(), "{input}");
+    }
+}
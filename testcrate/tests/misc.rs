@@ -1,6 +1,7 @@
 use awint::{
     awi::*,
     awint_internals::{Digit, BITS, USIZE_BITS},
+    ArcBits,
 };
 use rand_xoshiro::{
     rand_core::{RngCore, SeedableRng},
@@ -276,3 +277,653 @@ fn from_primitive() {
     );
     assert_eq!(Awi::from(Digit::MAX), awi!(umax: ..BITS).unwrap());
 }
+
+#[test]
+fn inlawi_from_bytes_and_digits() {
+    let x: inlawi_ty!(12) = InlAwi::try_from(&[0x21u8, 0x03][..]).unwrap();
+    assert_eq!(x, inlawi!(0x321u12));
+    let x: inlawi_ty!(16) = InlAwi::try_from(&[0x21u8, 0xf3][..]).unwrap();
+    assert_eq!(x, inlawi!(0xf321u16));
+    assert_eq!(
+        <inlawi_ty!(12)>::try_from(&[0x21u8, 0x13][..]),
+        Err(awint::SerdeError::Overflow)
+    );
+    assert_eq!(
+        <inlawi_ty!(12)>::try_from(&[0x21u8][..]),
+        Err(awint::SerdeError::NonEqualWidths)
+    );
+    assert_eq!(
+        <inlawi_ty!(12)>::try_from(&[0x21u8, 0x03, 0][..]),
+        Err(awint::SerdeError::NonEqualWidths)
+    );
+    let x: inlawi_ty!(200) = InlAwi::try_from(&[0xffu8; 25][..]).unwrap();
+    assert_eq!(x, inlawi!(umax: ..200));
+
+    let x: inlawi_ty!(12) = inlawi_from_u128_digits(0x321).unwrap();
+    assert_eq!(x, inlawi!(0x321u12));
+    let y: Option<inlawi_ty!(12)> = inlawi_from_u128_digits(0x1321);
+    assert!(y.is_none());
+}
+
+/// Splits `val` into however many `Digit`s `LEN` calls for, so that the test
+/// works for any `Digit` size
+fn inlawi_from_u128_digits<const BW: usize, const LEN: usize>(
+    val: u128,
+) -> Option<InlAwi<BW, LEN>> {
+    let mut digits = [0 as Digit; LEN];
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let shift = u32::try_from(i * BITS).unwrap_or(u32::MAX);
+        *digit = val.checked_shr(shift).unwrap_or(0) as Digit;
+    }
+    InlAwi::from_digits(digits)
+}
+
+#[test]
+fn lanes_map_panic() {
+    let mut x = inlawi!(0x777777_666666_555555_444444_333333_222222_111111_000000_u192);
+    let mut lane = 0;
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        x.lanes_map_(bw(24), |bits| {
+            if lane == 2 {
+                bits.umax_();
+                panic!();
+            }
+            bits.inc_(true);
+            lane += 1;
+        })
+    }));
+    assert!(res.is_err());
+    // the lanes before the panic are mapped, the panicking lane keeps what `f`
+    // wrote, and the rest are untouched
+    assert_eq!(
+        x,
+        inlawi!(0x777777_666666_555555_444444_333333_ffffff_111112_000001_u192)
+    );
+}
+
+#[test]
+fn checked_primitive_conversion() {
+    assert_eq!(inlawi!(255u100).try_to_u8(), Some(255));
+    assert_eq!(inlawi!(256u100).try_to_u8(), None);
+    assert_eq!(inlawi!(-128i100).try_to_i8(), Some(-128));
+    assert_eq!(inlawi!(-129i100).try_to_i8(), None);
+    assert_eq!(inlawi!(127i100).try_to_i8(), Some(127));
+    assert_eq!(inlawi!(128i100).try_to_i8(), None);
+    assert_eq!(inlawi!(-1i100).try_to_u64(), None);
+    assert_eq!(inlawi!(-1i100).try_to_i128(), Some(-1));
+    assert_eq!(inlawi!(umax: ..128).try_to_u128(), Some(u128::MAX));
+    assert_eq!(inlawi!(umax: ..128).try_to_i128(), Some(-1));
+    assert_eq!(inlawi!(imin: ..129).try_to_i128(), None);
+    assert_eq!(inlawi!(1u1).try_to_i8(), Some(-1));
+    assert_eq!(inlawi!(1u1).try_to_u8(), Some(1));
+}
+
+#[test]
+fn saturating_primitive_conversion() {
+    assert_eq!(inlawi!(255u100).saturating_to_u8(), 255);
+    assert_eq!(inlawi!(256u100).saturating_to_u8(), u8::MAX);
+    assert_eq!(inlawi!(-128i100).saturating_to_i8(), -128);
+    assert_eq!(inlawi!(-129i100).saturating_to_i8(), i8::MIN);
+    assert_eq!(inlawi!(127i100).saturating_to_i8(), 127);
+    assert_eq!(inlawi!(128i100).saturating_to_i8(), i8::MAX);
+    assert_eq!(inlawi!(-1i100).saturating_to_u64(), u64::MAX);
+    assert_eq!(inlawi!(-1i100).saturating_to_i128(), -1);
+    assert_eq!(inlawi!(umax: ..128).saturating_to_i128(), -1);
+    assert_eq!(inlawi!(imin: ..129).saturating_to_i128(), i128::MIN);
+    assert_eq!(inlawi!(imax: ..129).saturating_to_i128(), i128::MAX);
+    assert_eq!(inlawi!(1u1).saturating_to_i8(), -1);
+    assert_eq!(inlawi!(1u1).saturating_to_u8(), 1);
+}
+
+#[test]
+fn field_from_u128() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..1000 {
+        let w = bw(((rng.next_u32() % 300) + 1) as usize);
+        let mut x = ExtAwi::zero(w);
+        x.rand_(&mut rng).unwrap();
+        let val = ((rng.next_u64() as u128) << 64) | (rng.next_u64() as u128);
+        let width = (rng.next_u32() % 130) as usize;
+        let to = (rng.next_u32() as usize) % (w.get() + 2);
+        // one bit wider so that `field_to` itself accepts a width of 129
+        let mut val_awi = inlawi!(0u129);
+        val_awi.u128_(val);
+        let mut expected = x.clone();
+        let res = expected.field_to(to, &val_awi, width);
+        let res = if width > 128 { None } else { res };
+        assert_eq!(x.field_from_u128_(to, val, width), res);
+        if res.is_some() {
+            assert_eq!(x, expected);
+        }
+    }
+}
+
+#[test]
+fn signum() {
+    for (x, sign) in [(inlawi!(0i1), 0), (inlawi!(-1i1), -1)] {
+        assert_eq!(x.sign(), sign);
+        let mut y = x;
+        y.signum_();
+        assert_eq!(y.to_i8(), sign);
+    }
+    for (x, sign) in [
+        (inlawi!(0i2), 0),
+        (inlawi!(1i2), 1),
+        (inlawi!(-2i2), -1),
+        (inlawi!(-1i2), -1),
+    ] {
+        assert_eq!(x.sign(), sign);
+        let mut y = x;
+        y.signum_();
+        assert_eq!(y.to_i8(), sign);
+    }
+    let mut x = inlawi!(imin: ..200);
+    assert_eq!(x.sign(), -1);
+    x.signum_();
+    assert!(x.is_umax());
+    let mut x = inlawi!(imax: ..200);
+    assert_eq!(x.sign(), 1);
+    x.signum_();
+    assert!(x.is_uone());
+}
+
+#[test]
+fn expect_width() {
+    let x = inlawi!(0x1234u16);
+    let y = extawi!(0u16);
+    assert_eq!(x.expect_width(16), x.as_ref());
+    assert_eq!(x.expect_eq_width(&y), x.as_ref());
+    let location = awint::awint_internals::Location::caller();
+    assert_eq!(location.file, file!());
+    assert_eq!(location.line, line!() - 2);
+}
+
+#[test]
+#[should_panic(expected = "expected a bitwidth of 8, found a bitwidth of 16")]
+fn expect_width_panic() {
+    let _ = inlawi!(0x1234u16).expect_width(8);
+}
+
+#[test]
+#[should_panic(expected = "mismatched bitwidths: `self.bw()` is 16, `rhs.bw()` is 8")]
+fn expect_eq_width_panic() {
+    let _ = inlawi!(0x1234u16).expect_eq_width(&inlawi!(0u8));
+}
+
+/// Runs `f`, which should panic, and returns the file and line that the panic
+/// was reported at
+fn panic_location(f: impl FnOnce()) -> (String, u32) {
+    use std::{cell::RefCell, panic};
+    thread_local! {
+        static LOCATION: RefCell<Option<(String, u32)>> = const { RefCell::new(None) };
+    }
+    // the hook is global, but it runs on the panicking thread so other tests
+    // panicking in the meantime cannot write to this thread's `LOCATION`
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        if let Some(location) = info.location() {
+            LOCATION
+                .with(|l| *l.borrow_mut() = Some((location.file().to_owned(), location.line())));
+        }
+    }));
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    panic::set_hook(prev_hook);
+    assert!(res.is_err());
+    LOCATION.with(|l| l.borrow_mut().take()).unwrap()
+}
+
+#[test]
+fn expect_width_panic_location() {
+    let x = inlawi!(0x1234u16);
+    let y = inlawi!(0u8);
+    let line = line!() + 2;
+    let location = panic_location(|| {
+        let _ = x.expect_width(8);
+    });
+    assert_eq!(location, (file!().to_owned(), line));
+    let line = line!() + 2;
+    let location = panic_location(|| {
+        let _ = x.expect_eq_width(&y);
+    });
+    assert_eq!(location, (file!().to_owned(), line));
+}
+
+#[test]
+fn fp_from_ratio() {
+    use awint::fp::FpRounding;
+    let roundings = [
+        FpRounding::Trunc,
+        FpRounding::Floor,
+        FpRounding::Ceil,
+        FpRounding::NearestEven,
+    ];
+    for signed in [false, true] {
+        for fp in -3..=6isize {
+            for rounding in roundings {
+                for num_u in 0..32u8 {
+                    for den_u in 0..16u8 {
+                        let mut num = inlawi!(0u5);
+                        num.u8_(num_u);
+                        let mut den = inlawi!(0u4);
+                        den.u8_(den_u);
+                        let ty = FPType {
+                            signed,
+                            bw: bw(6),
+                            fp,
+                        };
+                        let res = FP::from_ratio(&num, &den, ty, rounding);
+                        let (mut n, mut d) = if signed {
+                            (num.to_i128(), den.to_i128())
+                        } else {
+                            (num.to_u128() as i128, den.to_u128() as i128)
+                        };
+                        if d == 0 {
+                            assert!(res.is_none());
+                            continue
+                        }
+                        if fp >= 0 {
+                            n <<= fp;
+                        } else {
+                            d <<= -fp;
+                        }
+                        if d < 0 {
+                            n = -n;
+                            d = -d;
+                        }
+                        let floor = n.div_euclid(d);
+                        let rem = n.rem_euclid(d);
+                        let val = match rounding {
+                            FpRounding::Trunc => floor + i128::from((n < 0) && (rem != 0)),
+                            FpRounding::Floor => floor,
+                            FpRounding::Ceil => floor + i128::from(rem != 0),
+                            FpRounding::NearestEven => {
+                                floor
+                                    + i128::from(
+                                        ((2 * rem) > d) || (((2 * rem) == d) && ((floor & 1) != 0)),
+                                    )
+                            }
+                        };
+                        let mut e = ExtAwi::zero(bw(6));
+                        e.i128_(val);
+                        let fits = if signed {
+                            e.to_i128() == val
+                        } else {
+                            (val >= 0) && (e.to_u128() == (val as u128))
+                        };
+                        if fits {
+                            assert_eq!(res.unwrap(), FP::new(signed, e, fp).unwrap());
+                        } else {
+                            assert!(res.is_none());
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn fp_cmp() {
+    use core::cmp::Ordering;
+    for l_signed in [false, true] {
+        for r_signed in [false, true] {
+            for l_fp in -2..=3isize {
+                for r_fp in -2..=3isize {
+                    for l_u in 0..8u8 {
+                        for r_u in 0..16u8 {
+                            let mut l = inlawi!(0u3);
+                            l.u8_(l_u);
+                            let mut r = inlawi!(0u4);
+                            r.u8_(r_u);
+                            let (l_i, r_i) = (
+                                if l_signed {
+                                    l.to_i128()
+                                } else {
+                                    l.to_u128() as i128
+                                },
+                                if r_signed {
+                                    r.to_i128()
+                                } else {
+                                    r.to_u128() as i128
+                                },
+                            );
+                            // scale by `2^3` so that all values are integers
+                            let expected = (l_i << (3 - l_fp)).cmp(&(r_i << (3 - r_fp)));
+                            let l = FP::new(l_signed, l, l_fp).unwrap();
+                            let r = FP::new(r_signed, r, r_fp).unwrap();
+                            assert_eq!(FP::cmp(&l, &r), expected);
+                            assert_eq!(FP::cmp(&r, &l), expected.reverse());
+                            assert_eq!(FP::eq(&l, &r), expected == Ordering::Equal);
+                            assert_eq!(FP::lt(&l, &r), expected == Ordering::Less);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compares `l` and `r` by aligning them in an allocation large enough for both
+fn fp_cmp_reference(l: &FP<ExtAwi>, r: &FP<ExtAwi>) -> core::cmp::Ordering {
+    let fp = l.fp().max(r.fp());
+    let int = (l.ibw() - l.fp()).max(r.ibw() - r.fp()) + 1;
+    let w = bw(usize::try_from(int + fp).unwrap());
+    let align = |x: &FP<ExtAwi>| {
+        let mut tmp = ExtAwi::zero(w);
+        tmp.resize_(x, x.is_negative());
+        tmp.shl_(usize::try_from(fp - x.fp()).unwrap()).unwrap();
+        tmp
+    };
+    let (l, r) = (align(l), align(r));
+    if l.ilt(&r).unwrap() {
+        core::cmp::Ordering::Less
+    } else if l == r {
+        core::cmp::Ordering::Equal
+    } else {
+        core::cmp::Ordering::Greater
+    }
+}
+
+#[test]
+fn fp_cmp_wide() {
+    use core::cmp::Ordering;
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..10000 {
+        let mut fps = [0isize; 2];
+        let mut xs = vec![];
+        for fp in &mut fps {
+            let w = ((rng.next_u32() % 200) + 1) as usize;
+            *fp = ((rng.next_u32() % 340) as isize) - 70;
+            let mut x = ExtAwi::zero(bw(w));
+            x.rand_(&mut rng).unwrap();
+            // make long runs of equal bits and values that are equal after alignment
+            // more likely
+            match rng.next_u32() % 4 {
+                0 => x.lshr_(rng.next_u32() as usize % w).unwrap(),
+                1 => x.ashr_(rng.next_u32() as usize % w).unwrap(),
+                _ => (),
+            }
+            xs.push(FP::new((rng.next_u32() & 1) == 0, x, *fp).unwrap());
+        }
+        if (rng.next_u32() % 4) == 0 {
+            // the same value shifted to a different fixed point
+            let shift = (rng.next_u32() % 64) as usize;
+            let mut x = ExtAwi::zero(bw(xs[0].bw() + shift));
+            x.resize_(&xs[0], xs[0].is_negative());
+            x.shl_(shift).unwrap();
+            xs[1] = FP::new(xs[0].signed(), x, xs[0].fp() + (shift as isize)).unwrap();
+        }
+        let expected = fp_cmp_reference(&xs[0], &xs[1]);
+        assert_eq!(FP::cmp(&xs[0], &xs[1]), expected);
+        assert_eq!(FP::cmp(&xs[1], &xs[0]), expected.reverse());
+        assert_eq!(FP::eq(&xs[0], &xs[1]), expected == Ordering::Equal);
+    }
+    // fixed points far apart would need an enormous allocation to align
+    let x = FP::new(true, inlawi!(-3i8), 1 << 40).unwrap();
+    let y = FP::new(true, inlawi!(3i8), -(1 << 40)).unwrap();
+    let z = FP::new(false, inlawi!(1u8), -(1 << 40)).unwrap();
+    assert_eq!(FP::cmp(&x, &y), Ordering::Less);
+    assert_eq!(FP::cmp(&y, &z), Ordering::Greater);
+    assert_eq!(FP::cmp(&z, &x), Ordering::Greater);
+    assert!(FP::lt(
+        &x,
+        &FP::new(true, inlawi!(0i8), -(1 << 40)).unwrap()
+    ));
+}
+
+/// Jacobi symbol computed from the Legendre symbols of the prime factors of
+/// `n`, using Euler's criterion
+fn jacobi_reference(a: u64, mut n: u64) -> i8 {
+    let mut res = 1;
+    let mut p = 3;
+    while n > 1 {
+        while n.wrapping_rem(p) == 0 {
+            n /= p;
+            let mut pow = 1;
+            for _ in 0..((p - 1) / 2) {
+                pow = (pow * a) % p;
+            }
+            if pow == 0 {
+                return 0
+            } else if pow != 1 {
+                res = -res;
+            }
+        }
+        p += 2;
+    }
+    res
+}
+
+#[test]
+fn jacobi() {
+    let mut pads = [inlawi!(0u12); 4];
+    let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.const_as_mut()).collect();
+    for n in 0..200u64 {
+        for a in 0..300u64 {
+            let mut x = inlawi!(0u12);
+            let mut y = inlawi!(0u12);
+            x.u64_(a);
+            y.u64_(n);
+            let res = x.jacobi(&y, &mut pads);
+            if (n & 1) == 0 {
+                assert!(res.is_none());
+            } else {
+                assert_eq!(res, Some(jacobi_reference(a, n)), "({a} / {n})");
+            }
+        }
+    }
+    assert!(inlawi!(1u12).jacobi(&inlawi!(1u11), &mut pads).is_none());
+    assert!(inlawi!(1u12)
+        .jacobi(&inlawi!(1u12), &mut pads[..3])
+        .is_none());
+}
+
+#[test]
+fn extended_gcd() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..1000 {
+        let w = bw(((rng.next_u32() % 200) + 2) as usize);
+        let mut a = ExtAwi::zero(w);
+        let mut b = ExtAwi::zero(w);
+        a.rand_(&mut rng).unwrap();
+        b.rand_(&mut rng).unwrap();
+        // make common factors and small values more likely
+        let s = (rng.next_u32() as usize) % w.get();
+        a.lshr_(s).unwrap();
+        let s = (rng.next_u32() as usize) % w.get();
+        b.lshr_(s).unwrap();
+        if (rng.next_u32() & 1) == 0 {
+            let mut pad = ExtAwi::zero(w);
+            let mut c = ExtAwi::zero(w);
+            c.rand_(&mut rng).unwrap();
+            c.range_and_(0..w.get().min(8)).unwrap();
+            pad.copy_(&a).unwrap();
+            a.zero_();
+            a.mul_add_(&pad, &c).unwrap();
+            pad.copy_(&b).unwrap();
+            b.zero_();
+            b.mul_add_(&pad, &c).unwrap();
+        }
+        let a_orig = a.clone();
+        let b_orig = b.clone();
+        let mut x = ExtAwi::zero(w);
+        let mut y = ExtAwi::zero(w);
+        let mut pads = vec![ExtAwi::zero(w); 5];
+        let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.as_mut()).collect();
+        Bits::extended_gcd_(&mut a, &mut b, &mut x, &mut y, &mut pads).unwrap();
+        assert!(b.is_zero());
+        // check the Bezout identity without overflow
+        let wide = bw(w.get() * 2 + 2);
+        let mut lhs = ExtAwi::zero(wide);
+        // zero extend so that the inputs are positive in the signed multiplication
+        let mut a_ext = ExtAwi::from_bits_resize(&a_orig, w.saturating_add(1), false);
+        let mut b_ext = ExtAwi::from_bits_resize(&b_orig, w.saturating_add(1), false);
+        lhs.arb_imul_add_(&mut a_ext, &mut x);
+        lhs.arb_imul_add_(&mut b_ext, &mut y);
+        assert_eq!(lhs, ExtAwi::from_bits_resize(&a, wide, false));
+        // the gcd divides both inputs
+        if !a.is_zero() {
+            let mut quo = ExtAwi::zero(w);
+            let mut rem = ExtAwi::zero(w);
+            Bits::udivide(&mut quo, &mut rem, &a_orig, &a).unwrap();
+            assert!(rem.is_zero());
+            Bits::udivide(&mut quo, &mut rem, &b_orig, &a).unwrap();
+            assert!(rem.is_zero());
+        }
+    }
+    let mut pads = [inlawi!(0u8); 4];
+    let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.const_as_mut()).collect();
+    let mut a = inlawi!(6u8);
+    let mut b = inlawi!(4u8);
+    let mut x = inlawi!(0u8);
+    let mut y = inlawi!(0u8);
+    assert!(Bits::extended_gcd_(&mut a, &mut b, &mut x, &mut y, &mut pads).is_none());
+}
+
+/// simple square-and-multiply reference for `mod_pow_windowed_`
+fn mod_pow_reference(base: &Bits, exp: &Bits, modulus: &Bits) -> ExtAwi {
+    let w = bw(base.bw() * 2);
+    let m = ExtAwi::from_bits_resize(modulus, w, false);
+    let b = ExtAwi::from_bits_resize(base, w, false);
+    let mut acc = ExtAwi::uone(w);
+    let mut prod = ExtAwi::zero(w);
+    let mut quo = ExtAwi::zero(w);
+    let mut tmp = ExtAwi::zero(w);
+    Bits::udivide(&mut quo, &mut tmp, &acc, &m).unwrap();
+    acc.copy_(&tmp).unwrap();
+    for i in (0..exp.bw()).rev() {
+        prod.zero_();
+        prod.mul_add_(&acc, &acc).unwrap();
+        Bits::udivide(&mut quo, &mut acc, &prod, &m).unwrap();
+        if exp.get(i).unwrap() {
+            prod.zero_();
+            prod.mul_add_(&acc, &b).unwrap();
+            Bits::udivide(&mut quo, &mut acc, &prod, &m).unwrap();
+        }
+    }
+    ExtAwi::from_bits_resize(&acc, base.nzbw(), false)
+}
+
+#[test]
+fn mod_pow_windowed() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for _ in 0..300 {
+        let w = bw(((rng.next_u32() % 150) + 1) as usize);
+        let exp_w = bw(((rng.next_u32() % 100) + 1) as usize);
+        let window = ((rng.next_u32() % 4) + 1) as usize;
+        let mut x = ExtAwi::zero(w);
+        let mut exp = ExtAwi::zero(exp_w);
+        let mut m = ExtAwi::zero(w);
+        x.rand_(&mut rng).unwrap();
+        exp.rand_(&mut rng).unwrap();
+        m.rand_(&mut rng).unwrap();
+        let s = (rng.next_u32() as usize) % w.get();
+        m.lshr_(s).unwrap();
+        if m.is_zero() {
+            m.uone_();
+        }
+        let expected = mod_pow_reference(&x, &exp, &m);
+        let mut pads = vec![ExtAwi::zero(bw(w.get() * 2)); 5 + (1 << window)];
+        let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.as_mut()).collect();
+        x.mod_pow_windowed_(&exp, &m, window, &mut pads).unwrap();
+        assert_eq!(x, expected);
+    }
+    let mut pads = [inlawi!(0u16); 8];
+    let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.const_as_mut()).collect();
+    let mut x = inlawi!(3u8);
+    let exp = inlawi!(5u8);
+    // not enough pads
+    assert!(x
+        .mod_pow_windowed_(&exp, &inlawi!(7u8), 2, &mut pads)
+        .is_none());
+    // zero window
+    assert!(x
+        .mod_pow_windowed_(&exp, &inlawi!(7u8), 0, &mut pads)
+        .is_none());
+    // zero modulus
+    assert!(x
+        .mod_pow_windowed_(&exp, &inlawi!(0u8), 1, &mut pads)
+        .is_none());
+    x.mod_pow_windowed_(&exp, &inlawi!(7u8), 1, &mut pads)
+        .unwrap();
+    // 3^5 = 243 = 34*7 + 5
+    assert_eq!(x, inlawi!(5u8));
+}
+
+#[test]
+fn arc_bits() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ArcBits>();
+
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    let mut awi = Awi::zero(bw(1000));
+    awi.rand_(&mut rng).unwrap();
+    let orig = awi.clone();
+    let shared = ArcBits::new(awi);
+    // sharing across threads does not clone
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let mut local = shared.clone();
+            let orig = orig.clone();
+            std::thread::spawn(move || {
+                assert_eq!(local.as_ref(), orig.as_ref());
+                for _ in 0..=i {
+                    local.make_mut().inc_(true);
+                }
+                local
+            })
+        })
+        .collect();
+    let mut expected = orig.clone();
+    for handle in handles {
+        let local = handle.join().unwrap();
+        expected.inc_(true);
+        assert_eq!(local.as_ref(), expected.as_ref());
+        assert!(!ArcBits::ptr_eq(&local, &shared));
+    }
+    // writes to clones do not affect the original
+    assert_eq!(shared.as_ref(), orig.as_ref());
+    assert_eq!(ArcBits::strong_count(&shared), 1);
+
+    let mut a = ArcBits::from(inlawi!(0xabcd_u16));
+    let b = a.clone();
+    assert_eq!(a, b);
+    assert!(a.get_mut().is_none());
+    drop(b);
+    // writing to a unique `ArcBits` does not reallocate
+    let ptr = a.as_ref() as *const Bits;
+    a.make_mut().not_();
+    a.make_mut().not_();
+    assert_eq!(a.as_ref() as *const Bits, ptr);
+    let c = a.clone();
+    assert!(a.get_mut().is_none());
+    drop(a);
+    assert_eq!(c.into_awi(), Awi::from(inlawi!(0xabcd_u16)));
+}
+
+#[test]
+fn transpose_square() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    let orders = (1..=20).chain([31, 32, 33, 64, 100, 128]);
+    for n in orders {
+        for _ in 0..4 {
+            let mut x = ExtAwi::zero(bw(n * n));
+            x.rand_(&mut rng).unwrap();
+            let orig = x.clone();
+            x.transpose_square_(bw(n)).unwrap();
+            for r in 0..n {
+                for c in 0..n {
+                    assert_eq!(x.get((r * n) + c), orig.get((c * n) + r));
+                }
+            }
+            x.transpose_square_(bw(n)).unwrap();
+            assert_eq!(x, orig);
+        }
+        if n > 1 {
+            let mut x = ExtAwi::zero(bw((n * n) - 1));
+            assert!(x.transpose_square_(bw(n)).is_none());
+        }
+    }
+    let mut x = inlawi!(0u16);
+    assert!(x.transpose_square_(bw(usize::MAX)).is_none());
+}
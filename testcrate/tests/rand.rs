@@ -96,3 +96,70 @@ fn rand_example() {
     assert_eq!(rng0.next_u32().to_u32(), rng1.next_u32());
     assert_eq!(rng0.next_u32().to_u32(), rng1.next_u32());
 }
+
+fn is_prime_trial_division(n: u64) -> bool {
+    if n < 2 {
+        return false
+    }
+    let mut d = 2;
+    while d * d <= n {
+        if n.wrapping_rem(d) == 0 {
+            return false
+        }
+        d += 1;
+    }
+    true
+}
+
+#[test]
+fn miller_rabin() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    let mut pads = vec![ExtAwi::zero(bw(64)); 6];
+    let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.as_mut()).collect();
+    for n in 0..3000u32 {
+        let x = InlAwi::from_u32(n);
+        assert_eq!(
+            x.is_probably_prime(16, &mut rng, &mut pads),
+            Some(is_prime_trial_division(n as u64))
+        );
+    }
+    // Carmichael numbers, which fool the Fermat test
+    for n in [561u32, 1105, 1729, 2465, 2821, 6601, 8911, 41041, 825265] {
+        assert_eq!(
+            InlAwi::from_u32(n).is_probably_prime(16, &mut rng, &mut pads),
+            Some(false)
+        );
+    }
+    // wrong scratchpad bitwidths or counts
+    assert!(inlawi!(7u31)
+        .is_probably_prime(16, &mut rng, &mut pads)
+        .is_none());
+    assert!(inlawi!(7u32)
+        .is_probably_prime(16, &mut rng, &mut pads[..5])
+        .is_none());
+
+    // the Mersenne prime 2^127 - 1 and its neighbors
+    let mut pads = vec![ExtAwi::zero(bw(256)); 6];
+    let mut pads: Vec<&mut Bits> = pads.iter_mut().map(|pad| pad.as_mut()).collect();
+    let mut m127 = inlawi!(0u128);
+    m127.umax_();
+    m127.lshr_(1).unwrap();
+    assert_eq!(m127.is_probably_prime(16, &mut rng, &mut pads), Some(true));
+    m127.digit_add_(2);
+    assert_eq!(m127.is_probably_prime(16, &mut rng, &mut pads), Some(false));
+}
+
+#[test]
+fn random_prime() {
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for w in 2..=40 {
+        let p = ExtAwi::random_prime(bw(w), 16, &mut rng).unwrap();
+        assert_eq!(p.sig(), w);
+        assert!(is_prime_trial_division(p.to_u64()));
+        let p = Awi::random_prime(bw(w), 16, &mut rng).unwrap();
+        assert_eq!(p.sig(), w);
+        assert!(is_prime_trial_division(p.to_u64()));
+    }
+    assert!(ExtAwi::random_prime(bw(1), 16, &mut rng).is_none());
+    assert!(Awi::random_prime(bw(1), 16, &mut rng).is_none());
+}
@@ -0,0 +1,33 @@
+use awint::{awi::*, awint_internals::DIGIT_BYTES};
+use rkyv::{archived_root, check_archived_root, AlignedVec, Deserialize, Infallible};
+
+#[test]
+fn rkyv() {
+    for x in [
+        extawi!(0u1),
+        extawi!(1u1),
+        extawi!(0xfedcba9876543210u100),
+        extawi!(umax: ..128),
+        extawi!(imin: ..1000),
+    ] {
+        let bytes = rkyv::to_bytes::<_, 256>(&x).unwrap();
+        let archived = check_archived_root::<ExtAwi>(&bytes).unwrap();
+        assert_eq!(archived.bw(), x.bw());
+        assert_eq!(archived.as_bits(), x.as_ref());
+        let y: ExtAwi = archived.deserialize(&mut Infallible).unwrap();
+        assert_eq!(x, y);
+    }
+
+    // setting an unused bit should fail validation
+    let x = extawi!(0u100);
+    let bytes = rkyv::to_bytes::<_, 256>(&x).unwrap();
+    let archived = unsafe { archived_root::<ExtAwi>(&bytes) };
+    let offset = archived.as_bits().as_ptr() as usize - bytes.as_ptr() as usize;
+    let mut corrupted = AlignedVec::new();
+    corrupted.extend_from_slice(&bytes);
+    let end = offset + (archived.as_bits().total_digits() * DIGIT_BYTES);
+    for byte in &mut corrupted[(end - DIGIT_BYTES)..end] {
+        *byte = u8::MAX;
+    }
+    assert!(check_archived_root::<ExtAwi>(&corrupted).is_err());
+}
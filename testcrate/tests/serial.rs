@@ -1,6 +1,6 @@
 use awint::{
-    awint_internals::{bits_upper_bound, chars_upper_bound, USIZE_BITS},
-    extawi, inlawi, Bits, ExtAwi, InlAwi,
+    awint_internals::{bits_upper_bound, chars_upper_bound, Digit, USIZE_BITS},
+    bits_for_str_len, bw, extawi, inlawi, max_str_len, Awi, Bits, ExtAwi, InlAwi,
     SerdeError::*,
     FP,
 };
@@ -213,6 +213,23 @@ fn fmt_strings() {
         format!("{}", inlawi!(0x1_fedcba98_76543210u100)),
         "0x1_fedcba98_76543210_u100"
     );
+    // formatter flags
+    assert_eq!(format!("{ext_awi:#x}"), "0xfedcba9876543210");
+    assert_eq!(format!("{ext_awi:#X}"), "0xFEDCBA9876543210");
+    assert_eq!(format!("{ext_awi:.4x}"), "0xfedc_ba98_7654_3210_u100");
+    assert_eq!(format!("{ext_awi:#.3o}"), "0o1_773_345_651_416_625_031_020");
+    assert_eq!(
+        format!("{ext_awi:.0b}"),
+        format!("0b{:b}_u100", 0xfedcba9876543210u64)
+    );
+    assert_eq!(
+        format!("{inl_awi:#b}"),
+        format!("0b{:b}", 0xfedcba9876543210u64)
+    );
+    assert_eq!(format!("{:#x}", inlawi!(0u100)), "0x0");
+    // `Debug` and `Display` ignore the flags
+    assert_eq!(format!("{bits:#?}"), "0xfedcba98_76543210_u100");
+    assert_eq!(format!("{bits:#.4}"), "0xfedcba98_76543210_u100");
 
     let fpbits = FP::new(true, inlawi!(-0xabcd1234i36), 16).unwrap();
     assert_eq!(format!("{fpbits:x}"), "-0xabcd.1234_i36f16");
@@ -339,3 +356,221 @@ fn all_single_byte_combos() {
 }
 
 // TODO serde conversion
+
+#[test]
+fn portable_bytes() {
+    use rand_xoshiro::{rand_core::SeedableRng, Xoshiro128StarStar};
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for w in 1..300 {
+        let mut x = ExtAwi::zero(bw(w));
+        x.rand_(&mut rng).unwrap();
+        let bytes = ExtAwi::bits_to_portable_bytes(&x);
+        assert_eq!(bytes.len(), 8 + (w / 8) + ((w % 8) != 0) as usize);
+        // must be the same as the `borsh_support` format
+        assert_eq!(bytes, borsh::to_vec(x.as_ref()).unwrap());
+        assert_eq!(Awi::bits_to_portable_bytes(&x), bytes);
+        assert_eq!(ExtAwi::from_portable_bytes(&bytes).unwrap(), x);
+        assert_eq!(
+            Awi::from_portable_bytes(&bytes).unwrap().as_ref(),
+            x.as_ref()
+        );
+    }
+    assert_eq!(ExtAwi::from_portable_bytes(&[]), Err(Empty));
+    assert_eq!(ExtAwi::from_portable_bytes(&[0; 7]), Err(Empty));
+    assert_eq!(ExtAwi::from_portable_bytes(&[0; 8]), Err(ZeroBitwidth));
+    assert_eq!(
+        ExtAwi::from_portable_bytes(&[12, 0, 0, 0, 0, 0, 0, 0, 0x34]),
+        Err(NonEqualWidths)
+    );
+    assert_eq!(
+        ExtAwi::from_portable_bytes(&[12, 0, 0, 0, 0, 0, 0, 0, 0x34, 0x2, 0]),
+        Err(NonEqualWidths)
+    );
+    assert_eq!(
+        ExtAwi::from_portable_bytes(&[12, 0, 0, 0, 0, 0, 0, 0, 0x34, 0x12]),
+        Err(Overflow)
+    );
+    // a forged huge bitwidth is rejected before allocating
+    let mut huge = (1u64 << 62).to_le_bytes().to_vec();
+    huge.extend_from_slice(&[0xff; 16]);
+    assert!(ExtAwi::from_portable_bytes(&huge).is_err());
+    assert!(Awi::from_portable_bytes(&huge).is_err());
+}
+
+#[test]
+fn bcd() {
+    use rand_xoshiro::{rand_core::SeedableRng, Xoshiro128StarStar};
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for w in 1..300 {
+        let mut x = ExtAwi::zero(bw(w));
+        x.rand_(&mut rng).unwrap();
+        let bcd = ExtAwi::bits_to_bcd(&x);
+        assert_eq!(Awi::bits_to_bcd(&x).as_ref(), bcd.as_ref());
+        // the number of nibbles is the number of decimal digits of the maximum
+        let digits = bcd.bw() / 4;
+        assert_eq!(bcd.bw() % 4, 0);
+        let max = ExtAwi::umax(bw(w));
+        assert_eq!(
+            ExtAwi::bits_to_string_radix(&max, false, 10, false, 0)
+                .unwrap()
+                .len(),
+            digits
+        );
+        // the hexadecimal string of the BCD is the decimal string of `x`
+        assert_eq!(
+            ExtAwi::bits_to_string_radix(&bcd, false, 16, false, digits).unwrap(),
+            ExtAwi::bits_to_string_radix(&x, false, 10, false, digits).unwrap()
+        );
+        let y = ExtAwi::from_bcd(&bcd).unwrap();
+        assert!(y.bw() >= w);
+        assert_eq!(y.sig(), x.sig());
+        let mut tmp = ExtAwi::zero(bw(w));
+        tmp.zero_resize_(&y);
+        assert_eq!(tmp, x);
+        assert_eq!(Awi::from_bcd(&bcd).unwrap().as_ref(), y.as_ref());
+        // decimal strings can be converted directly
+        let s = ExtAwi::bits_to_string_radix(&x, false, 10, false, 0).unwrap();
+        let bcd = ExtAwi::from_str_radix(None, &s, 16, bw(4 * s.len())).unwrap();
+        let y = ExtAwi::from_bcd(&bcd).unwrap();
+        assert_eq!(
+            ExtAwi::bits_to_string_radix(&y, false, 10, false, 0).unwrap(),
+            s
+        );
+    }
+    assert_eq!(
+        ExtAwi::from_bcd(&inlawi!(0x9876543210_u40)).unwrap(),
+        extawi!(9876543210_u34)
+    );
+    assert_eq!(ExtAwi::from_bcd(&inlawi!(0x7_u3)).unwrap(), extawi!(7_u4));
+    for digit in 10..16u8 {
+        let mut bcd = inlawi!(0x12345_u20);
+        bcd.field_to(8, &InlAwi::from_u8(digit), 4).unwrap();
+        assert!(ExtAwi::from_bcd(&bcd).is_none());
+        assert!(Awi::from_bcd(&bcd).is_none());
+    }
+}
+
+#[test]
+fn bit_string() {
+    use rand_xoshiro::{rand_core::SeedableRng, Xoshiro128StarStar};
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    for w in 1..300 {
+        let mut x = ExtAwi::zero(bw(w));
+        x.rand_(&mut rng).unwrap();
+        let s = ExtAwi::bits_to_bit_string(&x, true);
+        assert_eq!(s.len(), w);
+        assert_eq!(Awi::bits_to_bit_string(&x, true), s);
+        // the same as the radix 2 formatting with all leading zeros
+        assert_eq!(
+            ExtAwi::bits_to_string_radix(&x, false, 2, false, w).unwrap(),
+            s
+        );
+        let rev: String = s.chars().rev().collect();
+        assert_eq!(ExtAwi::bits_to_bit_string(&x, false), rev);
+        assert_eq!(ExtAwi::from_bit_string(&s).unwrap(), x);
+        assert_eq!(Awi::from_bit_string(&s).unwrap().as_ref(), x.as_ref());
+    }
+    assert_eq!(ExtAwi::from_bit_string(""), Err(Empty));
+    assert_eq!(ExtAwi::from_bit_string("0102"), Err(InvalidChar));
+    assert_eq!(ExtAwi::from_bit_string("01_01"), Err(InvalidChar));
+    assert_eq!(Awi::from_bit_string("0é").unwrap_err(), InvalidChar);
+}
+
+#[test]
+fn radix_digits() {
+    use rand_xoshiro::{
+        rand_core::{RngCore, SeedableRng},
+        Xoshiro128StarStar,
+    };
+    let mut rng = Xoshiro128StarStar::seed_from_u64(0);
+    let mut out = vec![];
+    let mut out1 = vec![];
+    for w in 1..300 {
+        let mut x = ExtAwi::zero(bw(w));
+        x.rand_(&mut rng).unwrap();
+        // compare against the string formatter for the radixes it supports
+        let radix = ((rng.next_u32() % 35) + 2) as u8;
+        ExtAwi::bits_to_radix_digits(&x, Digit::from(radix), &mut out).unwrap();
+        Awi::bits_to_radix_digits(&x, Digit::from(radix), &mut out1).unwrap();
+        assert_eq!(out, out1);
+        let s = ExtAwi::bits_to_vec_radix(&x, false, radix, false, 0).unwrap();
+        assert_eq!(out.len(), s.len());
+        for (digit, c) in out.iter().zip(s.iter().rev()) {
+            assert_eq!(
+                *digit,
+                Digit::from((*c as char).to_digit(36).unwrap() as u8)
+            );
+        }
+        // reconstruct with arbitrary radixes
+        let radix = (rng.next_u64() as Digit).max(2);
+        ExtAwi::bits_to_radix_digits(&x, radix, &mut out).unwrap();
+        let mut y = ExtAwi::zero(bw(w));
+        for digit in out.iter().rev() {
+            assert!(*digit < radix);
+            assert_eq!(y.digit_cin_mul_(*digit, radix), 0);
+        }
+        assert_eq!(y, x);
+    }
+    ExtAwi::bits_to_radix_digits(&inlawi!(0u100), Digit::MAX, &mut out).unwrap();
+    assert_eq!(out, [0]);
+    assert!(ExtAwi::bits_to_radix_digits(&inlawi!(0u100), 1, &mut out).is_none());
+}
+
+#[test]
+fn from_bytes_radix() {
+    let mut x = inlawi!(0u16);
+    x.from_bytes_radix_(b"000_065_535", 10).unwrap();
+    assert_eq!(x, inlawi!(65535u16));
+    assert_eq!(x.from_bytes_radix_(b"65536", 10), Err(Overflow));
+    x.from_bytes_radix_(b"zz", 36).unwrap();
+    assert_eq!(x, inlawi!(1295u16));
+    x.from_bytes_radix_(b"", 10).unwrap();
+    assert!(x.is_zero());
+    assert_eq!(x.from_bytes_radix_(b"12a", 10), Err(InvalidChar));
+    assert_eq!(x.from_bytes_radix_(b"1", 37), Err(InvalidRadix));
+    assert!(x.is_zero());
+    let mut y = inlawi!(0u100);
+    let s = ExtAwi::bits_to_vec_radix(&inlawi!(umax: ..100), false, 7, false, 0).unwrap();
+    y.from_bytes_radix_(&s, 7).unwrap();
+    assert!(y.is_umax());
+    let mut y = inlawi!(0u99);
+    assert_eq!(y.from_bytes_radix_(&s, 7), Err(Overflow));
+}
+
+#[test]
+fn str_len_sizing() {
+    for radix in 2..=36u8 {
+        for w in 1..300 {
+            let max = ExtAwi::umax(bw(w));
+            assert_eq!(
+                max_str_len(w, radix),
+                ExtAwi::bits_to_string_radix(&max, false, radix, false, 0)
+                    .unwrap()
+                    .len()
+            );
+        }
+        // `radix^len - 1`
+        let mut x = ExtAwi::uone(bw(1000));
+        for len in 1..150 {
+            x.digit_cin_mul_(0, Digit::from(radix));
+            let mut max = x.clone();
+            max.dec_(false);
+            assert_eq!(bits_for_str_len(len, radix).get(), max.sig());
+            assert!(max_str_len(bits_for_str_len(len, radix).get(), radix) >= len);
+        }
+        assert_eq!(bits_for_str_len(0, radix).get(), 1);
+        assert_eq!(max_str_len(0, radix), 1);
+    }
+    // large values, using exact relationships for powers of two and otherwise
+    // checking that the results are consistent
+    assert_eq!(max_str_len(usize::MAX, 2), usize::MAX);
+    assert_eq!(max_str_len(usize::MAX, 16), (usize::MAX / 4) + 1);
+    assert_eq!(max_str_len(usize::MAX - 2, 8), usize::MAX / 3);
+    for radix in 3..=36u8 {
+        for len in [1 << 20, 1 << 40, (1 << 50) + 12345, usize::MAX / 8] {
+            let bits = bits_for_str_len(len, radix).get();
+            assert!(max_str_len(bits, radix) >= len);
+            assert!(max_str_len(bits - 1, radix) <= len);
+        }
+    }
+}